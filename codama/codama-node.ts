@@ -11,7 +11,16 @@ import {
     publicKeyValueNode,
     publicKeyTypeNode,
     arrayTypeNode,
-    fixedCountNode
+    fixedCountNode,
+    remainderCountNode,
+    instructionRemainingAccountsNode,
+    argumentValueNode,
+    bytesTypeNode,
+    sizePrefixTypeNode,
+    stringTypeNode,
+    optionTypeNode,
+    structTypeNode,
+    structFieldTypeNode
 } from 'codama';
 
 export const root = rootNode(
@@ -66,7 +75,7 @@ export const root = rootNode(
                     instructionArgumentNode({
                         name: 'feeRecipient',
                         type: publicKeyTypeNode(),
-                        docs: ['Fee recipient address'],
+                        docs: ['Fee recipient address (all zeroes = protocol default)'],
                     }),
                     instructionArgumentNode({
                         name: 'initialBuyAmount',
@@ -93,6 +102,43 @@ export const root = rootNode(
                         type: arrayTypeNode(numberTypeNode('u8'), fixedCountNode(200)),
                         docs: ['Token metadata URI (max 200 bytes) - includes length in first byte'],
                     }),
+                    instructionArgumentNode({
+                        name: 'version',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(2),
+                        defaultValueStrategy: 'omitted',
+                        docs: ['Encoding version of the fields below; the original (v1) data ends before it'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'sellerFeeBasisPoints',
+                        type: numberTypeNode('u16'),
+                        docs: ['Royalty on secondary sales written to the metadata (basis points, max 10000). Metaplex only: Token-2022 mints need this, the creator share and the collection left zero'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'creatorShare',
+                        type: numberTypeNode('u8'),
+                        docs: ["Authority's percentage of the metadata creators; the authority is listed verified and the protocol fee recipient gets the rest (0 = the protocol fee recipient is the only creator)"],
+                    }),
+                    instructionArgumentNode({
+                        name: 'collection',
+                        type: publicKeyTypeNode(),
+                        docs: ['Platform collection the token joins, verified later through VerifyCollection (all zeroes = none)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'transferFeeBasisPoints',
+                        type: numberTypeNode('u16'),
+                        docs: ['Token-2022 only: creator royalty withheld on every transfer, in basis points (0 = none); buys and sells mint and burn directly and pay none'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'maximumTransferFee',
+                        type: numberTypeNode('u64'),
+                        docs: ['Cap on the transfer fee charged per transfer (base units)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'creatorAllocation',
+                        type: numberTypeNode('u64'),
+                        docs: ['Tokens reserved for AdminMint; counted against maxSupply, outside curve pricing'],
+                    }),
                 ],
                 accounts: [
                     instructionAccountNode({
@@ -149,7 +195,7 @@ export const root = rootNode(
                         ),
                         isSigner: false,
                         isWritable: false,
-                        docs: ['Token Program'],
+                        docs: ['Token program owning the mint: the classic token program, or Token-2022 to keep the metadata in the mint instead of Metaplex'],
                     }),
                     instructionAccountNode({
                         name: 'associatedTokenProgram',
@@ -189,6 +235,19 @@ export const root = rootNode(
                         isWritable: false,
                         docs: ['Metaplex Token Metadata Program'],
                     }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'protocolFeeRecipient',
+                        isSigner: false,
+                        isWritable: true,
+                        isOptional: true,
+                        docs: ['Protocol fee recipient receiving the creation fee; only needed while the protocol charges one'],
+                    }),
                 ],
             }),
             instructionNode({
@@ -265,7 +324,7 @@ export const root = rootNode(
                         ),
                         isSigner: false,
                         isWritable: false,
-                        docs: ['Token Program'],
+                        docs: ['Token program owning the mint (classic or Token-2022)'],
                     }),
                     instructionAccountNode({
                         name: 'associatedTokenProgram',
@@ -277,6 +336,12 @@ export const root = rootNode(
                         isWritable: false,
                         docs: ['Associated Token Program'],
                     }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA)'],
+                    }),
                 ],
             }),
             instructionNode({
@@ -343,7 +408,7 @@ export const root = rootNode(
                         ),
                         isSigner: false,
                         isWritable: false,
-                        docs: ['Token Program'],
+                        docs: ['Token program owning the mint (classic or Token-2022)'],
                     }),
                     instructionAccountNode({
                         name: 'systemProgram',
@@ -355,6 +420,12 @@ export const root = rootNode(
                         isWritable: false,
                         docs: ['System Program'],
                     }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA)'],
+                    }),
                 ],
             }),
             instructionNode({
@@ -368,7 +439,7 @@ export const root = rootNode(
                     instructionArgumentNode({
                         name: 'lamports',
                         type: numberTypeNode('u64'),
-                        docs: ['0 = withdraw all, otherwise exact lamports to withdraw'],
+                        docs: ['0 = withdraw everything withdrawable, otherwise exact lamports to withdraw. Only the surplus above the SOL reserve is withdrawable, graduated or not; once every token is sold back and no creator allocation was minted, everything above the rent floor is'],
                     }),
                 ],
                 accounts: [
@@ -376,12 +447,12 @@ export const root = rootNode(
                         name: 'authority',
                         isSigner: true,
                         isWritable: false,
-                        docs: ['Authority that controls the bonding curve'],
+                        docs: ['Owner or treasurer of the bonding curve'],
                     }),
                     instructionAccountNode({
                         name: 'bondingCurve',
                         isSigner: false,
-                        isWritable: false,
+                        isWritable: true,
                         docs: ['Bonding curve state account (PDA)'],
                     }),
                     instructionAccountNode({
@@ -412,6 +483,19 @@ export const root = rootNode(
                         isWritable: false,
                         docs: ['System Program'],
                     }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA, checked for the emergency halt)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
                 ],
             }),
             instructionNode({
@@ -433,7 +517,7 @@ export const root = rootNode(
                         name: 'authority',
                         isSigner: true,
                         isWritable: false,
-                        docs: ['Authority that controls the bonding curve'],
+                        docs: ['Holder of the minter role'],
                     }),
                     instructionAccountNode({
                         name: 'bondingCurve',
@@ -461,7 +545,2138 @@ export const root = rootNode(
                         ),
                         isSigner: false,
                         isWritable: false,
-                        docs: ['Token Program'],
+                        docs: ['Token program owning the mint (classic or Token-2022)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA, checked for the emergency halt)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'initializeConfig',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(5))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'feeRecipient',
+                        type: publicKeyTypeNode(),
+                        docs: ['Default fee recipient for curves launched without an explicit one'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'solCapLamports',
+                        type: numberTypeNode('u64'),
+                        docs: ['Maximum SOL (lamports) a single curve treasury may hold'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'minFeeBasisPoints',
+                        type: numberTypeNode('u16'),
+                        docs: ['Lowest fee a curve may charge, in basis points'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'maxFeeBasisPoints',
+                        type: numberTypeNode('u16'),
+                        docs: ['Highest fee a curve may charge, in basis points'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'useMetaplex',
+                        type: numberTypeNode('u8'),
+                        docs: ['Whether Initialize creates Metaplex metadata (0 = false, 1 = true)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'paused',
+                        type: numberTypeNode('u8'),
+                        docs: ['Whether launches and trading are paused (0 = false, 1 = true)'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'admin',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Protocol admin (becomes config admin)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Protocol config account (PDA) - will be created by program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'payer',
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ['Payer for account creation and rent'],
+                    }),
+                    instructionAccountNode({
+                        name: 'systemProgram',
+                        defaultValue: publicKeyValueNode(
+                            '11111111111111111111111111111111',
+                            'systemProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['System Program'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'updateConfig',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(6))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'newAdmin',
+                        type: publicKeyTypeNode(),
+                        docs: ['New protocol admin (pass the current admin to keep it; all zeroes is rejected)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'feeRecipient',
+                        type: publicKeyTypeNode(),
+                        docs: ['Default fee recipient for curves launched without an explicit one'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'solCapLamports',
+                        type: numberTypeNode('u64'),
+                        docs: ['Maximum SOL (lamports) a single curve treasury may hold'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'minFeeBasisPoints',
+                        type: numberTypeNode('u16'),
+                        docs: ['Lowest fee a curve may charge, in basis points'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'maxFeeBasisPoints',
+                        type: numberTypeNode('u16'),
+                        docs: ['Highest fee a curve may charge, in basis points'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'useMetaplex',
+                        type: numberTypeNode('u8'),
+                        docs: ['Whether Initialize creates Metaplex metadata (0 = false, 1 = true)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'paused',
+                        type: numberTypeNode('u8'),
+                        docs: ['Whether launches and trading are paused (0 = false, 1 = true)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'version',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(2),
+                        defaultValueStrategy: 'omitted',
+                        docs: ['Encoding version of the fields below; the original (v1) data ends before it and leaves them unchanged'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'timelockDelaySeconds',
+                        type: numberTypeNode('i64'),
+                        docs: ['Delay (seconds) before queued curve actions may execute; 0 disables the timelock. A lower delay than the current one applies only after the current delay has passed'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'creationFeeLamports',
+                        type: numberTypeNode('u64'),
+                        docs: ['Fee (lamports) charged to the payer of every launch; 0 disables it'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'creationFeeRebateBasisPoints',
+                        type: numberTypeNode('u16'),
+                        docs: ['Share of the creation fee rebated to the creator on graduation, in basis points'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'admin',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Protocol admin (must match config admin)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Protocol config account (PDA)'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'updateCurveConfig',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(7))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'feeBasisPoints',
+                        type: numberTypeNode('u16'),
+                        docs: ['Fees in basis points (100 = 1%) - may only decrease once tokens are in circulation'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'curveType',
+                        type: numberTypeNode('u8'),
+                        docs: ['Curve type (0 = linear, 3 = cpmm) - locked once tokens are in circulation'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'feeRecipient',
+                        type: publicKeyTypeNode(),
+                        docs: ['Fee recipient address (all zeroes is rejected)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'basePrice',
+                        type: numberTypeNode('u64'),
+                        docs: ['Base price in lamports per token (scaled by 1e9) - locked once tokens are in circulation'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'slope',
+                        type: numberTypeNode('u64'),
+                        docs: ['Slope parameter for pricing curve (scaled by 1e9) - locked once tokens are in circulation'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'maxSupply',
+                        type: numberTypeNode('u64'),
+                        docs: ['Maximum token supply - locked once tokens are in circulation'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Holder of the fee manager role (pricing changes also need the owner role)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'proposeAuthority',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(8))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'newAuthority',
+                        type: publicKeyTypeNode(),
+                        docs: ['Proposed new authority (must sign AcceptAuthority)'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Current authority of the bonding curve'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA, checked for the emergency halt)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'acceptAuthority',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(9))
+                    ),
+                ],
+                arguments: [],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'newAuthority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Pending authority accepting the handover'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA, checked for the emergency halt)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'cancelAuthorityTransfer',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(10))
+                    ),
+                ],
+                arguments: [],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Current authority of the bonding curve'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'setRole',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(11))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'role',
+                        type: numberTypeNode('u8'),
+                        docs: ['Role to assign (1 = operator, 2 = fee manager, 3 = minter, 4 = treasurer)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'holder',
+                        type: publicKeyTypeNode(),
+                        docs: ['New role holder (all zeroes revokes the role)'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Authority (owner role) of the bonding curve'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA, checked for the emergency halt)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'migrateAccount',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(12))
+                    ),
+                ],
+                arguments: [],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'payer',
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ['Payer covering the rent difference'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA) to upgrade to the current layout'],
+                    }),
+                    instructionAccountNode({
+                        name: 'systemProgram',
+                        defaultValue: publicKeyValueNode(
+                            '11111111111111111111111111111111',
+                            'systemProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['System Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA, checked for the emergency halt)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'mint',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Token mint account of the curve (read for tokens the v1 admin minted)'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'pauseCurve',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(13))
+                    ),
+                ],
+                arguments: [],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Holder of the owner or operator role'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'resumeCurve',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(14))
+                    ),
+                ],
+                arguments: [],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Holder of the owner or operator role'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA, checked for the emergency halt)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'setEmergencyHalt',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(15))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'halted',
+                        type: numberTypeNode('u8'),
+                        docs: ['Whether the protocol should be halted (0 = false, 1 = true)'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'guardian',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Protocol guardian (must match config guardian)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Protocol config account (PDA)'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'setGuardian',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(16))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'guardian',
+                        type: publicKeyTypeNode(),
+                        docs: ['New guardian allowed to toggle the emergency halt'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'admin',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Protocol admin (must match config admin)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Protocol config account (PDA)'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'createMultisig',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(17))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'threshold',
+                        type: numberTypeNode('u8'),
+                        docs: ['Number of member signatures required'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'signers',
+                        type: arrayTypeNode(publicKeyTypeNode(), remainderCountNode()),
+                        docs: ['Member keys (1 to 10)'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'createKey',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Creation key (seeds the multisig PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'multisig',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Multisig account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'payer',
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ['Payer for account creation'],
+                    }),
+                    instructionAccountNode({
+                        name: 'systemProgram',
+                        defaultValue: publicKeyValueNode(
+                            '11111111111111111111111111111111',
+                            'systemProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['System Program'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'queueAction',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(18))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'actionId',
+                        type: numberTypeNode('u64'),
+                        docs: ['Caller-chosen id distinguishing actions on the same curve'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'kind',
+                        type: numberTypeNode('u8'),
+                        docs: ['Discriminator of the queued instruction (3, 4, 7, 8, 11, 21, 24 or 25)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'destination',
+                        type: publicKeyTypeNode(),
+                        docs: ['Account the queued instruction pays out to (recipient, recipient token account or beneficiary); all zeroes for the other kinds. ExecuteAction only runs the action against this account'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'actionData',
+                        type: bytesTypeNode(),
+                        docs: ['Instruction data of the queued instruction'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Holder of the role the queued instruction requires'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Bonding curve state account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'action',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Pending action account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'payer',
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ['Payer for account creation (receives the rent back on close)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'systemProgram',
+                        defaultValue: publicKeyValueNode(
+                            '11111111111111111111111111111111',
+                            'systemProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['System Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'executeAction',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(19))
+                    ),
+                ],
+                arguments: [],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'action',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Pending action account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'rentRecipient',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Account that queued the action (receives the rent back)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('targetAccounts'), {
+                        docs: ['Accounts of the queued instruction, in that instruction order'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'cancelAction',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(20))
+                    ),
+                ],
+                arguments: [],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Owner, or holder of the role the queued instruction requires'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Bonding curve state account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'action',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Pending action account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'rentRecipient',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Account that queued the action (receives the rent back)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'createVesting',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(21))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'amount',
+                        type: numberTypeNode('u64'),
+                        docs: ['Tokens to vest (base units, taken from the creator allocation)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'startTs',
+                        type: numberTypeNode('i64'),
+                        docs: ['Unix timestamp unlocking starts from'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'cliffTs',
+                        type: numberTypeNode('i64'),
+                        docs: ['Unix timestamp before which nothing can be claimed'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'endTs',
+                        type: numberTypeNode('i64'),
+                        docs: ['Unix timestamp at which everything is unlocked'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'revocable',
+                        type: numberTypeNode('u8'),
+                        docs: ['Whether the curve owner may revoke unvested tokens (0 = false, 1 = true)'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Holder of the minter role'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'mint',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Token mint account'],
+                    }),
+                    instructionAccountNode({
+                        name: 'vesting',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Vesting account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'escrow',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Escrow token account (ATA owned by the vesting PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'beneficiary',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Beneficiary allowed to claim unlocked tokens'],
+                    }),
+                    instructionAccountNode({
+                        name: 'payer',
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ['Payer for account creation'],
+                    }),
+                    instructionAccountNode({
+                        name: 'systemProgram',
+                        defaultValue: publicKeyValueNode(
+                            '11111111111111111111111111111111',
+                            'systemProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['System Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'tokenProgram',
+                        defaultValue: publicKeyValueNode(
+                            'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+                            'tokenProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Token program owning the mint (classic or Token-2022)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'associatedTokenProgram',
+                        defaultValue: publicKeyValueNode(
+                            'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
+                            'associatedTokenProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Associated Token Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA, checked for the emergency halt)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'claimVested',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(22))
+                    ),
+                ],
+                arguments: [],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'beneficiary',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Vesting beneficiary'],
+                    }),
+                    instructionAccountNode({
+                        name: 'vesting',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Vesting account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'escrow',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Escrow token account (ATA owned by the vesting PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'beneficiaryTokenAccount',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Beneficiary token account receiving the unlocked tokens'],
+                    }),
+                    instructionAccountNode({
+                        name: 'tokenProgram',
+                        defaultValue: publicKeyValueNode(
+                            'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+                            'tokenProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Token program owning the mint (classic or Token-2022)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'mint',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Token mint account'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'revokeVesting',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(23))
+                    ),
+                ],
+                arguments: [],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Holder of the owner role'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'mint',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Token mint account'],
+                    }),
+                    instructionAccountNode({
+                        name: 'vesting',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Vesting account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'escrow',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Escrow token account (ATA owned by the vesting PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'tokenProgram',
+                        defaultValue: publicKeyValueNode(
+                            'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+                            'tokenProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Token program owning the mint (classic or Token-2022)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA, checked for the emergency halt)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'renounceAuthorities',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(24))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'authorities',
+                        type: numberTypeNode('u8'),
+                        docs: ['Authorities to renounce (1 = mint, 2 = freeze, 3 = both); the mint authority only after graduation'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Authority (owner role) of the bonding curve'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA, current mint and freeze authority)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'mint',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Token mint account'],
+                    }),
+                    instructionAccountNode({
+                        name: 'tokenProgram',
+                        defaultValue: publicKeyValueNode(
+                            'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+                            'tokenProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Token Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA, checked for the emergency halt)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'closeCurve',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(25))
+                    ),
+                ],
+                arguments: [],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Authority (owner role) of the bonding curve'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA, closed); total supply must be zero'],
+                    }),
+                    instructionAccountNode({
+                        name: 'mint',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Token mint account (mint and freeze authority removed)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'treasury',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Treasury PDA (system-owned, emptied)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'recipient',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Recipient of the curve and treasury lamports'],
+                    }),
+                    instructionAccountNode({
+                        name: 'systemProgram',
+                        defaultValue: publicKeyValueNode(
+                            '11111111111111111111111111111111',
+                            'systemProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['System Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'tokenProgram',
+                        defaultValue: publicKeyValueNode(
+                            'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+                            'tokenProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Token Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA, checked for the emergency halt)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                    instructionRemainingAccountsNode(argumentValueNode('protocolFeeRecipient'), {
+                        isWritable: true,
+                        isOptional: true,
+                        docs: ['Protocol fee recipient, receiving a creation rebate the curve forfeits'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'updateMetadata',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(26))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'updateData',
+                        type: numberTypeNode('u8'),
+                        docs: ['Replace name, symbol and URI (0 = keep, 1 = replace); royalty, creators and collection are kept'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'delegateToCurve',
+                        type: numberTypeNode('u8'),
+                        docs: ['Hand metadata update authority to the bonding curve PDA (0 = false, 1 = true)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'lock',
+                        type: numberTypeNode('u8'),
+                        docs: ['Make the metadata immutable; this cannot be undone (0 = false, 1 = true)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'tokenName',
+                        type: arrayTypeNode(numberTypeNode('u8'), fixedCountNode(32)),
+                        docs: ['Token name (max 32 bytes) - includes length in first byte'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'tokenSymbol',
+                        type: arrayTypeNode(numberTypeNode('u8'), fixedCountNode(10)),
+                        docs: ['Token symbol (max 10 bytes) - includes length in first byte'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'tokenUri',
+                        type: arrayTypeNode(numberTypeNode('u8'), fixedCountNode(200)),
+                        docs: ['Token metadata URI (max 200 bytes) - includes length in first byte'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Authority (owner role) of the bonding curve'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Bonding curve state account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'mint',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Token mint account'],
+                    }),
+                    instructionAccountNode({
+                        name: 'metadataAccount',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Metadata account (PDA from Metaplex)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'updateAuthority',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Current metadata update authority: the authority wallet (signer) or the bonding curve PDA'],
+                    }),
+                    instructionAccountNode({
+                        name: 'metaplexProgram',
+                        defaultValue: publicKeyValueNode(
+                            'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s',
+                            'metaplexProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Metaplex Token Metadata Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA, checked for the emergency halt)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'verifyCollection',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(27))
+                    ),
+                ],
+                arguments: [],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'payer',
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ['Payer for the Metaplex CPI'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Bonding curve state account (PDA), proving the token was launched by this program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'mint',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Token mint account'],
+                    }),
+                    instructionAccountNode({
+                        name: 'metadataAccount',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Metadata account of the token (PDA from Metaplex)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'collectionAuthority',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Program PDA ("collection_authority") holding update authority over the collection'],
+                    }),
+                    instructionAccountNode({
+                        name: 'collectionMint',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Collection NFT mint'],
+                    }),
+                    instructionAccountNode({
+                        name: 'collectionMetadata',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Collection NFT metadata account'],
+                    }),
+                    instructionAccountNode({
+                        name: 'collectionMasterEdition',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Collection NFT master edition account'],
+                    }),
+                    instructionAccountNode({
+                        name: 'metaplexProgram',
+                        defaultValue: publicKeyValueNode(
+                            'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s',
+                            'metaplexProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Metaplex Token Metadata Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA, checked for the emergency halt)'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'harvestWithheldFees',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(28))
+                    ),
+                ],
+                arguments: [],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Bonding curve state account (PDA, withdraw withheld authority of the mint)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'mint',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Token mint account (Token-2022 with a transfer fee)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'creatorTokenAccount',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Creator token account receiving the fees (owned by the curve authority)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'tokenProgram',
+                        defaultValue: publicKeyValueNode(
+                            'TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb',
+                            'tokenProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Token-2022 Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA, checked for the emergency halt)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('sources'), {
+                        isWritable: true,
+                        isOptional: true,
+                        docs: ['Token accounts of the mint holding withheld fees'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'initializeWithoutMetadata',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(29))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'decimals',
+                        type: numberTypeNode('u8'),
+                        docs: ['The number of decimals for the token.'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'curveType',
+                        type: numberTypeNode('u8'),
+                        docs: ['Curve type (0 = linear, 1 = exponential, 2 = logarithmic)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'feeBasisPoints',
+                        type: numberTypeNode('u16'),
+                        docs: ['Fees in basis points (100 = 1%)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'owner',
+                        type: arrayTypeNode(numberTypeNode('u8'), fixedCountNode(32)),
+                        docs: ['Owner username (max 32 bytes) - includes length in first byte'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'basePrice',
+                        type: numberTypeNode('u64'),
+                        docs: ['Base price in lamports per token (scaled by 1e9)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'slope',
+                        type: numberTypeNode('u64'),
+                        docs: ['Slope parameter for pricing curve (scaled by 1e9)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'maxSupply',
+                        type: numberTypeNode('u64'),
+                        docs: ['Maximum token supply'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'feeRecipient',
+                        type: publicKeyTypeNode(),
+                        docs: ['Fee recipient address (all zeroes = protocol default)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'initialBuyAmount',
+                        type: numberTypeNode('u64'),
+                        docs: ['Initial pre-buy token amount in base units (optional, 0 to skip)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'initialMaxSol',
+                        type: numberTypeNode('u64'),
+                        docs: ['Max SOL (lamports) willing to pay for initial pre-buy'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'creatorAllocation',
+                        type: numberTypeNode('u64'),
+                        docs: ['Tokens reserved for AdminMint; counted against maxSupply, outside curve pricing'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Authority that will control the bonding curve'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA) - will be created by program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'mint',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Token mint account - must be created by client before calling'],
+                    }),
+                    instructionAccountNode({
+                        name: 'treasury',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Treasury account (holds SOL for bonding curve)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'authorityTokenAccount',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Authority's token account (ATA) to receive initial pre-buy tokens"],
+                    }),
+                    instructionAccountNode({
+                        name: 'payer',
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ['Payer for account creation and rent'],
+                    }),
+                    instructionAccountNode({
+                        name: 'systemProgram',
+                        defaultValue: publicKeyValueNode(
+                            '11111111111111111111111111111111',
+                            'systemProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['System Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'tokenProgram',
+                        defaultValue: publicKeyValueNode(
+                            'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+                            'tokenProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Token program owning the mint (classic or Token-2022)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'associatedTokenProgram',
+                        defaultValue: publicKeyValueNode(
+                            'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
+                            'associatedTokenProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Associated Token Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'feeRecipientAccount',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Fee recipient account (for initial pre-buy fee transfer)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'protocolFeeRecipient',
+                        isSigner: false,
+                        isWritable: true,
+                        isOptional: true,
+                        docs: ['Protocol fee recipient receiving the creation fee; only needed while the protocol charges one'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'initializeCompact',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(30))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'version',
+                        type: numberTypeNode('u8'),
+                        defaultValue: numberValueNode(1),
+                        defaultValueStrategy: 'omitted',
+                        docs: ['Encoding version (1)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'decimals',
+                        type: numberTypeNode('u8'),
+                        docs: ['The number of decimals for the token.'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'curveType',
+                        type: numberTypeNode('u8'),
+                        docs: ['Curve type (0 = linear, 1 = exponential, 2 = logarithmic)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'feeBasisPoints',
+                        type: numberTypeNode('u16'),
+                        docs: ['Fees in basis points (100 = 1%)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'owner',
+                        type: sizePrefixTypeNode(stringTypeNode('utf8'), numberTypeNode('u8')),
+                        docs: ['Owner username (max 31 bytes)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'basePrice',
+                        type: numberTypeNode('u64'),
+                        docs: ['Base price in lamports per token (scaled by 1e9)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'slope',
+                        type: numberTypeNode('u64'),
+                        docs: ['Slope parameter for pricing curve (scaled by 1e9)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'maxSupply',
+                        type: numberTypeNode('u64'),
+                        docs: ['Maximum token supply'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'creatorAllocation',
+                        type: numberTypeNode('u64'),
+                        docs: ['Tokens reserved for AdminMint; counted against max_supply, outside curve pricing'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'feeRecipient',
+                        type: optionTypeNode(publicKeyTypeNode()),
+                        docs: ['Fee recipient (none = protocol default)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'initialBuyAmount',
+                        type: numberTypeNode('u64'),
+                        docs: ['Optional initial pre-buy token amount (base units)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'initialMaxSol',
+                        type: numberTypeNode('u64'),
+                        docs: ['Max SOL willing to pay for initial buy (slippage protection)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'tokenName',
+                        type: sizePrefixTypeNode(stringTypeNode('utf8'), numberTypeNode('u8')),
+                        docs: ['Token name (max 31 bytes)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'tokenSymbol',
+                        type: sizePrefixTypeNode(stringTypeNode('utf8'), numberTypeNode('u8')),
+                        docs: ['Token symbol (max 9 bytes)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'tokenUri',
+                        type: sizePrefixTypeNode(stringTypeNode('utf8'), numberTypeNode('u8')),
+                        docs: ['Token metadata URI (max 199 bytes)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'royalty',
+                        type: optionTypeNode(
+                            structTypeNode([
+                                structFieldTypeNode({ name: 'sellerFeeBasisPoints', type: numberTypeNode('u16') }),
+                                structFieldTypeNode({ name: 'creatorShare', type: numberTypeNode('u8') }),
+                            ])
+                        ),
+                        docs: ["Royalty on secondary sales (basis points) and the authority's percentage of the metadata creators"],
+                    }),
+                    instructionArgumentNode({
+                        name: 'collection',
+                        type: optionTypeNode(publicKeyTypeNode()),
+                        docs: ['Platform collection the token joins, verified later through VerifyCollection'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'transferFee',
+                        type: optionTypeNode(
+                            structTypeNode([
+                                structFieldTypeNode({ name: 'basisPoints', type: numberTypeNode('u16') }),
+                                structFieldTypeNode({ name: 'maximum', type: numberTypeNode('u64') }),
+                            ])
+                        ),
+                        docs: ['Token-2022 only: creator royalty withheld on every transfer (basis points) and its cap per transfer (base units)'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Authority that will control the bonding curve'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA) - will be created by program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'mint',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Token mint account - must be created by client before calling'],
+                    }),
+                    instructionAccountNode({
+                        name: 'treasury',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Treasury account (holds SOL for bonding curve)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'authorityTokenAccount',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Authority's token account (ATA) to receive initial pre-buy tokens"],
+                    }),
+                    instructionAccountNode({
+                        name: 'payer',
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ['Payer for account creation and rent'],
+                    }),
+                    instructionAccountNode({
+                        name: 'systemProgram',
+                        defaultValue: publicKeyValueNode(
+                            '11111111111111111111111111111111',
+                            'systemProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['System Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'tokenProgram',
+                        defaultValue: publicKeyValueNode(
+                            'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+                            'tokenProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Token program owning the mint: the classic token program, or Token-2022 to keep the metadata in the mint instead of Metaplex'],
+                    }),
+                    instructionAccountNode({
+                        name: 'associatedTokenProgram',
+                        defaultValue: publicKeyValueNode(
+                            'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
+                            'associatedTokenProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Associated Token Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'rent',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Rent sysvar'],
+                    }),
+                    instructionAccountNode({
+                        name: 'feeRecipientAccount',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Fee recipient account (for initial pre-buy fee transfer)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'metadataAccount',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Metadata account (PDA from Metaplex)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'metaplexProgram',
+                        defaultValue: publicKeyValueNode(
+                            'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s',
+                            'metaplexProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Metaplex Token Metadata Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'protocolFeeRecipient',
+                        isSigner: false,
+                        isWritable: true,
+                        isOptional: true,
+                        docs: ['Protocol fee recipient receiving the creation fee; only needed while the protocol charges one'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'createCurve',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(31))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'decimals',
+                        type: numberTypeNode('u8'),
+                        docs: ['The number of decimals for the token.'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'curveType',
+                        type: numberTypeNode('u8'),
+                        docs: ['Curve type (0 = linear, 1 = exponential, 2 = logarithmic)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'feeBasisPoints',
+                        type: numberTypeNode('u16'),
+                        docs: ['Fees in basis points (100 = 1%)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'owner',
+                        type: arrayTypeNode(numberTypeNode('u8'), fixedCountNode(32)),
+                        docs: ['Owner username (max 32 bytes) - includes length in first byte'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'basePrice',
+                        type: numberTypeNode('u64'),
+                        docs: ['Base price in lamports per token (scaled by 1e9)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'slope',
+                        type: numberTypeNode('u64'),
+                        docs: ['Slope parameter for pricing curve (scaled by 1e9)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'maxSupply',
+                        type: numberTypeNode('u64'),
+                        docs: ['Maximum token supply'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'creatorAllocation',
+                        type: numberTypeNode('u64'),
+                        docs: ['Tokens reserved for AdminMint; counted against maxSupply, outside curve pricing'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'feeRecipient',
+                        type: publicKeyTypeNode(),
+                        docs: ['Fee recipient address (all zeroes = protocol default)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'transferFeeBasisPoints',
+                        type: numberTypeNode('u16'),
+                        docs: ['Token-2022 only: creator royalty withheld on every transfer, in basis points (0 = none); buys and sells mint and burn directly and pay none'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'maximumTransferFee',
+                        type: numberTypeNode('u64'),
+                        docs: ['Cap on the transfer fee charged per transfer (base units)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'deriveMint',
+                        type: numberTypeNode('u8'),
+                        docs: ['Create the mint as a PDA of the authority, the lowercased owner username and mintNonce instead of taking a client-created mint (0 = false, 1 = true)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'mintNonce',
+                        type: numberTypeNode('u64'),
+                        docs: ['Nonce of a derived mint, to launch more than one token per username'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Authority that will control the bonding curve'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA) - will be created by program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'mint',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Token mint account: created by the client but not initialized, or the derived mint PDA (created by the program)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'treasury',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Treasury account (holds SOL for bonding curve)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'payer',
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ['Payer for account creation'],
+                    }),
+                    instructionAccountNode({
+                        name: 'systemProgram',
+                        defaultValue: publicKeyValueNode(
+                            '11111111111111111111111111111111',
+                            'systemProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['System Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'tokenProgram',
+                        defaultValue: publicKeyValueNode(
+                            'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+                            'tokenProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Token program owning the mint: the classic token program, or Token-2022 (mint sized for the MetadataPointer extension)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                    instructionRemainingAccountsNode(argumentValueNode('protocolFeeRecipient'), {
+                        isWritable: true,
+                        isOptional: true,
+                        docs: ['Protocol fee recipient receiving the creation fee; only needed while the protocol charges one'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'createMetadata',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(32))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'tokenName',
+                        type: arrayTypeNode(numberTypeNode('u8'), fixedCountNode(32)),
+                        docs: ['Token name (max 32 bytes) - includes length in first byte'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'tokenSymbol',
+                        type: arrayTypeNode(numberTypeNode('u8'), fixedCountNode(10)),
+                        docs: ['Token symbol (max 10 bytes) - includes length in first byte'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'tokenUri',
+                        type: arrayTypeNode(numberTypeNode('u8'), fixedCountNode(200)),
+                        docs: ['Token metadata URI (max 200 bytes) - includes length in first byte'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'sellerFeeBasisPoints',
+                        type: numberTypeNode('u16'),
+                        docs: ['Royalty on secondary sales written to the metadata (basis points, max 10000). Metaplex only: Token-2022 mints need this, the creator share and the collection left zero'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'creatorShare',
+                        type: numberTypeNode('u8'),
+                        docs: ["Authority's percentage of the metadata creators; the authority is listed verified and the protocol fee recipient gets the rest (0 = the protocol fee recipient is the only creator)"],
+                    }),
+                    instructionArgumentNode({
+                        name: 'collection',
+                        type: publicKeyTypeNode(),
+                        docs: ['Platform collection the token joins, verified later through VerifyCollection (all zeroes = none)'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Authority (must hold the owner role); becomes the metadata update authority and, when it signs directly rather than as a multisig, a verified creator'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA, mint authority)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'mint',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Token mint account (Token-2022 mints grow to hold the metadata)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'payer',
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ['Payer for the metadata rent'],
+                    }),
+                    instructionAccountNode({
+                        name: 'systemProgram',
+                        defaultValue: publicKeyValueNode(
+                            '11111111111111111111111111111111',
+                            'systemProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['System Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'tokenProgram',
+                        defaultValue: publicKeyValueNode(
+                            'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+                            'tokenProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Token program owning the mint: Token-2022 keeps the metadata in the mint, the classic token program gets Metaplex metadata'],
+                    }),
+                    instructionAccountNode({
+                        name: 'rent',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Rent sysvar (Metaplex only)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'metadataAccount',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Metadata account (PDA from Metaplex; unused for Token-2022 mints)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'metaplexProgram',
+                        defaultValue: publicKeyValueNode(
+                            'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s',
+                            'metaplexProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Metaplex Token Metadata Program (unused for Token-2022 mints)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'initialBuy',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(33))
+                    ),
+                ],
+                arguments: [
+                    instructionArgumentNode({
+                        name: 'tokenAmount',
+                        type: numberTypeNode('u64'),
+                        docs: ['Amount of tokens to buy (base units)'],
+                    }),
+                    instructionArgumentNode({
+                        name: 'maxSolAmount',
+                        type: numberTypeNode('u64'),
+                        docs: ['Maximum SOL amount willing to pay (slippage protection)'],
+                    }),
+                ],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'authority',
+                        isSigner: true,
+                        isWritable: false,
+                        docs: ['Authority (must hold the owner role) receiving the tokens'],
+                    }),
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'mint',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Token mint account'],
+                    }),
+                    instructionAccountNode({
+                        name: 'treasury',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Treasury account (holds SOL for bonding curve)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'authorityTokenAccount',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ["Authority's token account (ATA) to receive initial pre-buy tokens"],
+                    }),
+                    instructionAccountNode({
+                        name: 'payer',
+                        isSigner: true,
+                        isWritable: true,
+                        docs: ['Payer of the SOL cost, fee and account rent'],
+                    }),
+                    instructionAccountNode({
+                        name: 'feeRecipientAccount',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Fee recipient account of the curve'],
+                    }),
+                    instructionAccountNode({
+                        name: 'systemProgram',
+                        defaultValue: publicKeyValueNode(
+                            '11111111111111111111111111111111',
+                            'systemProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['System Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'tokenProgram',
+                        defaultValue: publicKeyValueNode(
+                            'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+                            'tokenProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Token program owning the mint (classic or Token-2022)'],
+                    }),
+                    instructionAccountNode({
+                        name: 'associatedTokenProgram',
+                        defaultValue: publicKeyValueNode(
+                            'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
+                            'associatedTokenProgram'
+                        ),
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Associated Token Program'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA)'],
+                    }),
+                ],
+                remainingAccounts: [
+                    instructionRemainingAccountsNode(argumentValueNode('multisigSigners'), {
+                        isSigner: true,
+                        isOptional: true,
+                        docs: ['Multisig members approving when the authority is a multisig'],
+                    }),
+                ],
+            }),
+            instructionNode({
+                name: 'claimCreationRebate',
+                discriminators: [
+                    constantDiscriminatorNode(
+                        constantValueNode(numberTypeNode('u8'), numberValueNode(34))
+                    ),
+                ],
+                arguments: [],
+                accounts: [
+                    instructionAccountNode({
+                        name: 'bondingCurve',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Bonding curve state account (PDA) holding the rebate; the curve must have graduated'],
+                    }),
+                    instructionAccountNode({
+                        name: 'mint',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Token mint account'],
+                    }),
+                    instructionAccountNode({
+                        name: 'creator',
+                        isSigner: false,
+                        isWritable: true,
+                        docs: ['Curve authority receiving the rebate'],
+                    }),
+                    instructionAccountNode({
+                        name: 'config',
+                        isSigner: false,
+                        isWritable: false,
+                        docs: ['Protocol config account (PDA, checked for the emergency halt)'],
                     }),
                 ],
             }),
@@ -28,6 +28,57 @@ pub enum XTokenError {
     InvalidAuthority,
     /// Invalid profile data
     InvalidProfileData,
+    /// Invalid protocol config parameters
+    InvalidConfig,
+    /// Launches and trading are paused protocol-wide
+    ProtocolPaused,
+    /// Pricing parameters cannot change once tokens are in circulation
+    CurveParametersLocked,
+    /// Unknown or non-assignable role
+    InvalidRole,
+    /// Curve account uses an older layout; run MigrateAccount first
+    AccountNeedsMigration,
+    /// Curve account already uses the current layout
+    AccountAlreadyMigrated,
+    /// Curve is paused by its authority or operator
+    CurvePaused,
+    /// Protocol-wide emergency halt is active
+    EmergencyHalt,
+    /// Invalid multisig members or threshold
+    InvalidMultisig,
+    /// Fewer multisig members signed than the threshold requires
+    NotEnoughMultisigSigners,
+    /// Action is timelocked; queue it with QueueAction
+    TimelockRequired,
+    /// Queued action's delay has not passed yet
+    TimelockNotExpired,
+    /// AdminMint would exceed the creator allocation fixed at Initialize
+    CreatorAllocationExceeded,
+    /// Vesting amount or timestamps are invalid
+    InvalidVestingSchedule,
+    /// Vesting is not revocable or was already revoked
+    VestingNotRevocable,
+    /// Curve sold out its supply; buying is closed
+    CurveGraduated,
+    /// Curve has not graduated yet
+    CurveNotGraduated,
+    /// Mint or freeze authority already renounced
+    AuthorityRenounced,
+    /// Curve still has tokens in circulation
+    CurveActive,
+    /// Curve was launched without Metaplex metadata
+    NoMetaplexMetadata,
+    /// Curve already has its token metadata
+    MetadataAlreadyCreated,
+    /// Metaplex metadata is disabled in the protocol config
+    MetaplexDisabled,
+    /// Curve has tokens in circulation (sold or minted); the initial buy is closed
+    InitialBuyClosed,
+    /// Curve holds no creation rebate
+    NoCreationRebate,
+    /// Royalty, creators, collection and metadata updates need Metaplex
+    /// metadata; Token-2022 in-mint metadata has no place for them
+    MetaplexMetadataRequired,
 }
 
 impl From<XTokenError> for ProgramError {
@@ -46,6 +97,31 @@ impl From<XTokenError> for ProgramError {
             XTokenError::ArithmeticOverflow => ProgramError::ArithmeticOverflow,
             XTokenError::InvalidAuthority => ProgramError::InvalidArgument,
             XTokenError::InvalidProfileData => ProgramError::InvalidArgument,
+            XTokenError::InvalidConfig => ProgramError::InvalidArgument,
+            XTokenError::ProtocolPaused => ProgramError::Custom(error as u32),
+            XTokenError::CurveParametersLocked => ProgramError::InvalidArgument,
+            XTokenError::InvalidRole => ProgramError::InvalidArgument,
+            XTokenError::AccountNeedsMigration => ProgramError::Custom(error as u32),
+            XTokenError::AccountAlreadyMigrated => ProgramError::InvalidArgument,
+            XTokenError::CurvePaused => ProgramError::Custom(error as u32),
+            XTokenError::EmergencyHalt => ProgramError::Custom(error as u32),
+            XTokenError::InvalidMultisig => ProgramError::InvalidArgument,
+            XTokenError::NotEnoughMultisigSigners => ProgramError::Custom(error as u32),
+            XTokenError::TimelockRequired => ProgramError::Custom(error as u32),
+            XTokenError::TimelockNotExpired => ProgramError::Custom(error as u32),
+            XTokenError::CreatorAllocationExceeded => ProgramError::Custom(error as u32),
+            XTokenError::InvalidVestingSchedule => ProgramError::InvalidArgument,
+            XTokenError::VestingNotRevocable => ProgramError::InvalidArgument,
+            XTokenError::CurveGraduated => ProgramError::Custom(error as u32),
+            XTokenError::CurveNotGraduated => ProgramError::Custom(error as u32),
+            XTokenError::AuthorityRenounced => ProgramError::Custom(error as u32),
+            XTokenError::CurveActive => ProgramError::Custom(error as u32),
+            XTokenError::NoMetaplexMetadata => ProgramError::Custom(error as u32),
+            XTokenError::MetadataAlreadyCreated => ProgramError::Custom(error as u32),
+            XTokenError::MetaplexDisabled => ProgramError::Custom(error as u32),
+            XTokenError::InitialBuyClosed => ProgramError::Custom(error as u32),
+            XTokenError::NoCreationRebate => ProgramError::Custom(error as u32),
+            XTokenError::MetaplexMetadataRequired => ProgramError::Custom(error as u32),
         }
     }
 }
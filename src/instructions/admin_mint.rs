@@ -3,12 +3,13 @@ use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
 
 use crate::{
     error::XTokenError,
-    state::{AccountData, XToken},
+    state::{check_authority_signed, AccountData, Role, XToken},
+    token_interface::{check_token_program, mint_to},
 };
 
 /// Accounts for AdminMint instruction
 pub struct AdminMintAccounts<'info> {
-    /// Authority (must match bonding curve authority)
+    /// Authority (must hold the minter role)
     pub authority: &'info AccountInfo,
     /// Bonding curve state account (PDA)
     pub bonding_curve: &'info AccountInfo,
@@ -16,13 +17,17 @@ pub struct AdminMintAccounts<'info> {
     pub mint: &'info AccountInfo,
     /// Recipient token account (ATA)
     pub recipient_token_account: &'info AccountInfo,
-    /// Token program
+    /// Token program owning the mint (classic or Token-2022)
     pub token_program: &'info AccountInfo,
+    /// Protocol config account (PDA, checked for the emergency halt)
+    pub config: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig (remaining accounts)
+    pub signers: &'info [AccountInfo],
 }
 
 impl<'info> AdminMintAccounts<'info> {
     pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
-        if accounts.len() < 5 {
+        if accounts.len() < 6 {
             return Err(ProgramError::NotEnoughAccountKeys);
         }
         Ok(Self {
@@ -31,6 +36,8 @@ impl<'info> AdminMintAccounts<'info> {
             mint: &accounts[2],
             recipient_token_account: &accounts[3],
             token_program: &accounts[4],
+            config: &accounts[5],
+            signers: &accounts[6..],
         })
     }
 }
@@ -77,22 +84,26 @@ impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for AdminMint<'info> {
 
 impl<'info> AdminMint<'info> {
     pub fn handler(&mut self) -> Result<(), ProgramError> {
-        if !self.accounts.authority.is_signer() {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
 
         if self.instruction_data.amount == 0 {
             return Err(XTokenError::InvalidTokenAmount.into());
         }
 
-        // validate state
-        let bonding_curve_data = self.accounts.bonding_curve.try_borrow_data()?;
-        let state = XToken::load(&bonding_curve_data)?;
-        if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
-        if state.token_mint != *self.accounts.mint.key() { return Err(XTokenError::InvalidAccountData.into()); }
-        let admin = state.get_admin();
-        let is_auth = state.authority == *self.accounts.authority.key() || admin == *self.accounts.authority.key();
-        if !is_auth { return Err(XTokenError::InvalidAuthority.into()); }
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // validate state and count the mint against the creator allocation
+        {
+            let mut bonding_curve_data = self.accounts.bonding_curve.try_borrow_mut_data()?;
+            let state = XToken::load_mut(&mut bonding_curve_data)?;
+            if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+            if state.paused != 0 { return Err(XTokenError::CurvePaused.into()); }
+            if state.token_mint != *self.accounts.mint.key() { return Err(XTokenError::InvalidAccountData.into()); }
+            if !state.has_role(Role::Minter, self.accounts.authority.key()) { return Err(XTokenError::InvalidAuthority.into()); }
+            state.record_creator_mint(self.instruction_data.amount)?;
+        }
 
         // derive bonding curve PDA and signer seeds
         let (bonding_curve_pda, bonding_curve_bump) = pinocchio::pubkey::find_program_address(
@@ -103,6 +114,8 @@ impl<'info> AdminMint<'info> {
             return Err(ProgramError::InvalidSeeds);
         }
 
+        check_token_program(self.accounts.mint, self.accounts.token_program)?;
+
         // mint tokens using bonding curve as mint authority
         let bump_bytes = [bonding_curve_bump];
         let seeds = [
@@ -112,13 +125,14 @@ impl<'info> AdminMint<'info> {
         ];
         let signer = pinocchio::instruction::Signer::from(&seeds);
 
-        pinocchio_token::instructions::MintTo {
-            mint: self.accounts.mint,
-            account: self.accounts.recipient_token_account,
-            mint_authority: self.accounts.bonding_curve,
-            amount: self.instruction_data.amount,
-        }
-        .invoke_signed(&[signer])?;
+        mint_to(
+            self.accounts.token_program,
+            self.accounts.mint,
+            self.accounts.recipient_token_account,
+            self.accounts.bonding_curve,
+            self.instruction_data.amount,
+            &[signer],
+        )?;
 
         Ok(())
     }
@@ -3,7 +3,8 @@ use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
 
 use crate::{
     error::XTokenError,
-    state::{AccountData, XToken},
+    state::{AccountData, ProtocolConfig, XToken},
+    token_interface::{check_token_program, mint_to},
 };
 
 /// Accounts for BuyTokens instruction
@@ -22,15 +23,17 @@ pub struct BuyTokensAccounts<'info> {
     pub fee_recipient: &'info AccountInfo,
     /// System program
     pub system_program: &'info AccountInfo,
-    /// Token program
+    /// Token program owning the mint (classic or Token-2022)
     pub token_program: &'info AccountInfo,
     /// Associated token program
     pub associated_token_program: &'info AccountInfo,
+    /// Protocol config account (PDA)
+    pub config: &'info AccountInfo,
 }
 
 impl<'info> BuyTokensAccounts<'info> {
     pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
-        if accounts.len() < 9 {
+        if accounts.len() < 10 {
             return Err(ProgramError::NotEnoughAccountKeys);
         }
 
@@ -44,6 +47,7 @@ impl<'info> BuyTokensAccounts<'info> {
             system_program: &accounts[6],
             token_program: &accounts[7],
             associated_token_program: &accounts[8],
+            config: &accounts[9],
         })
     }
 }
@@ -120,6 +124,11 @@ impl<'info> BuyTokens<'info> {
             return Err(XTokenError::InvalidTokenAmount.into());
         }
 
+        let config = ProtocolConfig::from_account(self.accounts.config)?;
+        if config.paused != 0 {
+            return Err(XTokenError::ProtocolPaused.into());
+        }
+
         // -------- Phase 1: Read bonding curve snapshot (immutable borrow) --------
         let (bump, _token_mint_key, total_supply_snapshot, max_supply_snapshot) = {
             let bonding_curve_data = self.accounts.bonding_curve.try_borrow_data()?;
@@ -129,15 +138,30 @@ impl<'info> BuyTokens<'info> {
                 return Err(XTokenError::AccountNotInitialized.into());
             }
 
+            if bonding_curve.paused != 0 {
+                return Err(XTokenError::CurvePaused.into());
+            }
+
+            if bonding_curve.graduated != 0 {
+                return Err(XTokenError::CurveGraduated.into());
+            }
+
             if bonding_curve.token_mint != *self.accounts.mint.key() {
                 return Err(XTokenError::InvalidAccountData.into());
             }
 
+            // Fees must go to the curve's current fee recipient
+            if bonding_curve.fee_recipient != *self.accounts.fee_recipient.key() {
+                return Err(XTokenError::InvalidAccountData.into());
+            }
+
             // Calculate price & fee using immutable snapshot
             // (We compute below after extracting fields to minimize borrow scope if needed later.)
-            (bonding_curve.bump, bonding_curve.token_mint, bonding_curve.total_supply, bonding_curve.max_supply)
+            (bonding_curve.bump, bonding_curve.token_mint, bonding_curve.total_supply, bonding_curve.curve_max_supply())
         }; // immutable borrow dropped here
 
+        check_token_program(self.accounts.mint, self.accounts.token_program)?;
+
         // Validate supply bounds using snapshot
         let new_supply = total_supply_snapshot
             .checked_add(self.instruction_data.token_amount)
@@ -164,12 +188,11 @@ impl<'info> BuyTokens<'info> {
             return Err(XTokenError::SlippageExceeded.into());
         }
 
-        // Cap treasury to 84 SOL: sol_reserve + incoming (without fee) must not exceed cap
-        const SOL_CAP_LAMPORTS: u64 = 84_000_000_000; // 84 * 1e9
+        // Cap treasury: sol_reserve + incoming (without fee) must not exceed protocol cap
         let new_reserve = sol_reserve_snapshot
             .checked_add(total_cost)
             .ok_or(ProgramError::ArithmeticOverflow)?;
-        if new_reserve > SOL_CAP_LAMPORTS {
+        if new_reserve > config.sol_cap_lamports {
             return Err(ProgramError::InvalidArgument);
         }
 
@@ -221,13 +244,14 @@ impl<'info> BuyTokens<'info> {
         }
 
         // Mint tokens to buyer (PDA as mint authority)
-        pinocchio_token::instructions::MintTo {
-            mint: self.accounts.mint,
-            account: self.accounts.buyer_token_account,
-            mint_authority: self.accounts.bonding_curve,
-            amount: self.instruction_data.token_amount,
-        }
-        .invoke_signed(&[signer])?;
+        mint_to(
+            self.accounts.token_program,
+            self.accounts.mint,
+            self.accounts.buyer_token_account,
+            self.accounts.bonding_curve,
+            self.instruction_data.token_amount,
+            &[signer],
+        )?;
 
         // -------- Phase 3: Re-borrow mutable to update state --------
         {
@@ -0,0 +1,83 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    error::XTokenError,
+    instructions::Instruction,
+    state::{check_authority_signed, AccountData, PendingAction, Role, XToken},
+};
+
+/// Accounts for CancelAction instruction
+pub struct CancelActionAccounts<'info> {
+    /// Authority (owner, or holder of the role the queued instruction requires)
+    pub authority: &'info AccountInfo,
+    /// Bonding curve state account (PDA)
+    pub bonding_curve: &'info AccountInfo,
+    /// Pending action account (PDA)
+    pub action: &'info AccountInfo,
+    /// Account that queued the action (receives the rent back)
+    pub rent_recipient: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig (remaining accounts)
+    pub signers: &'info [AccountInfo],
+}
+
+impl<'info> CancelActionAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 4 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            authority: &accounts[0],
+            bonding_curve: &accounts[1],
+            action: &accounts[2],
+            rent_recipient: &accounts[3],
+            signers: &accounts[4..],
+        })
+    }
+}
+
+/// Drop a queued action before it executes
+pub struct CancelAction<'info> {
+    pub accounts: CancelActionAccounts<'info>,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for CancelAction<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let accounts = CancelActionAccounts::try_from(accounts)?;
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> CancelAction<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
+
+        let action = PendingAction::from_account(self.accounts.action, self.accounts.bonding_curve.key())?;
+        if action.proposer != *self.accounts.rent_recipient.key() {
+            return Err(XTokenError::InvalidAccountData.into());
+        }
+
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        {
+            let bonding_curve_data = self.accounts.bonding_curve.try_borrow_data()?;
+            let state = XToken::load(&bonding_curve_data)?;
+            if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+
+            let role = Instruction::try_from(action.kind)?
+                .timelock_role()
+                .ok_or(ProgramError::InvalidAccountData)?;
+            let authority = self.accounts.authority.key();
+            if !state.has_role(Role::Owner, authority) && !state.has_role(role, authority) {
+                return Err(XTokenError::InvalidAuthority.into());
+            }
+        }
+
+        PendingAction::close(self.accounts.action, self.accounts.rent_recipient)
+    }
+}
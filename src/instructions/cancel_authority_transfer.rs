@@ -0,0 +1,62 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    error::XTokenError,
+    state::{check_authority_signed, AccountData, Role, XToken},
+};
+
+/// Accounts for CancelAuthorityTransfer instruction
+pub struct CancelAuthorityTransferAccounts<'info> {
+    /// Current authority (must match bonding curve authority)
+    pub authority: &'info AccountInfo,
+    /// Bonding curve state account (PDA)
+    pub bonding_curve: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig (remaining accounts)
+    pub signers: &'info [AccountInfo],
+}
+
+impl<'info> CancelAuthorityTransferAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 2 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            authority: &accounts[0],
+            bonding_curve: &accounts[1],
+            signers: &accounts[2..],
+        })
+    }
+}
+
+pub struct CancelAuthorityTransfer<'info> {
+    pub accounts: CancelAuthorityTransferAccounts<'info>,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for CancelAuthorityTransfer<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let accounts = CancelAuthorityTransferAccounts::try_from(accounts)?;
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> CancelAuthorityTransfer<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
+
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let mut bonding_curve_data = self.accounts.bonding_curve.try_borrow_mut_data()?;
+        let state = XToken::load_mut(&mut bonding_curve_data)?;
+        if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+        if !state.has_role(Role::Owner, self.accounts.authority.key()) { return Err(XTokenError::InvalidAuthority.into()); }
+
+        state.cancel_authority_transfer();
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+use pinocchio_log::log;
+
+use crate::{
+    error::XTokenError,
+    state::{AccountData, XToken},
+};
+
+/// Accounts for ClaimCreationRebate instruction
+pub struct ClaimCreationRebateAccounts<'info> {
+    /// Bonding curve state account (PDA) holding the rebate
+    pub bonding_curve: &'info AccountInfo,
+    /// Token mint account
+    pub mint: &'info AccountInfo,
+    /// Curve authority receiving the rebate
+    pub creator: &'info AccountInfo,
+    /// Protocol config account (PDA, checked for the emergency halt)
+    pub config: &'info AccountInfo,
+}
+
+impl<'info> ClaimCreationRebateAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 4 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            bonding_curve: &accounts[0],
+            mint: &accounts[1],
+            creator: &accounts[2],
+            config: &accounts[3],
+        })
+    }
+}
+
+/// Pay the creation fee rebate held in a graduated curve's account to the
+/// curve authority. Permissionless: the rebate can only go to the authority.
+pub struct ClaimCreationRebate<'info> {
+    pub accounts: ClaimCreationRebateAccounts<'info>,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for ClaimCreationRebate<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let accounts = ClaimCreationRebateAccounts::try_from(accounts)?;
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> ClaimCreationRebate<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let rebate = {
+            let mut bonding_curve_data = self.accounts.bonding_curve.try_borrow_mut_data()?;
+            let state = XToken::load_mut(&mut bonding_curve_data)?;
+            if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+            if state.token_mint != *self.accounts.mint.key() { return Err(XTokenError::InvalidAccountData.into()); }
+            if state.authority != *self.accounts.creator.key() { return Err(XTokenError::InvalidAuthority.into()); }
+            state.claim_creation_rebate()?
+        };
+
+        // the curve account is program-owned, so the lamports move directly
+        {
+            let mut curve_lamports = self.accounts.bonding_curve.try_borrow_mut_lamports()?;
+            *curve_lamports = curve_lamports
+                .checked_sub(rebate)
+                .ok_or(XTokenError::ArithmeticOverflow)?;
+        }
+        {
+            let mut creator_lamports = self.accounts.creator.try_borrow_mut_lamports()?;
+            *creator_lamports = creator_lamports
+                .checked_add(rebate)
+                .ok_or(XTokenError::ArithmeticOverflow)?;
+        }
+
+        log!("creation_rebate_paid: {} lamports", rebate);
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+};
+
+use crate::{
+    error::XTokenError,
+    state::{AccountData, Vesting},
+    token_interface::{check_token_program, transfer_checked},
+};
+
+/// Accounts for ClaimVested instruction
+pub struct ClaimVestedAccounts<'info> {
+    /// Beneficiary (must match vesting beneficiary)
+    pub beneficiary: &'info AccountInfo,
+    /// Vesting account (PDA)
+    pub vesting: &'info AccountInfo,
+    /// Escrow token account (ATA owned by the vesting PDA)
+    pub escrow: &'info AccountInfo,
+    /// Beneficiary token account receiving the unlocked tokens
+    pub beneficiary_token_account: &'info AccountInfo,
+    /// Token program owning the mint (classic or Token-2022)
+    pub token_program: &'info AccountInfo,
+    /// Token mint account
+    pub mint: &'info AccountInfo,
+}
+
+impl<'info> ClaimVestedAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 6 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            beneficiary: &accounts[0],
+            vesting: &accounts[1],
+            escrow: &accounts[2],
+            beneficiary_token_account: &accounts[3],
+            token_program: &accounts[4],
+            mint: &accounts[5],
+        })
+    }
+}
+
+/// Release the unlocked, unclaimed part of a vesting to its beneficiary; a
+/// Token-2022 transfer fee is withheld from what the beneficiary receives
+pub struct ClaimVested<'info> {
+    pub accounts: ClaimVestedAccounts<'info>,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for ClaimVested<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let accounts = ClaimVestedAccounts::try_from(accounts)?;
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> ClaimVested<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        if !self.accounts.beneficiary.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if unsafe { *self.accounts.vesting.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+
+        let (amount, bonding_curve, bump) = {
+            let mut vesting_data = self.accounts.vesting.try_borrow_mut_data()?;
+            let vesting = Vesting::load_mut(&mut vesting_data)?;
+            if vesting.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+            if vesting.beneficiary != *self.accounts.beneficiary.key() { return Err(XTokenError::InvalidAuthority.into()); }
+            if vesting.escrow != *self.accounts.escrow.key() { return Err(XTokenError::InvalidAccountData.into()); }
+
+            let amount = vesting.claimable_amount(now);
+            if amount == 0 {
+                return Err(XTokenError::InvalidTokenAmount.into());
+            }
+            vesting.claimed_amount = vesting
+                .claimed_amount
+                .checked_add(amount)
+                .ok_or(XTokenError::ArithmeticOverflow)?;
+            (amount, vesting.bonding_curve, vesting.bump)
+        };
+
+        check_token_program(self.accounts.mint, self.accounts.token_program)?;
+
+        // transfer out of escrow using the vesting PDA as owner
+        let bump_bytes = [bump];
+        let seeds = [
+            Seed::from(Vesting::SEED_PREFIX),
+            Seed::from(&bonding_curve),
+            Seed::from(self.accounts.beneficiary.key()),
+            Seed::from(&bump_bytes),
+        ];
+        let signer = Signer::from(&seeds);
+
+        transfer_checked(
+            self.accounts.token_program,
+            self.accounts.escrow,
+            self.accounts.mint,
+            self.accounts.beneficiary_token_account,
+            self.accounts.vesting,
+            amount,
+            &[signer],
+        )?;
+
+        Ok(())
+    }
+}
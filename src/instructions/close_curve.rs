@@ -0,0 +1,181 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+};
+use pinocchio_log::log;
+use pinocchio_token::instructions::AuthorityType;
+
+use crate::{
+    error::XTokenError,
+    state::{check_authority_signed, AccountData, ProtocolConfig, Role, XToken},
+    token_interface::{check_token_program, set_authority},
+};
+
+/// Accounts for CloseCurve instruction
+pub struct CloseCurveAccounts<'info> {
+    /// Authority (must hold the owner role)
+    pub authority: &'info AccountInfo,
+    /// Bonding curve state account (PDA, closed)
+    pub bonding_curve: &'info AccountInfo,
+    /// Token mint account
+    pub mint: &'info AccountInfo,
+    /// Treasury PDA account (system-owned, emptied)
+    pub treasury: &'info AccountInfo,
+    /// Recipient of the curve and treasury lamports
+    pub recipient: &'info AccountInfo,
+    /// System program
+    pub system_program: &'info AccountInfo,
+    /// Token program
+    pub token_program: &'info AccountInfo,
+    /// Protocol config account (PDA, checked for the emergency halt)
+    pub config: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig, and the
+    /// protocol fee recipient when a creation rebate is forfeited (remaining accounts)
+    pub signers: &'info [AccountInfo],
+}
+
+impl<'info> CloseCurveAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 8 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            authority: &accounts[0],
+            bonding_curve: &accounts[1],
+            mint: &accounts[2],
+            treasury: &accounts[3],
+            recipient: &accounts[4],
+            system_program: &accounts[5],
+            token_program: &accounts[6],
+            config: &accounts[7],
+            signers: &accounts[8..],
+        })
+    }
+}
+
+/// Close a curve with no tokens in circulation, neither sold on the curve nor
+/// minted from the creator allocation: the curve state and treasury
+/// lamports go to the recipient and the mint loses its remaining authorities,
+/// so nothing can be minted on it again. Metaplex metadata is owned by the
+/// metadata program and keeps its rent. A creation rebate still held by the
+/// curve was never earned and goes to the protocol fee recipient.
+pub struct CloseCurve<'info> {
+    pub accounts: CloseCurveAccounts<'info>,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for CloseCurve<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let accounts = CloseCurveAccounts::try_from(accounts)?;
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> CloseCurve<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
+
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let (bump, renounced_authorities, creation_rebate) = {
+            let bonding_curve_data = self.accounts.bonding_curve.try_borrow_data()?;
+            let state = XToken::load(&bonding_curve_data)?;
+            if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+            if state.token_mint != *self.accounts.mint.key() { return Err(XTokenError::InvalidAccountData.into()); }
+            if !state.has_role(Role::Owner, self.accounts.authority.key()) { return Err(XTokenError::InvalidAuthority.into()); }
+            if state.total_supply != 0 || state.creator_minted != 0 { return Err(XTokenError::CurveActive.into()); }
+            (state.bump, state.renounced_authorities, state.creation_rebate)
+        };
+
+        // derive treasury PDA and signer seeds
+        let (treasury_pda, treasury_bump) = pinocchio::pubkey::find_program_address(
+            &[b"treasury", self.accounts.mint.key().as_ref()],
+            &crate::ID,
+        );
+        if treasury_pda != *self.accounts.treasury.key() {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        check_token_program(self.accounts.mint, self.accounts.token_program)?;
+
+        // drop the mint authorities still held by the curve
+        let bump_bytes = [bump];
+        let seeds = [
+            Seed::from(XToken::SEED_PREFIX),
+            Seed::from(self.accounts.mint.key().as_ref()),
+            Seed::from(&bump_bytes),
+        ];
+
+        if renounced_authorities & XToken::RENOUNCED_MINT == 0 {
+            set_authority(
+                self.accounts.token_program,
+                self.accounts.mint,
+                self.accounts.bonding_curve,
+                AuthorityType::MintTokens,
+                None,
+                &[Signer::from(&seeds)],
+            )?;
+        }
+
+        if renounced_authorities & XToken::RENOUNCED_FREEZE == 0 {
+            set_authority(
+                self.accounts.token_program,
+                self.accounts.mint,
+                self.accounts.bonding_curve,
+                AuthorityType::FreezeAccount,
+                None,
+                &[Signer::from(&seeds)],
+            )?;
+        }
+
+        // empty the treasury; with no lamports left the runtime removes it
+        let treasury_lamports = self.accounts.treasury.lamports();
+        if treasury_lamports > 0 {
+            let tb = [treasury_bump];
+            let treasury_seeds = [
+                Seed::from(b"treasury"),
+                Seed::from(self.accounts.mint.key().as_ref()),
+                Seed::from(&tb),
+            ];
+
+            pinocchio_system::instructions::Transfer {
+                from: self.accounts.treasury,
+                to: self.accounts.recipient,
+                lamports: treasury_lamports,
+            }
+            .invoke_signed(&[Signer::from(&treasury_seeds)])?;
+        }
+
+        if creation_rebate > 0 {
+            let config = ProtocolConfig::from_account(self.accounts.config)?;
+            let protocol_fee_recipient = self
+                .accounts
+                .signers
+                .iter()
+                .find(|account| *account.key() == config.fee_recipient)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            {
+                let mut curve_lamports = self.accounts.bonding_curve.try_borrow_mut_lamports()?;
+                *curve_lamports = curve_lamports
+                    .checked_sub(creation_rebate)
+                    .ok_or(XTokenError::ArithmeticOverflow)?;
+            }
+            {
+                let mut recipient_lamports = protocol_fee_recipient.try_borrow_mut_lamports()?;
+                *recipient_lamports = recipient_lamports
+                    .checked_add(creation_rebate)
+                    .ok_or(XTokenError::ArithmeticOverflow)?;
+            }
+            log!("creation_rebate_forfeited: {} lamports", creation_rebate);
+        }
+
+        XToken::close(self.accounts.bonding_curve, self.accounts.recipient)
+    }
+}
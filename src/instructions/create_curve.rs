@@ -0,0 +1,404 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+};
+use pinocchio_log::log;
+
+use crate::{
+    error::XTokenError,
+    instructions::initialize::InitializeInstructionData,
+    state::{check_authority_signed, AccountData, ProtocolConfig, XToken},
+    token_interface::{
+        check_token_program, initialize_metadata_pointer, initialize_mint2, initialize_transfer_fee_config,
+        is_token_program, mint_len, TOKEN_2022_PROGRAM_ID,
+    },
+};
+
+// Seed prefix of mints derived from (authority, username, nonce)
+pub const MINT_SEED: &[u8] = b"mint";
+
+/// Accounts for CreateCurve instruction
+pub struct CreateCurveAccounts<'info> {
+    /// Authority that will control the bonding curve
+    pub authority: &'info AccountInfo,
+    /// Bonding curve state account (PDA, created)
+    pub bonding_curve: &'info AccountInfo,
+    /// Token mint account: created by the client but not initialized, or the
+    /// derived mint PDA created here
+    pub mint: &'info AccountInfo,
+    /// Treasury account (PDA, created) - holds SOL for bonding curve
+    pub treasury: &'info AccountInfo,
+    /// Payer for account creation
+    pub payer: &'info AccountInfo,
+    /// System program
+    pub system_program: &'info AccountInfo,
+    /// Token program owning the mint (classic or Token-2022)
+    pub token_program: &'info AccountInfo,
+    /// Protocol config account (PDA)
+    pub config: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig, and the
+    /// protocol fee recipient receiving the creation fee while the protocol
+    /// charges one (remaining accounts)
+    pub signers: &'info [AccountInfo],
+}
+
+impl<'info> CreateCurveAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 8 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            authority: &accounts[0],
+            bonding_curve: &accounts[1],
+            mint: &accounts[2],
+            treasury: &accounts[3],
+            payer: &accounts[4],
+            system_program: &accounts[5],
+            token_program: &accounts[6],
+            config: &accounts[7],
+            signers: &accounts[8..],
+        })
+    }
+}
+
+/// Instruction data for CreateCurve
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct CreateCurveInstructionData {
+    /// Token decimals
+    pub decimals: u8,
+    /// Curve type (0 = linear, 1 = exponential, 2 = logarithmic, 3 = cpmm)
+    pub curve_type: u8,
+    /// Fees in basis points (100 = 1%)
+    pub fee_basis_points: u16,
+    /// Owner username (max 32 bytes) - includes length in first byte
+    pub owner: [u8; 32],
+    /// Base price in lamports per token (scaled by 1e9)
+    pub base_price: u64,
+    /// Slope parameter for pricing curve (scaled by 1e9)
+    pub slope: u64,
+    /// Maximum token supply
+    pub max_supply: u64,
+    /// Tokens reserved for AdminMint; counted against max_supply, outside curve pricing
+    pub creator_allocation: u64,
+    /// Fee recipient (all zeroes = protocol default)
+    pub fee_recipient: Pubkey,
+    /// Token-2022 only: creator royalty withheld on every transfer, in basis
+    /// points (0 = none)
+    pub transfer_fee_basis_points: u16,
+    /// Cap on the transfer fee charged per transfer (base units)
+    pub maximum_transfer_fee: u64,
+    /// Create the mint as a PDA of the authority, the lowercased owner
+    /// username and `mint_nonce` instead of taking a client-created mint
+    /// (0 = false, 1 = true)
+    pub derive_mint: u8,
+    /// Nonce of a derived mint, to launch more than one token per username
+    pub mint_nonce: u64,
+}
+
+impl CreateCurveInstructionData {
+    pub const LEN: usize = core::mem::size_of::<CreateCurveInstructionData>();
+}
+
+impl<'info> TryFrom<&'info [u8]> for CreateCurveInstructionData {
+    type Error = ProgramError;
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let instruction_data = bytemuck::try_from_bytes::<Self>(data)
+            .copied()
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        if instruction_data.derive_mint > 1 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok(instruction_data)
+    }
+}
+
+impl From<&InitializeInstructionData> for CreateCurveInstructionData {
+    fn from(data: &InitializeInstructionData) -> Self {
+        Self {
+            decimals: data.decimals,
+            curve_type: data.curve_type,
+            fee_basis_points: data.fee_basis_points,
+            owner: data.owner,
+            base_price: data.base_price,
+            slope: data.slope,
+            max_supply: data.max_supply,
+            creator_allocation: data.creator_allocation,
+            fee_recipient: data.fee_recipient,
+            transfer_fee_basis_points: data.transfer_fee_basis_points,
+            maximum_transfer_fee: data.maximum_transfer_fee,
+            derive_mint: 0,
+            mint_nonce: 0,
+        }
+    }
+}
+
+/// First launch step: create the bonding curve and treasury PDAs, initialize
+/// the mint with the curve as mint and freeze authority, charge the protocol
+/// creation fee and write the curve state. Metadata (CreateMetadata) and the creator's pre-buy (InitialBuy)
+/// follow in the same or later transactions.
+pub struct CreateCurve<'info> {
+    pub accounts: CreateCurveAccounts<'info>,
+    pub instruction_data: CreateCurveInstructionData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for CreateCurve<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        let accounts = CreateCurveAccounts::try_from(accounts)?;
+        let instruction_data = CreateCurveInstructionData::try_from(data)?;
+        Ok(Self { accounts, instruction_data })
+    }
+}
+
+impl<'info> CreateCurve<'info> {
+    /// Token-2022 mints always get a metadata pointer here, so they must be
+    /// sized for the MetadataPointer extension
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        let config = ProtocolConfig::from_account(self.accounts.config)?;
+        self.create(&config, true)
+    }
+
+    /// Create the curve; `metadata_pointer` points a Token-2022 mint at itself
+    /// so CreateMetadata can write the metadata into it later
+    pub(crate) fn create(&self, config: &ProtocolConfig, metadata_pointer: bool) -> Result<(), ProgramError> {
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
+        if !self.accounts.payer.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Validate curve parameters (0=linear,1=exp,2=log,3=cpmm)
+        if self.instruction_data.curve_type > 3
+            || self.instruction_data.base_price == 0
+            || self.instruction_data.max_supply == 0
+        {
+            return Err(XTokenError::InvalidCurveParameters.into());
+        }
+
+        // Protocol-wide defaults and limits
+        if config.paused != 0 {
+            return Err(XTokenError::ProtocolPaused.into());
+        }
+        if !config.fee_in_bounds(self.instruction_data.fee_basis_points) {
+            return Err(XTokenError::InvalidCurveParameters.into());
+        }
+
+        let fee_recipient = if self.instruction_data.fee_recipient == [0u8; 32] {
+            config.fee_recipient
+        } else {
+            self.instruction_data.fee_recipient
+        };
+        let owner = InitializeInstructionData::extract_str(&self.instruction_data.owner)?;
+
+        let (bonding_curve_address, bump) = pinocchio::pubkey::find_program_address(
+            &[XToken::SEED_PREFIX, self.accounts.mint.key().as_ref()],
+            &crate::ID,
+        );
+        if bonding_curve_address != *self.accounts.bonding_curve.key() {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let (treasury_address, treasury_bump) = pinocchio::pubkey::find_program_address(
+            &[b"treasury", self.accounts.mint.key().as_ref()],
+            &crate::ID,
+        );
+        if treasury_address != *self.accounts.treasury.key() {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        // Create bonding curve PDA account
+        let rent = Rent::get()?;
+        let bump_bytes = [bump];
+        let seeds = [
+            Seed::from(XToken::SEED_PREFIX),
+            Seed::from(self.accounts.mint.key().as_ref()),
+            Seed::from(&bump_bytes),
+        ];
+
+        pinocchio_system::instructions::CreateAccount {
+            from: self.accounts.payer,
+            to: self.accounts.bonding_curve,
+            space: XToken::LEN as u64,
+            lamports: rent.minimum_balance(XToken::LEN),
+            owner: &crate::ID,
+        }
+        .invoke_signed(&[Signer::from(&seeds)])?;
+
+        // Create treasury PDA account (system-owned, space=0)
+        let treasury_bump_bytes = [treasury_bump];
+        let treasury_seeds = [
+            Seed::from(b"treasury"),
+            Seed::from(self.accounts.mint.key().as_ref()),
+            Seed::from(&treasury_bump_bytes),
+        ];
+
+        pinocchio_system::instructions::CreateAccount {
+            from: self.accounts.payer,
+            to: self.accounts.treasury,
+            space: 0,
+            lamports: rent.minimum_balance(0),
+            owner: &pinocchio_system::ID,
+        }
+        .invoke_signed(&[Signer::from(&treasury_seeds)])?;
+
+        if self.instruction_data.derive_mint != 0 {
+            self.create_derived_mint(owner, metadata_pointer, &rent)?;
+        }
+
+        // Verify mint account exists (should be created by client)
+        if self.accounts.mint.data_is_empty() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        check_token_program(self.accounts.mint, self.accounts.token_program)?;
+        let token_2022 = *self.accounts.token_program.key() == TOKEN_2022_PROGRAM_ID;
+
+        let transfer_fee_basis_points = self.instruction_data.transfer_fee_basis_points;
+        if transfer_fee_basis_points > 10_000 || (transfer_fee_basis_points != 0 && !token_2022) {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        // the curve PDA collects the withheld fees for HarvestWithheldFees
+        if transfer_fee_basis_points != 0 {
+            initialize_transfer_fee_config(
+                self.accounts.token_program,
+                self.accounts.mint,
+                &bonding_curve_address,
+                transfer_fee_basis_points,
+                self.instruction_data.maximum_transfer_fee,
+            )?;
+        }
+
+        // Token-2022 mints carry their own metadata, pointed at from the mint
+        if token_2022 && metadata_pointer {
+            initialize_metadata_pointer(
+                self.accounts.token_program,
+                self.accounts.mint,
+                self.accounts.authority.key(),
+                self.accounts.mint.key(),
+            )?;
+        }
+
+        // Initialize mint with bonding curve as authority
+        initialize_mint2(
+            self.accounts.token_program,
+            self.accounts.mint,
+            self.instruction_data.decimals,
+            &bonding_curve_address,
+            Some(&bonding_curve_address),
+        )?;
+
+        let creation_rebate = self.charge_creation_fee(config)?;
+
+        let mut bonding_curve_data = self.accounts.bonding_curve.try_borrow_mut_data()?;
+        let bonding_curve = XToken::load_uninitialized_mut(&mut bonding_curve_data)?;
+
+        bonding_curve.initialize(
+            *self.accounts.authority.key(),
+            *self.accounts.mint.key(),
+            self.instruction_data.curve_type,
+            self.instruction_data.base_price,
+            self.instruction_data.slope,
+            self.instruction_data.max_supply,
+            self.instruction_data.fee_basis_points,
+            fee_recipient,
+            owner,
+            bump,
+        )?;
+        bonding_curve.set_creator_allocation(self.instruction_data.creator_allocation)?;
+        // CreateMetadata records where the metadata ends up
+        bonding_curve.metadata_mode = XToken::METADATA_NONE;
+        bonding_curve.creation_rebate = creation_rebate;
+
+        Ok(())
+    }
+
+    /// Charge the payer the protocol creation fee: the protocol fee recipient
+    /// gets its share and the rebate is parked in the curve account until
+    /// graduation. Returns the rebate held.
+    fn charge_creation_fee(&self, config: &ProtocolConfig) -> Result<u64, ProgramError> {
+        if config.creation_fee_lamports == 0 {
+            return Ok(0);
+        }
+
+        let (protocol_fee, rebate) = config.creation_fee_split();
+        if protocol_fee > 0 {
+            let protocol_fee_recipient = self
+                .accounts
+                .signers
+                .iter()
+                .find(|account| *account.key() == config.fee_recipient)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+            pinocchio_system::instructions::Transfer {
+                from: self.accounts.payer,
+                to: protocol_fee_recipient,
+                lamports: protocol_fee,
+            }
+            .invoke()?;
+        }
+
+        if rebate > 0 {
+            pinocchio_system::instructions::Transfer {
+                from: self.accounts.payer,
+                to: self.accounts.bonding_curve,
+                lamports: rebate,
+            }
+            .invoke()?;
+        }
+
+        log!("creation_fee: {} lamports, rebate held: {}", config.creation_fee_lamports, rebate);
+        Ok(rebate)
+    }
+
+    /// Create the mint at its PDA, owned by the token program and sized for
+    /// the extensions `create` initializes. Usernames are seeded lowercased, as
+    /// X handles are case-insensitive; an existing mint fails the creation,
+    /// so a username and nonce launch only once per authority.
+    fn create_derived_mint(&self, owner: &str, metadata_pointer: bool, rent: &Rent) -> Result<(), ProgramError> {
+        let token_program = self.accounts.token_program.key();
+        if !is_token_program(token_program) {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        let mut username = [0u8; 31];
+        let username = &mut username[..owner.len()];
+        username.copy_from_slice(owner.as_bytes());
+        username.make_ascii_lowercase();
+        let nonce = self.instruction_data.mint_nonce.to_le_bytes();
+
+        let (mint_address, mint_bump) = pinocchio::pubkey::find_program_address(
+            &[MINT_SEED, self.accounts.authority.key().as_ref(), &*username, &nonce],
+            &crate::ID,
+        );
+        if mint_address != *self.accounts.mint.key() {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let space = mint_len(token_program, metadata_pointer, self.instruction_data.transfer_fee_basis_points != 0);
+        let bump_bytes = [mint_bump];
+        let seeds = [
+            Seed::from(MINT_SEED),
+            Seed::from(self.accounts.authority.key().as_ref()),
+            Seed::from(&*username),
+            Seed::from(&nonce),
+            Seed::from(&bump_bytes),
+        ];
+
+        pinocchio_system::instructions::CreateAccount {
+            from: self.accounts.payer,
+            to: self.accounts.mint,
+            space: space as u64,
+            lamports: rent.minimum_balance(space),
+            owner: token_program,
+        }
+        .invoke_signed(&[Signer::from(&seeds)])
+    }
+}
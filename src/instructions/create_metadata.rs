@@ -0,0 +1,453 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+};
+
+use crate::{
+    error::XTokenError,
+    instructions::initialize::{
+        serialize_metadata_strings_fixed, write_fixed, InitializeInstructionData, CREATE_METADATA_ACCOUNT_V3,
+        METADATA_PREFIX, METAPLEX_TOKEN_METADATA_ID,
+    },
+    state::{check_authority_signed, AccountData, ProtocolConfig, Role, XToken},
+    token_interface::{check_token_program, initialize_token_metadata, token_metadata_len, TOKEN_2022_PROGRAM_ID},
+};
+
+/// Accounts for CreateMetadata instruction
+pub struct CreateMetadataAccounts<'info> {
+    /// Authority (must hold the owner role); becomes the metadata update
+    /// authority and, when it signs directly, is verified as creator
+    pub authority: &'info AccountInfo,
+    /// Bonding curve state account (PDA, mint authority)
+    pub bonding_curve: &'info AccountInfo,
+    /// Token mint account
+    pub mint: &'info AccountInfo,
+    /// Payer for the metadata rent
+    pub payer: &'info AccountInfo,
+    /// System program
+    pub system_program: &'info AccountInfo,
+    /// Token program owning the mint: Token-2022 mints keep the metadata in
+    /// the mint, classic mints get Metaplex metadata
+    pub token_program: &'info AccountInfo,
+    /// Rent sysvar (Metaplex only)
+    pub rent: &'info AccountInfo,
+    /// Metadata account (PDA from Metaplex; unused for Token-2022 mints)
+    pub metadata_account: &'info AccountInfo,
+    /// Metaplex Token Metadata Program (unused for Token-2022 mints)
+    pub metaplex_program: &'info AccountInfo,
+    /// Protocol config account (PDA)
+    pub config: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig (remaining accounts)
+    pub signers: &'info [AccountInfo],
+}
+
+impl<'info> CreateMetadataAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 10 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            authority: &accounts[0],
+            bonding_curve: &accounts[1],
+            mint: &accounts[2],
+            payer: &accounts[3],
+            system_program: &accounts[4],
+            token_program: &accounts[5],
+            rent: &accounts[6],
+            metadata_account: &accounts[7],
+            metaplex_program: &accounts[8],
+            config: &accounts[9],
+            signers: &accounts[10..],
+        })
+    }
+}
+
+/// Instruction data for CreateMetadata
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct CreateMetadataInstructionData {
+    /// Token name (max 32 bytes) - includes length in first byte
+    pub token_name: [u8; 32],
+    /// Token symbol (max 10 bytes) - includes length in first byte
+    pub token_symbol: [u8; 10],
+    /// Token metadata URI (max 200 bytes) - includes length in first byte
+    pub token_uri: [u8; 200],
+    /// Royalty on secondary sales written to the metadata (basis points, max
+    /// 10000). Metaplex only: Token-2022 mints need this, the creator share
+    /// and the collection left zero
+    pub seller_fee_basis_points: u16,
+    /// Authority's percentage of the metadata creators; the authority is
+    /// listed verified and the protocol fee recipient gets the rest (0 = the
+    /// protocol fee recipient is the only creator)
+    pub creator_share: u8,
+    /// Platform collection the token joins, verified later through
+    /// VerifyCollection (all zeroes = none)
+    pub collection: Pubkey,
+}
+
+impl CreateMetadataInstructionData {
+    pub const LEN: usize = core::mem::size_of::<CreateMetadataInstructionData>();
+
+    /// Reject a royalty above 100% or a creator share above 100
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        if self.seller_fee_basis_points > 10_000 || self.creator_share > 100 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok(())
+    }
+}
+
+impl<'info> TryFrom<&'info [u8]> for CreateMetadataInstructionData {
+    type Error = ProgramError;
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let instruction_data = bytemuck::try_from_bytes::<Self>(data)
+            .copied()
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        instruction_data.validate()?;
+        Ok(instruction_data)
+    }
+}
+
+impl From<&InitializeInstructionData> for CreateMetadataInstructionData {
+    fn from(data: &InitializeInstructionData) -> Self {
+        Self {
+            token_name: data.token_name,
+            token_symbol: data.token_symbol,
+            token_uri: data.token_uri,
+            seller_fee_basis_points: data.seller_fee_basis_points,
+            creator_share: data.creator_share,
+            collection: data.collection,
+        }
+    }
+}
+
+/// Metadata creator entry: address, verified, share
+type Creator = (Pubkey, bool, u8);
+
+/// Royalty, creators and collection of a DataV2
+struct MetadataAttribution<'a> {
+    seller_fee_basis_points: u16,
+    creators: &'a [Creator],
+    /// Collection mint the token claims (unverified until VerifyCollection)
+    collection: Option<&'a Pubkey>,
+}
+
+impl MetadataAttribution<'_> {
+    /// Serialized size of these fields
+    fn len(&self) -> usize {
+        2 + // seller_fee_basis_points
+        1 + (if self.creators.is_empty() { 0 } else { 4 + self.creators.len() * 34 }) + // creators
+        1 + (if self.collection.is_some() { 33 } else { 0 }) // collection
+    }
+}
+
+/// Fixed-size metadata serialization to avoid heap allocation
+fn serialize_metadata_v2_fixed(
+    buf: &mut [u8],
+    offset: &mut usize,
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    attribution: &MetadataAttribution,
+) -> Result<(), ProgramError> {
+    let write = write_fixed;
+
+    serialize_metadata_strings_fixed(buf, offset, name, symbol, uri)?;
+    write(buf, offset, &attribution.seller_fee_basis_points.to_le_bytes())?;
+    if attribution.creators.is_empty() {
+        write(buf, offset, &[0])?; // creators None
+    } else {
+        write(buf, offset, &[1])?;
+        write(buf, offset, &(attribution.creators.len() as u32).to_le_bytes())?;
+        for (address, verified, share) in attribution.creators {
+            write(buf, offset, address)?;
+            write(buf, offset, &[*verified as u8, *share])?;
+        }
+    }
+    match attribution.collection {
+        Some(collection) => {
+            write(buf, offset, &[1, 0])?; // collection Some, unverified
+            write(buf, offset, collection)?;
+        }
+        None => write(buf, offset, &[0])?, // collection None
+    }
+    write(buf, offset, &[0])?; // uses None
+
+    Ok(())
+}
+
+/// Build CreateMetadataAccountV3 instruction with fixed-size buffer
+fn build_create_metadata_instruction_fixed(
+    buf: &mut [u8],
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    attribution: &MetadataAttribution,
+) -> Result<usize, ProgramError> {
+    let mut offset = 0usize;
+    if offset + 1 > buf.len() { return Err(ProgramError::InvalidInstructionData); }
+    buf[offset] = CREATE_METADATA_ACCOUNT_V3;
+    offset += 1;
+
+    serialize_metadata_v2_fixed(buf, &mut offset, name, symbol, uri, attribution)?;
+    if offset + 2 > buf.len() { return Err(ProgramError::InvalidInstructionData); }
+    buf[offset] = 1; offset += 1; // isMutable
+    buf[offset] = 0; offset += 1; // collectionDetails None
+    Ok(offset)
+}
+
+/// Second launch step: give a curve created without metadata its token
+/// metadata, inside the mint for Token-2022 or through Metaplex otherwise
+/// (when the protocol config enables it). Only possible once, while the curve
+/// still holds the mint authority.
+pub struct CreateMetadata<'info> {
+    pub accounts: CreateMetadataAccounts<'info>,
+    pub instruction_data: CreateMetadataInstructionData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for CreateMetadata<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        let accounts = CreateMetadataAccounts::try_from(accounts)?;
+        let instruction_data = CreateMetadataInstructionData::try_from(data)?;
+        Ok(Self { accounts, instruction_data })
+    }
+}
+
+impl<'info> CreateMetadata<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        let config = ProtocolConfig::from_account(self.accounts.config)?;
+        self.create(&config)
+    }
+
+    pub(crate) fn create(&self, config: &ProtocolConfig) -> Result<(), ProgramError> {
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
+        if !self.accounts.payer.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let bump = {
+            let bonding_curve_data = self.accounts.bonding_curve.try_borrow_data()?;
+            let state = XToken::load(&bonding_curve_data)?;
+            if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+            if state.token_mint != *self.accounts.mint.key() { return Err(XTokenError::InvalidAccountData.into()); }
+            if !state.has_role(Role::Owner, self.accounts.authority.key()) { return Err(XTokenError::InvalidAuthority.into()); }
+            if state.metadata_mode != XToken::METADATA_NONE { return Err(XTokenError::MetadataAlreadyCreated.into()); }
+            state.bump
+        };
+
+        check_token_program(self.accounts.mint, self.accounts.token_program)?;
+
+        let token_name = InitializeInstructionData::extract_str(&self.instruction_data.token_name)?;
+        let token_symbol = InitializeInstructionData::extract_str(&self.instruction_data.token_symbol)?;
+        let token_uri = InitializeInstructionData::extract_str(&self.instruction_data.token_uri)?;
+
+        let metadata_mode = if *self.accounts.token_program.key() == TOKEN_2022_PROGRAM_ID {
+            // in-mint metadata only holds name, symbol and URI
+            if self.instruction_data.seller_fee_basis_points != 0
+                || self.instruction_data.creator_share != 0
+                || self.instruction_data.collection != [0u8; 32]
+            {
+                return Err(XTokenError::MetaplexMetadataRequired.into());
+            }
+            self.create_token_metadata(token_name, token_symbol, token_uri, bump)?;
+            XToken::METADATA_TOKEN_2022
+        } else if config.use_metaplex != 0 {
+            self.create_metadata(token_name, token_symbol, token_uri, &config.fee_recipient, bump)?;
+            XToken::METADATA_METAPLEX
+        } else {
+            return Err(XTokenError::MetaplexDisabled.into());
+        };
+
+        let mut bonding_curve_data = self.accounts.bonding_curve.try_borrow_mut_data()?;
+        XToken::load_mut(&mut bonding_curve_data)?.metadata_mode = metadata_mode;
+        Ok(())
+    }
+
+    /// CPI into Metaplex to create the token metadata account
+    fn create_metadata(
+        &self,
+        token_name: &str,
+        token_symbol: &str,
+        token_uri: &str,
+        platform: &Pubkey,
+        bump: u8,
+    ) -> Result<(), ProgramError> {
+        // Derive metadata PDA and verify
+        let metaplex_program_id = Pubkey::from(METAPLEX_TOKEN_METADATA_ID);
+        let metadata_seeds = &[
+            METADATA_PREFIX,
+            metaplex_program_id.as_ref(),
+            self.accounts.mint.key().as_ref(),
+        ];
+        let (metadata_address, _metadata_bump) =
+            pinocchio::pubkey::find_program_address(metadata_seeds, &metaplex_program_id);
+
+        if metadata_address != *self.accounts.metadata_account.key() {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        // Creators: the authority (verified by its signature; a multisig
+        // authority cannot sign the CPI and stays unverified) and the platform
+        // splitting the royalty; a platform that is the authority itself is
+        // folded into a single entry, and a zero share leaves the platform alone
+        let creator_share = self.instruction_data.creator_share;
+        let authority = *self.accounts.authority.key();
+        let authority_signed = self.accounts.authority.is_signer();
+        let sole_creator = creator_share == 100 || *platform == authority;
+        let creators: [Creator; 2] = [
+            (authority, authority_signed, if sole_creator { 100 } else { creator_share }),
+            (*platform, false, 100 - creator_share),
+        ];
+        let creators = match (creator_share, sole_creator) {
+            (_, true) => &creators[..1],
+            (0, false) => &creators[1..],
+            _ => &creators[..],
+        };
+        let collection = self.instruction_data.collection;
+        let attribution = MetadataAttribution {
+            seller_fee_basis_points: self.instruction_data.seller_fee_basis_points,
+            creators,
+            collection: if collection == [0u8; 32] { None } else { Some(&collection) },
+        };
+
+        // Create metadata instruction with fixed-size buffer
+        let mut ix_buf = [0u8; 450];
+        let ix_len = build_create_metadata_instruction_fixed(
+            &mut ix_buf,
+            token_name,
+            token_symbol,
+            token_uri,
+            &attribution,
+        )?;
+
+        // Calculate actual size of instruction data
+        let actual_data_size = 1 + // discriminator
+            4 + token_name.len() + // name
+            4 + token_symbol.len() + // symbol  
+            4 + token_uri.len() + // uri
+            attribution.len() + // seller_fee_basis_points, creators, collection
+            1 + // uses (None)
+            1 + // is_mutable
+            1; // collection_details (None)
+
+        // Build the instruction struct manually with only the used data
+        let metadata_instruction = pinocchio::instruction::Instruction {
+            program_id: &metaplex_program_id,
+            accounts: &[
+                AccountMeta {
+                    pubkey: self.accounts.metadata_account.key(),
+                    is_signer: false,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: self.accounts.mint.key(),
+                    is_signer: false,
+                    is_writable: false,
+                },
+                AccountMeta {
+                    pubkey: self.accounts.bonding_curve.key(), // mint_authority
+                    is_signer: true,
+                    is_writable: false,
+                },
+                AccountMeta {
+                    pubkey: self.accounts.payer.key(),
+                    is_signer: true,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: self.accounts.authority.key(), // update_authority (signs to verify itself as creator)
+                    is_signer: authority_signed,
+                    is_writable: false,
+                },
+                AccountMeta {
+                    pubkey: self.accounts.system_program.key(),
+                    is_signer: false,
+                    is_writable: false,
+                },
+                AccountMeta {
+                    pubkey: self.accounts.rent.key(),
+                    is_signer: false,
+                    is_writable: false,
+                },
+            ],
+            data: &ix_buf[..ix_len.min(actual_data_size)],
+        };
+
+        // Prepare signer seeds for bonding curve - use Seed array directly
+        let bump_bytes = [bump];
+        let signer_seeds = [
+            Seed::from(XToken::SEED_PREFIX),
+            Seed::from(self.accounts.mint.key().as_ref()),
+            Seed::from(&bump_bytes),
+        ];
+        let signer = Signer::from(&signer_seeds);
+
+        // Collect account infos for metadata creation
+        let metadata_account_infos = [
+            self.accounts.metadata_account,
+            self.accounts.mint,
+            self.accounts.bonding_curve, // mint_authority (signer)
+            self.accounts.payer,
+            self.accounts.authority, // update_authority
+            self.accounts.system_program,
+            self.accounts.rent,
+        ];
+
+        // Invoke Metaplex to create metadata
+        pinocchio::program::invoke_signed(
+            &metadata_instruction,
+            &metadata_account_infos,
+            &[signer],
+        )
+    }
+
+    /// Write name, symbol and URI into the TokenMetadata extension of a
+    /// Token-2022 mint, with the authority as update authority
+    fn create_token_metadata(
+        &self,
+        token_name: &str,
+        token_symbol: &str,
+        token_uri: &str,
+        bump: u8,
+    ) -> Result<(), ProgramError> {
+        // the extension grows the mint, so fund the larger account up front
+        let new_len = self.accounts.mint.data_len() + token_metadata_len(token_name, token_symbol, token_uri);
+        let required_lamports = Rent::get()?.minimum_balance(new_len);
+        let mint_lamports = self.accounts.mint.lamports();
+        if required_lamports > mint_lamports {
+            pinocchio_system::instructions::Transfer {
+                from: self.accounts.payer,
+                to: self.accounts.mint,
+                lamports: required_lamports - mint_lamports,
+            }
+            .invoke()?;
+        }
+
+        let bump_bytes = [bump];
+        let signer_seeds = [
+            Seed::from(XToken::SEED_PREFIX),
+            Seed::from(self.accounts.mint.key().as_ref()),
+            Seed::from(&bump_bytes),
+        ];
+        initialize_token_metadata(
+            self.accounts.token_program,
+            self.accounts.mint,
+            self.accounts.authority,
+            self.accounts.bonding_curve,
+            (token_name, token_symbol, token_uri),
+            &[Signer::from(&signer_seeds)],
+        )
+    }
+}
@@ -0,0 +1,142 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+};
+
+use crate::{
+    error::XTokenError,
+    state::{AccountData, Multisig, MAX_MULTISIG_SIGNERS},
+};
+
+/// Accounts for CreateMultisig instruction
+pub struct CreateMultisigAccounts<'info> {
+    /// Creation key (seeds the multisig PDA)
+    pub create_key: &'info AccountInfo,
+    /// Multisig account (PDA)
+    pub multisig: &'info AccountInfo,
+    /// Payer for account creation
+    pub payer: &'info AccountInfo,
+    /// System program
+    pub system_program: &'info AccountInfo,
+}
+
+impl<'info> CreateMultisigAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 4 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            create_key: &accounts[0],
+            multisig: &accounts[1],
+            payer: &accounts[2],
+            system_program: &accounts[3],
+        })
+    }
+}
+
+/// Instruction data for CreateMultisig: threshold followed by 1..=10 member keys
+pub struct CreateMultisigInstructionData {
+    /// Number of member signatures required
+    pub threshold: u8,
+    /// Member keys; only the first `num_signers` entries are used
+    pub signers: [Pubkey; MAX_MULTISIG_SIGNERS],
+    /// Number of member keys
+    pub num_signers: usize,
+}
+
+impl<'info> TryFrom<&'info [u8]> for CreateMultisigInstructionData {
+    type Error = ProgramError;
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        let (threshold, keys) = data
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        if keys.is_empty() || keys.len() % 32 != 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let num_signers = keys.len() / 32;
+        if num_signers > MAX_MULTISIG_SIGNERS {
+            return Err(XTokenError::InvalidMultisig.into());
+        }
+
+        let mut signers = [Pubkey::default(); MAX_MULTISIG_SIGNERS];
+        for (signer, key) in signers.iter_mut().zip(keys.chunks_exact(32)) {
+            *signer = key
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?;
+        }
+
+        Ok(Self {
+            threshold: *threshold,
+            signers,
+            num_signers,
+        })
+    }
+}
+
+pub struct CreateMultisig<'info> {
+    pub accounts: CreateMultisigAccounts<'info>,
+    pub instruction_data: CreateMultisigInstructionData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for CreateMultisig<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        let accounts = CreateMultisigAccounts::try_from(accounts)?;
+        let instruction_data = CreateMultisigInstructionData::try_from(data)?;
+        Ok(Self { accounts, instruction_data })
+    }
+}
+
+impl<'info> CreateMultisig<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        if !self.accounts.create_key.is_signer() || !self.accounts.payer.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // derive multisig PDA
+        let (multisig_address, bump) = pinocchio::pubkey::find_program_address(
+            &[Multisig::SEED_PREFIX, self.accounts.create_key.key()],
+            &crate::ID,
+        );
+        if multisig_address != *self.accounts.multisig.key() {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        if !self.accounts.multisig.data_is_empty() {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        // create multisig PDA account
+        let space = Multisig::LEN;
+        let lamports = Rent::get()?.minimum_balance(space);
+
+        let bump_bytes = [bump];
+        let seeds = [
+            Seed::from(Multisig::SEED_PREFIX),
+            Seed::from(self.accounts.create_key.key()),
+            Seed::from(&bump_bytes),
+        ];
+        let signer = Signer::from(&seeds);
+
+        pinocchio_system::instructions::CreateAccount {
+            from: self.accounts.payer,
+            to: self.accounts.multisig,
+            space: space as u64,
+            lamports,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&[signer])?;
+
+        let mut multisig_data = self.accounts.multisig.try_borrow_mut_data()?;
+        let multisig = Multisig::load_mut(&mut multisig_data)?;
+        multisig.initialize(
+            &self.instruction_data.signers[..self.instruction_data.num_signers],
+            self.instruction_data.threshold,
+            bump,
+        )
+    }
+}
@@ -0,0 +1,224 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+};
+
+use crate::{
+    error::XTokenError,
+    state::{check_authority_signed, AccountData, Role, Vesting, XToken},
+    token_interface::{check_token_program, mint_to},
+};
+
+/// Accounts for CreateVesting instruction
+pub struct CreateVestingAccounts<'info> {
+    /// Authority (must hold the minter role)
+    pub authority: &'info AccountInfo,
+    /// Bonding curve state account (PDA)
+    pub bonding_curve: &'info AccountInfo,
+    /// Token mint account
+    pub mint: &'info AccountInfo,
+    /// Vesting account (PDA)
+    pub vesting: &'info AccountInfo,
+    /// Escrow token account (ATA owned by the vesting PDA)
+    pub escrow: &'info AccountInfo,
+    /// Beneficiary allowed to claim unlocked tokens
+    pub beneficiary: &'info AccountInfo,
+    /// Payer for account creation
+    pub payer: &'info AccountInfo,
+    /// System program
+    pub system_program: &'info AccountInfo,
+    /// Token program owning the mint (classic or Token-2022)
+    pub token_program: &'info AccountInfo,
+    /// Associated token program
+    pub associated_token_program: &'info AccountInfo,
+    /// Protocol config account (PDA, checked for the emergency halt)
+    pub config: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig (remaining accounts)
+    pub signers: &'info [AccountInfo],
+}
+
+impl<'info> CreateVestingAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 11 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            authority: &accounts[0],
+            bonding_curve: &accounts[1],
+            mint: &accounts[2],
+            vesting: &accounts[3],
+            escrow: &accounts[4],
+            beneficiary: &accounts[5],
+            payer: &accounts[6],
+            system_program: &accounts[7],
+            token_program: &accounts[8],
+            associated_token_program: &accounts[9],
+            config: &accounts[10],
+            signers: &accounts[11..],
+        })
+    }
+}
+
+/// Instruction data for CreateVesting
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct CreateVestingInstructionData {
+    /// Tokens to vest (base units, taken from the creator allocation)
+    pub amount: u64,
+    /// Unix timestamp unlocking starts from
+    pub start_ts: i64,
+    /// Unix timestamp before which nothing can be claimed
+    pub cliff_ts: i64,
+    /// Unix timestamp at which everything is unlocked
+    pub end_ts: i64,
+    /// Whether the curve owner may revoke unvested tokens (0 = false, 1 = true)
+    pub revocable: u8,
+}
+
+impl CreateVestingInstructionData {
+    pub const LEN: usize = core::mem::size_of::<CreateVestingInstructionData>();
+}
+
+impl<'info> TryFrom<&'info [u8]> for CreateVestingInstructionData {
+    type Error = ProgramError;
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        bytemuck::try_from_bytes::<Self>(data)
+            .copied()
+            .map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+pub struct CreateVesting<'info> {
+    pub accounts: CreateVestingAccounts<'info>,
+    pub instruction_data: CreateVestingInstructionData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for CreateVesting<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        let accounts = CreateVestingAccounts::try_from(accounts)?;
+        let instruction_data = CreateVestingInstructionData::try_from(data)?;
+        Ok(Self { accounts, instruction_data })
+    }
+}
+
+impl<'info> CreateVesting<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
+        if !self.accounts.payer.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // validate state and count the vested tokens against the creator allocation
+        let bonding_curve_bump = {
+            let mut bonding_curve_data = self.accounts.bonding_curve.try_borrow_mut_data()?;
+            let state = XToken::load_mut(&mut bonding_curve_data)?;
+            if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+            if state.paused != 0 { return Err(XTokenError::CurvePaused.into()); }
+            if state.token_mint != *self.accounts.mint.key() { return Err(XTokenError::InvalidAccountData.into()); }
+            if !state.has_role(Role::Minter, self.accounts.authority.key()) { return Err(XTokenError::InvalidAuthority.into()); }
+            state.record_creator_mint(self.instruction_data.amount)?;
+            state.bump
+        };
+
+        // derive vesting PDA
+        let (vesting_address, vesting_bump) = pinocchio::pubkey::find_program_address(
+            &[
+                Vesting::SEED_PREFIX,
+                self.accounts.bonding_curve.key(),
+                self.accounts.beneficiary.key(),
+            ],
+            &crate::ID,
+        );
+        if vesting_address != *self.accounts.vesting.key() {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        if !self.accounts.vesting.data_is_empty() {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        // create vesting PDA account
+        let space = Vesting::LEN;
+        let lamports = Rent::get()?.minimum_balance(space);
+
+        let bump_bytes = [vesting_bump];
+        let seeds = [
+            Seed::from(Vesting::SEED_PREFIX),
+            Seed::from(self.accounts.bonding_curve.key()),
+            Seed::from(self.accounts.beneficiary.key()),
+            Seed::from(&bump_bytes),
+        ];
+        let signer = Signer::from(&seeds);
+
+        pinocchio_system::instructions::CreateAccount {
+            from: self.accounts.payer,
+            to: self.accounts.vesting,
+            space: space as u64,
+            lamports,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&[signer])?;
+
+        {
+            let mut vesting_data = self.accounts.vesting.try_borrow_mut_data()?;
+            let vesting = Vesting::load_mut(&mut vesting_data)?;
+            vesting.initialize(
+                *self.accounts.bonding_curve.key(),
+                *self.accounts.beneficiary.key(),
+                *self.accounts.escrow.key(),
+                vesting_bump,
+            )?;
+            vesting.set_schedule(
+                self.instruction_data.amount,
+                self.instruction_data.start_ts,
+                self.instruction_data.cliff_ts,
+                self.instruction_data.end_ts,
+                self.instruction_data.revocable != 0,
+            )?;
+        }
+
+        check_token_program(self.accounts.mint, self.accounts.token_program)?;
+
+        // escrow is the vesting PDA's ATA; the ATA program verifies the address
+        pinocchio_associated_token_account::instructions::Create {
+            account: self.accounts.escrow,
+            mint: self.accounts.mint,
+            funding_account: self.accounts.payer,
+            system_program: self.accounts.system_program,
+            token_program: self.accounts.token_program,
+            wallet: self.accounts.vesting,
+        }
+        .invoke()?;
+
+        // mint into escrow using bonding curve as mint authority
+        let bump_bytes = [bonding_curve_bump];
+        let seeds = [
+            Seed::from(XToken::SEED_PREFIX),
+            Seed::from(self.accounts.mint.key().as_ref()),
+            Seed::from(&bump_bytes),
+        ];
+        let signer = Signer::from(&seeds);
+
+        mint_to(
+            self.accounts.token_program,
+            self.accounts.mint,
+            self.accounts.escrow,
+            self.accounts.bonding_curve,
+            self.instruction_data.amount,
+            &[signer],
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+};
+
+use crate::{
+    error::XTokenError,
+    instructions::{
+        AdminMint, CloseCurve, CreateVesting, Instruction, ProposeAuthority, RenounceAuthorities,
+        SetRole, UpdateCurveConfig, WithdrawReserves,
+    },
+    state::{AccountData, PendingAction},
+};
+
+/// Accounts for ExecuteAction instruction
+pub struct ExecuteActionAccounts<'info> {
+    /// Pending action account (PDA)
+    pub action: &'info AccountInfo,
+    /// Account that queued the action (receives the rent back)
+    pub rent_recipient: &'info AccountInfo,
+    /// Accounts of the queued instruction, in that instruction's order
+    /// (remaining accounts); its signer and role checks apply as usual
+    pub target: &'info [AccountInfo],
+}
+
+impl<'info> ExecuteActionAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        // every timelocked instruction takes the bonding curve as its second account
+        if accounts.len() < 4 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            action: &accounts[0],
+            rent_recipient: &accounts[1],
+            target: &accounts[2..],
+        })
+    }
+}
+
+/// Run a queued action once its timelock has passed
+pub struct ExecuteAction<'info> {
+    pub accounts: ExecuteActionAccounts<'info>,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for ExecuteAction<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let accounts = ExecuteActionAccounts::try_from(accounts)?;
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> ExecuteAction<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        let target = self.accounts.target;
+        let action = PendingAction::from_account(self.accounts.action, target[1].key())?;
+        if action.proposer != *self.accounts.rent_recipient.key() {
+            return Err(XTokenError::InvalidAccountData.into());
+        }
+
+        if Clock::get()?.unix_timestamp < action.execute_after {
+            return Err(XTokenError::TimelockNotExpired.into());
+        }
+
+        // the queued action pays out only to the account fixed when it was queued
+        let kind = Instruction::try_from(action.kind)?;
+        if let Some(index) = kind.timelock_destination_index() {
+            let destination = target.get(index).ok_or(ProgramError::NotEnoughAccountKeys)?;
+            if *destination.key() != action.destination {
+                return Err(XTokenError::InvalidAccountData.into());
+            }
+        }
+
+        // close first so the action can never run twice
+        PendingAction::close(self.accounts.action, self.accounts.rent_recipient)?;
+
+        let data = action.action_data();
+        match kind {
+            Instruction::WithdrawReserves => WithdrawReserves::try_from((target, data))?.handler(),
+            Instruction::AdminMint => AdminMint::try_from((target, data))?.handler(),
+            Instruction::UpdateCurveConfig => UpdateCurveConfig::try_from((target, data))?.handler(),
+            Instruction::ProposeAuthority => ProposeAuthority::try_from((target, data))?.handler(),
+            Instruction::SetRole => SetRole::try_from((target, data))?.handler(),
+            Instruction::CreateVesting => CreateVesting::try_from((target, data))?.handler(),
+            Instruction::RenounceAuthorities => RenounceAuthorities::try_from((target, data))?.handler(),
+            Instruction::CloseCurve => CloseCurve::try_from((target, data))?.handler(),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
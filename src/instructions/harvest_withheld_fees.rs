@@ -0,0 +1,115 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+};
+
+use crate::{
+    error::XTokenError,
+    state::{AccountData, XToken},
+    token_interface::{
+        check_token_program, harvest_withheld_tokens_to_mint, withdraw_withheld_tokens_from_mint,
+        TOKEN_2022_PROGRAM_ID,
+    },
+};
+
+/// Accounts for HarvestWithheldFees instruction
+pub struct HarvestWithheldFeesAccounts<'info> {
+    /// Bonding curve state account (PDA, withdraw withheld authority of the mint)
+    pub bonding_curve: &'info AccountInfo,
+    /// Token mint account (Token-2022 with a transfer fee)
+    pub mint: &'info AccountInfo,
+    /// Creator token account receiving the fees (owned by the curve authority)
+    pub creator_token_account: &'info AccountInfo,
+    /// Token-2022 program
+    pub token_program: &'info AccountInfo,
+    /// Protocol config account (PDA, checked for the emergency halt)
+    pub config: &'info AccountInfo,
+    /// Token accounts of the mint holding withheld fees (remaining accounts)
+    pub sources: &'info [AccountInfo],
+}
+
+impl<'info> HarvestWithheldFeesAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 5 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            bonding_curve: &accounts[0],
+            mint: &accounts[1],
+            creator_token_account: &accounts[2],
+            token_program: &accounts[3],
+            config: &accounts[4],
+            sources: &accounts[5..],
+        })
+    }
+}
+
+/// Sweep Token-2022 transfer fees withheld in the given token accounts, and
+/// any already harvested into the mint, to the creator. Permissionless: the
+/// fees can only go to a token account of the curve authority.
+pub struct HarvestWithheldFees<'info> {
+    pub accounts: HarvestWithheldFeesAccounts<'info>,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for HarvestWithheldFees<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let accounts = HarvestWithheldFeesAccounts::try_from(accounts)?;
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> HarvestWithheldFees<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let (bump, creator) = {
+            let bonding_curve_data = self.accounts.bonding_curve.try_borrow_data()?;
+            let state = XToken::load(&bonding_curve_data)?;
+            if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+            if state.token_mint != *self.accounts.mint.key() { return Err(XTokenError::InvalidAccountData.into()); }
+            (state.bump, state.authority)
+        };
+
+        check_token_program(self.accounts.mint, self.accounts.token_program)?;
+        if *self.accounts.token_program.key() != TOKEN_2022_PROGRAM_ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        // token account layout: mint, then owner
+        {
+            if unsafe { *self.accounts.creator_token_account.owner() } != TOKEN_2022_PROGRAM_ID {
+                return Err(ProgramError::InvalidAccountOwner);
+            }
+            let data = self.accounts.creator_token_account.try_borrow_data()?;
+            if data.len() < 64 { return Err(ProgramError::InvalidAccountData); }
+            if data[0..32] != *self.accounts.mint.key() { return Err(XTokenError::InvalidAccountData.into()); }
+            if data[32..64] != creator { return Err(XTokenError::InvalidAuthority.into()); }
+        }
+
+        for source in self.accounts.sources {
+            harvest_withheld_tokens_to_mint(self.accounts.token_program, self.accounts.mint, source)?;
+        }
+
+        let bump_bytes = [bump];
+        let seeds = [
+            Seed::from(XToken::SEED_PREFIX),
+            Seed::from(self.accounts.mint.key().as_ref()),
+            Seed::from(&bump_bytes),
+        ];
+
+        withdraw_withheld_tokens_from_mint(
+            self.accounts.token_program,
+            self.accounts.mint,
+            self.accounts.creator_token_account,
+            self.accounts.bonding_curve,
+            &[Signer::from(&seeds)],
+        )
+    }
+}
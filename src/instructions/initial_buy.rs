@@ -0,0 +1,227 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+};
+
+use crate::{
+    error::XTokenError,
+    state::{check_authority_signed, AccountData, ProtocolConfig, Role, XToken},
+    token_interface::{check_token_program, mint_to},
+};
+
+/// Accounts for InitialBuy instruction
+pub struct InitialBuyAccounts<'info> {
+    /// Authority (must hold the owner role) receiving the tokens
+    pub authority: &'info AccountInfo,
+    /// Bonding curve state account (PDA)
+    pub bonding_curve: &'info AccountInfo,
+    /// Token mint account
+    pub mint: &'info AccountInfo,
+    /// Treasury account (PDA) - holds SOL for bonding curve
+    pub treasury: &'info AccountInfo,
+    /// Authority's token account (ATA, created if missing)
+    pub authority_token_account: &'info AccountInfo,
+    /// Payer of the SOL cost, fee and account rent
+    pub payer: &'info AccountInfo,
+    /// Fee recipient account of the curve
+    pub fee_recipient_account: &'info AccountInfo,
+    /// System program
+    pub system_program: &'info AccountInfo,
+    /// Token program owning the mint (classic or Token-2022)
+    pub token_program: &'info AccountInfo,
+    /// Associated token program
+    pub associated_token_program: &'info AccountInfo,
+    /// Protocol config account (PDA)
+    pub config: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig (remaining accounts)
+    pub signers: &'info [AccountInfo],
+}
+
+impl<'info> InitialBuyAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 11 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            authority: &accounts[0],
+            bonding_curve: &accounts[1],
+            mint: &accounts[2],
+            treasury: &accounts[3],
+            authority_token_account: &accounts[4],
+            payer: &accounts[5],
+            fee_recipient_account: &accounts[6],
+            system_program: &accounts[7],
+            token_program: &accounts[8],
+            associated_token_program: &accounts[9],
+            config: &accounts[10],
+            signers: &accounts[11..],
+        })
+    }
+}
+
+/// Instruction data for InitialBuy
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct InitialBuyInstructionData {
+    /// Amount of tokens to buy (base units)
+    pub token_amount: u64,
+    /// Maximum SOL amount willing to pay (slippage protection)
+    pub max_sol_amount: u64,
+}
+
+impl InitialBuyInstructionData {
+    pub const LEN: usize = core::mem::size_of::<InitialBuyInstructionData>();
+}
+
+impl<'info> TryFrom<&'info [u8]> for InitialBuyInstructionData {
+    type Error = ProgramError;
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        bytemuck::try_from_bytes::<Self>(data)
+            .copied()
+            .map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+/// Last launch step: the creator's pre-buy, priced like a regular buy but
+/// paid by the payer and only open while no tokens have been sold or minted
+pub struct InitialBuy<'info> {
+    pub accounts: InitialBuyAccounts<'info>,
+    pub instruction_data: InitialBuyInstructionData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for InitialBuy<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        let accounts = InitialBuyAccounts::try_from(accounts)?;
+        let instruction_data = InitialBuyInstructionData::try_from(data)?;
+        Ok(Self { accounts, instruction_data })
+    }
+}
+
+impl<'info> InitialBuy<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        let config = ProtocolConfig::from_account(self.accounts.config)?;
+        self.buy(&config)
+    }
+
+    pub(crate) fn buy(&self, config: &ProtocolConfig) -> Result<(), ProgramError> {
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
+        if !self.accounts.payer.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let token_amount = self.instruction_data.token_amount;
+        if token_amount == 0 {
+            return Err(XTokenError::InvalidTokenAmount.into());
+        }
+
+        if config.paused != 0 {
+            return Err(XTokenError::ProtocolPaused.into());
+        }
+
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let (bump, sol_reserve, total_cost, fee, fee_recipient) = {
+            let bonding_curve_data = self.accounts.bonding_curve.try_borrow_data()?;
+            let state = XToken::load(&bonding_curve_data)?;
+            if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+            if state.token_mint != *self.accounts.mint.key() { return Err(XTokenError::InvalidAccountData.into()); }
+            if !state.has_role(Role::Owner, self.accounts.authority.key()) { return Err(XTokenError::InvalidAuthority.into()); }
+            if state.paused != 0 { return Err(XTokenError::CurvePaused.into()); }
+            if state.total_supply != 0 || state.creator_minted != 0 { return Err(XTokenError::InitialBuyClosed.into()); }
+
+            let new_supply = state.total_supply.checked_add(token_amount).ok_or(ProgramError::ArithmeticOverflow)?;
+            if new_supply > state.curve_max_supply() { return Err(ProgramError::InvalidArgument); }
+
+            let total_cost = state.calculate_buy_price(token_amount)?;
+            let fee = state.calculate_fee(total_cost)?;
+            (state.bump, state.sol_reserve, total_cost, fee, state.fee_recipient)
+        };
+
+        check_token_program(self.accounts.mint, self.accounts.token_program)?;
+
+        let (treasury_pda, _) = pinocchio::pubkey::find_program_address(
+            &[b"treasury", self.accounts.mint.key().as_ref()],
+            &crate::ID,
+        );
+        if treasury_pda != *self.accounts.treasury.key() {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let total_with_fee = total_cost.checked_add(fee).ok_or(ProgramError::ArithmeticOverflow)?;
+
+        // Slippage check
+        if total_with_fee > self.instruction_data.max_sol_amount {
+            return Err(XTokenError::SlippageExceeded.into());
+        }
+
+        // Treasury cap like in buy
+        let new_reserve = sol_reserve.checked_add(total_cost).ok_or(ProgramError::ArithmeticOverflow)?;
+        if new_reserve > config.sol_cap_lamports {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if self.accounts.payer.lamports() < total_with_fee {
+            return Err(XTokenError::InsufficientFunds.into());
+        }
+
+        if self.accounts.authority_token_account.data_is_empty() {
+            pinocchio_associated_token_account::instructions::Create {
+                account: self.accounts.authority_token_account,
+                mint: self.accounts.mint,
+                funding_account: self.accounts.payer,
+                system_program: self.accounts.system_program,
+                token_program: self.accounts.token_program,
+                wallet: self.accounts.authority,
+            }
+            .invoke()?;
+        }
+
+        // Transfer SOL to treasury and fee recipient from payer
+        pinocchio_system::instructions::Transfer {
+            from: self.accounts.payer,
+            to: self.accounts.treasury,
+            lamports: total_cost,
+        }
+        .invoke()?;
+
+        if fee > 0 {
+            if *self.accounts.fee_recipient_account.key() != fee_recipient {
+                return Err(XTokenError::InvalidAccountData.into());
+            }
+
+            pinocchio_system::instructions::Transfer {
+                from: self.accounts.payer,
+                to: self.accounts.fee_recipient_account,
+                lamports: fee,
+            }
+            .invoke()?;
+        }
+
+        let bump_bytes = [bump];
+        let seeds = [
+            Seed::from(XToken::SEED_PREFIX),
+            Seed::from(self.accounts.mint.key().as_ref()),
+            Seed::from(&bump_bytes),
+        ];
+
+        mint_to(
+            self.accounts.token_program,
+            self.accounts.mint,
+            self.accounts.authority_token_account,
+            self.accounts.bonding_curve,
+            token_amount,
+            &[Signer::from(&seeds)],
+        )?;
+
+        let mut bonding_curve_data = self.accounts.bonding_curve.try_borrow_mut_data()?;
+        XToken::load_mut(&mut bonding_curve_data)?.update_buy(token_amount, total_cost)
+    }
+}
@@ -1,17 +1,16 @@
 use bytemuck::{Pod, Zeroable};
-use pinocchio::{
-    account_info::AccountInfo,
-    instruction::{AccountMeta, Seed, Signer},
-    program_error::ProgramError,
-    pubkey::Pubkey,
-    sysvars::{rent::Rent, Sysvar},
-};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
 // No heap allocations in SBF
 
 use crate::{
-    error::XTokenError,
-    state::{AccountData, XToken},
+    instructions::{
+        create_curve::{CreateCurve, CreateCurveAccounts, CreateCurveInstructionData},
+        create_metadata::{CreateMetadata, CreateMetadataAccounts, CreateMetadataInstructionData},
+        initial_buy::{InitialBuy, InitialBuyAccounts, InitialBuyInstructionData},
+    },
+    state::ProtocolConfig,
+    token_interface::TOKEN_2022_PROGRAM_ID,
 };
 
 // Metaplex Token Metadata Program ID: metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s
@@ -45,14 +44,26 @@ pub struct InitializeAccounts<'info> {
     pub payer: &'info AccountInfo,
     /// System program
     pub system_program: &'info AccountInfo,
-    /// Token program
+    /// Token program owning the mint: the classic token program, or Token-2022
+    /// to launch with the metadata stored in the mint instead of Metaplex
     pub token_program: &'info AccountInfo,
     /// Associated token program
     pub associated_token_program: &'info AccountInfo,
-    /// Rent sysvar
-    pub rent: &'info AccountInfo,
     /// Fee recipient account (for transferring initial fee)
     pub fee_recipient_account: &'info AccountInfo,
+    /// Metaplex accounts; None for a launch without metadata
+    pub metadata: Option<MetadataAccounts<'info>>,
+    /// Protocol config account (PDA)
+    pub config: &'info AccountInfo,
+    /// Protocol fee recipient receiving the creation fee (trailing account,
+    /// only needed while the protocol charges one)
+    pub protocol_fee_recipient: Option<&'info AccountInfo>,
+}
+
+/// Accounts Initialize only needs to create Metaplex metadata
+pub struct MetadataAccounts<'info> {
+    /// Rent sysvar
+    pub rent: &'info AccountInfo,
     /// Metadata account (PDA from Metaplex)
     pub metadata_account: &'info AccountInfo,
     /// Metaplex Token Metadata Program
@@ -61,7 +72,7 @@ pub struct InitializeAccounts<'info> {
 
 impl<'info> InitializeAccounts<'info> {
     pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
-        if accounts.len() < 13 {
+        if accounts.len() < 14 {
             return Err(ProgramError::NotEnoughAccountKeys);
         }
 
@@ -75,10 +86,38 @@ impl<'info> InitializeAccounts<'info> {
             system_program: &accounts[6],
             token_program: &accounts[7],
             associated_token_program: &accounts[8],
-            rent: &accounts[9],
             fee_recipient_account: &accounts[10],
-            metadata_account: &accounts[11],
-            metaplex_program: &accounts[12],
+            metadata: Some(MetadataAccounts {
+                rent: &accounts[9],
+                metadata_account: &accounts[11],
+                metaplex_program: &accounts[12],
+            }),
+            config: &accounts[13],
+            protocol_fee_recipient: accounts.get(14),
+        })
+    }
+
+    /// Accounts of a launch without metadata: the same order minus the rent
+    /// sysvar, metadata account and Metaplex program
+    pub fn try_from_without_metadata(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 11 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        Ok(Self {
+            authority: &accounts[0],
+            bonding_curve: &accounts[1],
+            mint: &accounts[2],
+            treasury: &accounts[3],
+            authority_token_account: &accounts[4],
+            payer: &accounts[5],
+            system_program: &accounts[6],
+            token_program: &accounts[7],
+            associated_token_program: &accounts[8],
+            fee_recipient_account: &accounts[9],
+            metadata: None,
+            config: &accounts[10],
+            protocol_fee_recipient: accounts.get(11),
         })
     }
 }
@@ -101,7 +140,7 @@ pub struct InitializeInstructionData {
     pub slope: u64,
     /// Maximum token supply
     pub max_supply: u64,
-    /// Fee recipient
+    /// Fee recipient (all zeroes = protocol default)
     pub fee_recipient: Pubkey,
     /// Optional initial pre-buy token amount (base units)
     pub initial_buy_amount: u64,
@@ -113,13 +152,116 @@ pub struct InitializeInstructionData {
     pub token_symbol: [u8; 10],
     /// Token metadata URI (max 200 bytes) - includes length in first byte
     pub token_uri: [u8; 200],
+    /// Encoding version of the fields below; the original (v1) data ends
+    /// before it and leaves them zeroed
+    pub version: u8,
+    /// Royalty on secondary sales written to the metadata (basis points, max
+    /// 10000). Metaplex only: Token-2022 mints need this, the creator share
+    /// and the collection left zero
+    pub seller_fee_basis_points: u16,
+    /// Authority's percentage of the metadata creators; the authority is
+    /// listed verified and the protocol fee recipient gets the rest (0 = the
+    /// protocol fee recipient is the only creator)
+    pub creator_share: u8,
+    /// Platform collection the token joins, verified later through
+    /// VerifyCollection (all zeroes = none)
+    pub collection: Pubkey,
+    /// Token-2022 only: creator royalty withheld on every transfer, in basis
+    /// points (0 = none); buys and sells mint and burn directly and pay none
+    pub transfer_fee_basis_points: u16,
+    /// Cap on the transfer fee charged per transfer (base units)
+    pub maximum_transfer_fee: u64,
+    /// Tokens reserved for AdminMint; counted against max_supply, outside curve pricing
+    pub creator_allocation: u64,
 }
 
 impl InitializeInstructionData {
     pub const LEN: usize = core::mem::size_of::<InitializeInstructionData>();
+
+    /// Length of the original (v1) data: every field before `version`
+    pub const V1_LEN: usize = core::mem::offset_of!(InitializeInstructionData, version);
+
+    /// Encoding version expected in full-length data
+    pub const CURRENT_VERSION: u8 = 2;
+
+    /// Length of the curve fields of a launch without metadata: every field
+    /// before `token_name`
+    const CURVE_FIELDS_LEN: usize = core::mem::offset_of!(InitializeInstructionData, token_name);
+
+    /// Length of the data of a launch without metadata: the curve fields,
+    /// then `creator_allocation`
+    pub const WITHOUT_METADATA_LEN: usize = Self::CURVE_FIELDS_LEN + 8;
+
+    /// Parse the data of a launch without metadata; the metadata fields stay zeroed
+    pub fn try_from_without_metadata(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::WITHOUT_METADATA_LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let (curve_fields, creator_allocation) = data.split_at(Self::CURVE_FIELDS_LEN);
+        let mut instruction_data = Self::zeroed();
+        bytemuck::bytes_of_mut(&mut instruction_data)[..Self::CURVE_FIELDS_LEN].copy_from_slice(curve_fields);
+        instruction_data.version = Self::CURRENT_VERSION;
+        instruction_data.creator_allocation =
+            u64::from_le_bytes(creator_allocation.try_into().map_err(|_| ProgramError::InvalidInstructionData)?);
+        Ok(instruction_data)
+    }
     
+    /// Version byte leading the compact encoding of InitializeCompact
+    pub const COMPACT_VERSION: u8 = 1;
+
+    /// Parse the compact encoding of InitializeCompact: a version byte, then
+    /// the fields in the order of this struct, with creator_allocation right
+    /// after max_supply, each string sent as a u8 length and its bytes, and
+    /// fee_recipient, the royalty (seller fee and
+    /// creator share), collection and transfer fee (basis points and maximum)
+    /// each behind a presence byte (0 = absent, 1 = present). Absent fields
+    /// and strings stay zeroed, as in the fixed encoding.
+    pub fn try_from_compact(data: &[u8]) -> Result<Self, ProgramError> {
+        let mut reader = CompactReader { data };
+        if reader.u8()? != Self::COMPACT_VERSION {
+            pinocchio::msg!("Unsupported compact Initialize version");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mut instruction_data = Self::zeroed();
+        instruction_data.version = Self::CURRENT_VERSION;
+        instruction_data.decimals = reader.u8()?;
+        instruction_data.curve_type = reader.u8()?;
+        instruction_data.fee_basis_points = reader.u16()?;
+        reader.string_into(&mut instruction_data.owner)?;
+        instruction_data.base_price = reader.u64()?;
+        instruction_data.slope = reader.u64()?;
+        instruction_data.max_supply = reader.u64()?;
+        instruction_data.creator_allocation = reader.u64()?;
+        if reader.option()? {
+            instruction_data.fee_recipient = reader.pubkey()?;
+        }
+        instruction_data.initial_buy_amount = reader.u64()?;
+        instruction_data.initial_max_sol = reader.u64()?;
+        reader.string_into(&mut instruction_data.token_name)?;
+        reader.string_into(&mut instruction_data.token_symbol)?;
+        reader.string_into(&mut instruction_data.token_uri)?;
+        if reader.option()? {
+            instruction_data.seller_fee_basis_points = reader.u16()?;
+            instruction_data.creator_share = reader.u8()?;
+        }
+        if reader.option()? {
+            instruction_data.collection = reader.pubkey()?;
+        }
+        if reader.option()? {
+            instruction_data.transfer_fee_basis_points = reader.u16()?;
+            instruction_data.maximum_transfer_fee = reader.u64()?;
+        }
+
+        if !reader.data.is_empty() {
+            pinocchio::msg!("Trailing bytes after compact Initialize data");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok(instruction_data)
+    }
+
     /// Extract &str from fixed-size array with length prefix (no alloc)
-    fn extract_str<'a>(data: &'a [u8]) -> Result<&'a str, ProgramError> {
+    pub(crate) fn extract_str<'a>(data: &'a [u8]) -> Result<&'a str, ProgramError> {
         if data.is_empty() {
             return Ok("");
         }
@@ -162,29 +304,78 @@ impl InitializeInstructionData {
     }
 }
 
+/// Cursor over the compact Initialize encoding
+struct CompactReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> CompactReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], ProgramError> {
+        if len > self.data.len() { return Err(ProgramError::InvalidInstructionData); }
+        let (bytes, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, ProgramError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, ProgramError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u64(&mut self) -> Result<u64, ProgramError> {
+        let bytes = self.take(8)?;
+        Ok(u64::from_le_bytes(bytes.try_into().map_err(|_| ProgramError::InvalidInstructionData)?))
+    }
+
+    fn pubkey(&mut self) -> Result<Pubkey, ProgramError> {
+        self.take(32)?.try_into().map_err(|_| ProgramError::InvalidInstructionData)
+    }
+
+    /// Presence byte of an optional field
+    fn option(&mut self) -> Result<bool, ProgramError> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    /// Read a u8-length-prefixed string into a fixed field that keeps the
+    /// length in its first byte
+    fn string_into(&mut self, field: &mut [u8]) -> Result<(), ProgramError> {
+        let len = self.u8()? as usize;
+        if len >= field.len() { return Err(ProgramError::InvalidInstructionData); }
+        field[0] = len as u8;
+        field[1..1 + len].copy_from_slice(self.take(len)?);
+        Ok(())
+    }
+}
+
 impl<'info> TryFrom<&'info [u8]> for InitializeInstructionData {
     type Error = ProgramError;
 
     fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
-        pinocchio::msg!("Starting InitializeInstructionData::try_from");
-        
-        let expected_len = Self::LEN;
         let actual_len = data.len();
-        
-        if actual_len != expected_len {
+        if actual_len != Self::LEN && actual_len != Self::V1_LEN {
             pinocchio::msg!("Invalid instruction data length - size mismatch");
             return Err(ProgramError::InvalidInstructionData);
         }
-        pinocchio::msg!("Data length validation passed");
 
-        let result = bytemuck::try_from_bytes::<Self>(data)
-            .map_err(|_| {
-                pinocchio::msg!("Bytemuck deserialization failed");
-                ProgramError::InvalidInstructionData
-            })?;
+        // v1 data stops before the version byte: the later fields stay zeroed
+        let mut result = Self::zeroed();
+        bytemuck::bytes_of_mut(&mut result)[..actual_len].copy_from_slice(data);
+        if actual_len == Self::V1_LEN {
+            result.version = 1;
+        } else if result.version != Self::CURRENT_VERSION {
+            pinocchio::msg!("Unsupported Initialize data version");
+            return Err(ProgramError::InvalidInstructionData);
+        }
 
-        pinocchio::msg!("InitializeInstructionData created successfully");
-        Ok(*result)
+        Ok(result)
     }
 }
 
@@ -200,13 +391,8 @@ impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for Initialize<'info> {
     fn try_from(
         (accounts, data): (&'info [AccountInfo], &'info [u8]),
     ) -> Result<Self, Self::Error> {
-        pinocchio::msg!("Starting Initialize::try_from");
-        
         let accounts = InitializeAccounts::try_from(accounts)?;
-        pinocchio::msg!("Accounts parsed successfully");
-        
         let instruction_data = InitializeInstructionData::try_from(data)?;
-        pinocchio::msg!("Instruction data parsed successfully");
 
         Ok(Self {
             accounts,
@@ -215,14 +401,45 @@ impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for Initialize<'info> {
     }
 }
 
-/// Fixed-size metadata serialization to avoid heap allocation
-fn serialize_metadata_v2_fixed(buf: &mut [u8], offset: &mut usize, name: &str, symbol: &str, uri: &str) -> Result<(), ProgramError> {
-    let write = |b: &mut [u8], o: &mut usize, s: &[u8]| -> Result<(), ProgramError> {
-        if *o + s.len() > b.len() { return Err(ProgramError::InvalidInstructionData); }
-        b[*o..*o + s.len()].copy_from_slice(s);
-        *o += s.len();
-        Ok(())
-    };
+impl<'info> Initialize<'info> {
+    /// Launch without metadata (InitializeWithoutMetadata): fewer accounts and
+    /// only the curve fields of the instruction data, no Metaplex CPI
+    pub fn without_metadata(
+        (accounts, data): (&'info [AccountInfo], &'info [u8]),
+    ) -> Result<Self, ProgramError> {
+        let accounts = InitializeAccounts::try_from_without_metadata(accounts)?;
+        let instruction_data = InitializeInstructionData::try_from_without_metadata(data)?;
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+
+    /// Launch from the compact encoding (InitializeCompact): the Initialize
+    /// accounts with length-prefixed strings and optional fields
+    pub fn compact(
+        (accounts, data): (&'info [AccountInfo], &'info [u8]),
+    ) -> Result<Self, ProgramError> {
+        let accounts = InitializeAccounts::try_from(accounts)?;
+        let instruction_data = InitializeInstructionData::try_from_compact(data)?;
+        Ok(Self {
+            accounts,
+            instruction_data,
+        })
+    }
+}
+
+/// Append `bytes` to a fixed-size instruction buffer
+pub(crate) fn write_fixed(buf: &mut [u8], offset: &mut usize, bytes: &[u8]) -> Result<(), ProgramError> {
+    if *offset + bytes.len() > buf.len() { return Err(ProgramError::InvalidInstructionData); }
+    buf[*offset..*offset + bytes.len()].copy_from_slice(bytes);
+    *offset += bytes.len();
+    Ok(())
+}
+
+/// Serialize the name, symbol and URI of a DataV2 into a fixed-size buffer
+pub(crate) fn serialize_metadata_strings_fixed(buf: &mut [u8], offset: &mut usize, name: &str, symbol: &str, uri: &str) -> Result<(), ProgramError> {
+    let write = write_fixed;
 
     let name_bytes = name.as_bytes();
     if name_bytes.len() > 32 { return Err(ProgramError::InvalidInstructionData); }
@@ -239,428 +456,84 @@ fn serialize_metadata_v2_fixed(buf: &mut [u8], offset: &mut usize, name: &str, s
     write(buf, offset, &(uri_bytes.len() as u32).to_le_bytes())?;
     write(buf, offset, uri_bytes)?;
 
-    write(buf, offset, &0u16.to_le_bytes())?;
-    write(buf, offset, &[0])?; // creators None
-    write(buf, offset, &[0])?; // collection None
-    write(buf, offset, &[0])?; // uses None
-
     Ok(())
 }
 
-/// Build CreateMetadataAccountV3 instruction with fixed-size buffer
-fn build_create_metadata_instruction_fixed(buf: &mut [u8], name: &str, symbol: &str, uri: &str) -> Result<usize, ProgramError> {
-    let mut offset = 0usize;
-    if offset + 1 > buf.len() { return Err(ProgramError::InvalidInstructionData); }
-    buf[offset] = CREATE_METADATA_ACCOUNT_V3;
-    offset += 1;
-
-    serialize_metadata_v2_fixed(buf, &mut offset, name, symbol, uri)?;
-    if offset + 2 > buf.len() { return Err(ProgramError::InvalidInstructionData); }
-    buf[offset] = 1; offset += 1; // isMutable
-    buf[offset] = 0; offset += 1; // collectionDetails None
-    Ok(offset)
-}
-
 impl<'info> Initialize<'info> {
+    /// Run the launch steps in one instruction: CreateCurve, then
+    /// CreateMetadata when there is metadata to create, then InitialBuy when
+    /// a pre-buy amount is set
     pub fn handler(&mut self) -> Result<(), ProgramError> {
-        pinocchio::msg!("Starting initialize handler");
-        
-        // Validate accounts
-        if !self.accounts.authority.is_signer() {
-            pinocchio::msg!("Authority must be signer");
-            return Err(ProgramError::MissingRequiredSignature);
-        }
-
-        if !self.accounts.payer.is_signer() {
-            pinocchio::msg!("Payer must be signer");
-            return Err(ProgramError::MissingRequiredSignature);
-        }
-
-        // Validate curve parameters (0=linear,1=exp,2=log,3=cpmm)
-        if self.instruction_data.curve_type > 3 {
-            pinocchio::msg!("Invalid curve type");
-            return Err(XTokenError::InvalidCurveParameters.into());
-        }
-
-        if self.instruction_data.base_price == 0 {
-            pinocchio::msg!("Base price cannot be zero");
-            return Err(XTokenError::InvalidCurveParameters.into());
-        }
-
-        if self.instruction_data.max_supply == 0 {
-            pinocchio::msg!("Max supply cannot be zero");
-            return Err(XTokenError::InvalidCurveParameters.into());
-        }
-
-        if self.instruction_data.fee_basis_points > 1000 {
-            pinocchio::msg!("Fee basis points too high");
-            return Err(XTokenError::InvalidCurveParameters.into());
-        }
-
-        pinocchio::msg!("Basic validation passed, extracting metadata strings");
-        
-        // Extract metadata strings - this is where the panic might occur
-        let token_name = self.instruction_data.get_token_name().map_err(|e| {
-            pinocchio::msg!("Failed to extract token name");
-            e
-        })?;
-        pinocchio::msg!("Token name extracted successfully");
-        
-        let token_symbol = self.instruction_data.get_token_symbol().map_err(|e| {
-            pinocchio::msg!("Failed to extract token symbol");
-            e
-        })?;
-        pinocchio::msg!("Token symbol extracted successfully");
-        
-        let token_uri = self.instruction_data.get_token_uri().map_err(|e| {
-            pinocchio::msg!("Failed to extract token URI");
-            e
-        })?;
-        pinocchio::msg!("Token URI extracted successfully");
-        
-        let owner_str = self.instruction_data.get_owner().map_err(|e| {
-            pinocchio::msg!("Failed to extract owner");
-            e
-        })?;
-        pinocchio::msg!("All strings extracted successfully");
-
-        // Derive bonding curve PDA
-        pinocchio::msg!("Deriving bonding curve PDA");
-        let seeds = &[XToken::SEED_PREFIX, self.accounts.mint.key().as_ref()];
-        let (bonding_curve_address, bump) =
-            pinocchio::pubkey::find_program_address(seeds, &crate::ID);
-
-        if bonding_curve_address != *self.accounts.bonding_curve.key() {
-            pinocchio::msg!("Invalid bonding curve PDA");
-            return Err(ProgramError::InvalidSeeds);
-        }
-
-        // Derive treasury PDA
-        pinocchio::msg!("Deriving treasury PDA");
-        let treasury_seeds = &[b"treasury", self.accounts.mint.key().as_ref()];
-        let (treasury_address, treasury_bump) =
-            pinocchio::pubkey::find_program_address(treasury_seeds, &crate::ID);
-
-        if treasury_address != *self.accounts.treasury.key() {
-            pinocchio::msg!("Invalid treasury PDA");
-            return Err(ProgramError::InvalidSeeds);
-        }
-
-        // Derive metadata PDA and verify
-        pinocchio::msg!("Deriving metadata PDA");
-        let metaplex_program_id = Pubkey::from(METAPLEX_TOKEN_METADATA_ID);
-        let metadata_seeds = &[
-            METADATA_PREFIX,
-            metaplex_program_id.as_ref(),
-            self.accounts.mint.key().as_ref(),
-        ];
-        let (metadata_address, _metadata_bump) =
-            pinocchio::pubkey::find_program_address(metadata_seeds, &metaplex_program_id);
-
-        if metadata_address != *self.accounts.metadata_account.key() {
-            pinocchio::msg!("Invalid metadata PDA");
-            return Err(ProgramError::InvalidSeeds);
-        }
+        let config = ProtocolConfig::from_account(self.accounts.config)?;
 
-        pinocchio::msg!("All PDAs validated, creating bonding curve account");
-
-        // Create bonding curve PDA account
-        let space = XToken::LEN;
-        let rent = Rent::get()?;
-        let lamports = rent.minimum_balance(space);
-
-        let bump_bytes = [bump];
-        let seeds = [
-            Seed::from(XToken::SEED_PREFIX),
-            Seed::from(self.accounts.mint.key().as_ref()),
-            Seed::from(&bump_bytes),
-        ];
-        let signer = Signer::from(&seeds);
-
-        pinocchio_system::instructions::CreateAccount {
-            from: self.accounts.payer,
-            to: self.accounts.bonding_curve,
-            space: space as u64,
-            lamports,
-            owner: &crate::ID,
-        }
-        .invoke_signed(&[signer])?;
-
-        pinocchio::msg!("Bonding curve account created, creating treasury account");
-
-        // Create treasury PDA account (system-owned, space=0)
-        let treasury_space = 0;
-        let treasury_lamports = rent.minimum_balance(treasury_space);
-
-        let treasury_bump_bytes = [treasury_bump];
-        let treasury_seeds = [
-            Seed::from(b"treasury"),
-            Seed::from(self.accounts.mint.key().as_ref()),
-            Seed::from(&treasury_bump_bytes),
-        ];
-        let treasury_signer = Signer::from(&treasury_seeds);
-
-        pinocchio_system::instructions::CreateAccount {
-            from: self.accounts.payer,
-            to: self.accounts.treasury,
-            space: treasury_space as u64,
-            lamports: treasury_lamports,
-            owner: &pinocchio_system::ID,
-        }
-        .invoke_signed(&[treasury_signer])?;
-
-        pinocchio::msg!("Treasury account created, verifying mint");
-
-        // Verify mint account exists (should be created by client)
-        if self.accounts.mint.data_is_empty() {
-            pinocchio::msg!("Mint account not initialized");
-            return Err(ProgramError::UninitializedAccount);
-        }
+        let metadata_data = CreateMetadataInstructionData::from(&self.instruction_data);
+        metadata_data.validate()?;
 
-        pinocchio::msg!("Initializing mint");
-
-        // Initialize mint with bonding curve as authority
-        pinocchio_token::instructions::InitializeMint2 {
-            mint: self.accounts.mint,
-            decimals: self.instruction_data.decimals,
-            mint_authority: &bonding_curve_address,
-            freeze_authority: Some(&bonding_curve_address),
+        CreateCurve {
+            accounts: CreateCurveAccounts {
+                authority: self.accounts.authority,
+                bonding_curve: self.accounts.bonding_curve,
+                mint: self.accounts.mint,
+                treasury: self.accounts.treasury,
+                payer: self.accounts.payer,
+                system_program: self.accounts.system_program,
+                token_program: self.accounts.token_program,
+                config: self.accounts.config,
+                signers: self.accounts.protocol_fee_recipient.map_or(&[][..], core::slice::from_ref),
+            },
+            instruction_data: CreateCurveInstructionData::from(&self.instruction_data),
         }
-        .invoke()?;
-
-        pinocchio::msg!("Mint initialized, building metadata instruction");
-        
-        // Create metadata instruction with fixed-size buffer
-        let mut ix_buf = [0u8; 300];
-        let ix_len = build_create_metadata_instruction_fixed(
-            &mut ix_buf,
-            token_name,
-            token_symbol,
-            token_uri,
-        ).map_err(|e| {
-            pinocchio::msg!("Failed to build metadata instruction");
-            e
-        })?;
-
-        pinocchio::msg!("Metadata instruction built successfully");
-        
-        // Calculate actual size of instruction data
-        let actual_data_size = 1 + // discriminator
-            4 + token_name.len() + // name
-            4 + token_symbol.len() + // symbol  
-            4 + token_uri.len() + // uri
-            2 + // seller_fee_basis_points
-            1 + // creators (None)
-            1 + // collection (None)  
-            1 + // uses (None)
-            1 + // is_mutable
-            1; // collection_details (None)
-
-        // Build the instruction struct manually with only the used data
-        let metadata_instruction = pinocchio::instruction::Instruction {
-            program_id: &metaplex_program_id,
-            accounts: &[
-                AccountMeta {
-                    pubkey: self.accounts.metadata_account.key(),
-                    is_signer: false,
-                    is_writable: true,
-                },
-                AccountMeta {
-                    pubkey: self.accounts.mint.key(),
-                    is_signer: false,
-                    is_writable: false,
-                },
-                AccountMeta {
-                    pubkey: self.accounts.bonding_curve.key(), // mint_authority
-                    is_signer: true,
-                    is_writable: false,
-                },
-                AccountMeta {
-                    pubkey: self.accounts.payer.key(),
-                    is_signer: true,
-                    is_writable: true,
-                },
-                AccountMeta {
-                    pubkey: self.accounts.authority.key(), // update_authority
-                    is_signer: false,
-                    is_writable: false,
-                },
-                AccountMeta {
-                    pubkey: self.accounts.system_program.key(),
-                    is_signer: false,
-                    is_writable: false,
-                },
-                AccountMeta {
-                    pubkey: self.accounts.rent.key(),
-                    is_signer: false,
-                    is_writable: false,
+        .create(&config, self.accounts.metadata.is_some())?;
+
+        // Token-2022 mints keep the metadata in the mint; classic mints only
+        // get Metaplex metadata while the protocol enables it
+        let token_2022 = *self.accounts.token_program.key() == TOKEN_2022_PROGRAM_ID;
+        let metadata = self.accounts.metadata.as_ref().filter(|_| token_2022 || config.use_metaplex != 0);
+        if let Some(metadata) = metadata {
+            CreateMetadata {
+                accounts: CreateMetadataAccounts {
+                    authority: self.accounts.authority,
+                    bonding_curve: self.accounts.bonding_curve,
+                    mint: self.accounts.mint,
+                    payer: self.accounts.payer,
+                    system_program: self.accounts.system_program,
+                    token_program: self.accounts.token_program,
+                    rent: metadata.rent,
+                    metadata_account: metadata.metadata_account,
+                    metaplex_program: metadata.metaplex_program,
+                    config: self.accounts.config,
+                    signers: &[],
                 },
-            ],
-            data: &ix_buf[..ix_len.min(actual_data_size)],
-        };
-
-        pinocchio::msg!("Preparing to invoke metadata creation");
-
-        // Prepare signer seeds for bonding curve - use Seed array directly
-        let bump_bytes = [bump];
-        let signer_seeds = [
-            Seed::from(XToken::SEED_PREFIX),
-            Seed::from(self.accounts.mint.key().as_ref()),
-            Seed::from(&bump_bytes),
-        ];
-        let signer = Signer::from(&signer_seeds);
-
-        // Collect account infos for metadata creation
-        let metadata_account_infos = [
-            self.accounts.metadata_account,
-            self.accounts.mint,
-            self.accounts.bonding_curve, // mint_authority (signer)
-            self.accounts.payer,
-            self.accounts.authority, // update_authority
-            self.accounts.system_program,
-            self.accounts.rent,
-        ];
-
-        pinocchio::msg!("Invoking Metaplex metadata creation");
-
-        // Invoke Metaplex to create metadata
-        pinocchio::program::invoke_signed(
-            &metadata_instruction,
-            &metadata_account_infos,
-            &[signer],
-        ).map_err(|e| {
-            pinocchio::msg!("Metaplex metadata creation failed");
-            e
-        })?;
-
-        pinocchio::msg!("Metadata created successfully, initializing bonding curve state");
-
-        // Initialize bonding curve state
-        let mut bonding_curve_data = self.accounts.bonding_curve.try_borrow_mut_data()?;
-        let bonding_curve = XToken::load_mut(&mut bonding_curve_data)?;
-
-        bonding_curve.initialize(
-            *self.accounts.authority.key(),
-            *self.accounts.mint.key(),
-            self.instruction_data.curve_type,
-            self.instruction_data.base_price,
-            self.instruction_data.slope,
-            self.instruction_data.max_supply,
-            self.instruction_data.fee_basis_points,
-            self.instruction_data.fee_recipient,
-            &owner_str,
-            bump,
-        )?;
-
-        pinocchio::msg!("Bonding curve initialized");
+                instruction_data: metadata_data,
+            }
+            .create(&config)?;
+        }
 
         // Optional: perform initial pre-buy similar to pump.fun
         if self.instruction_data.initial_buy_amount > 0 {
-            pinocchio::msg!("Processing initial buy");
-            
-            // Drop mutable borrow of bonding_curve before re-borrowing
-            drop(bonding_curve_data);
-
-            // Compute cost and fee from fresh immutable snapshot
-            let (total_cost, fee) = {
-                let bonding_curve_data = self.accounts.bonding_curve.try_borrow_data()?;
-                let bonding_curve_ro = XToken::load(&bonding_curve_data)?;
-                let total_cost = bonding_curve_ro.calculate_buy_price(self.instruction_data.initial_buy_amount)?;
-                let fee = bonding_curve_ro.calculate_fee(total_cost)?;
-                (total_cost, fee)
-            };
-
-            let total_with_fee = total_cost
-                .checked_add(fee)
-                .ok_or(ProgramError::ArithmeticOverflow)?;
-
-            // Slippage check
-            if total_with_fee > self.instruction_data.initial_max_sol {
-                pinocchio::msg!("Slippage exceeded");
-                return Err(XTokenError::SlippageExceeded.into());
-            }
-
-            // Treasury cap like in buy (84 SOL)
-            const SOL_CAP_LAMPORTS: u64 = 84_000_000_000;
-            let sol_reserve_snapshot = {
-                let bonding_curve_data = self.accounts.bonding_curve.try_borrow_data()?;
-                let bonding_curve_ro = XToken::load(&bonding_curve_data)?;
-                bonding_curve_ro.sol_reserve
-            };
-            let new_reserve = sol_reserve_snapshot
-                .checked_add(total_cost)
-                .ok_or(ProgramError::ArithmeticOverflow)?;
-            if new_reserve > SOL_CAP_LAMPORTS {
-                pinocchio::msg!("Treasury cap exceeded");
-                return Err(ProgramError::InvalidArgument);
-            }
-
-            // Ensure payer has enough SOL
-            if self.accounts.payer.lamports() < total_with_fee {
-                pinocchio::msg!("Insufficient funds");
-                return Err(XTokenError::InsufficientFunds.into());
-            }
-
-            // Derive signer seeds for bonding curve
-            let bump_bytes = [bump];
-            let seeds = [
-                Seed::from(XToken::SEED_PREFIX),
-                Seed::from(self.accounts.mint.key().as_ref()),
-                Seed::from(&bump_bytes),
-            ];
-            let signer = Signer::from(&seeds);
-
-            // Ensure ATA exists
-            if self.accounts.authority_token_account.data_is_empty() {
-                pinocchio::msg!("Creating authority ATA");
-                pinocchio_associated_token_account::instructions::Create {
-                    account: self.accounts.authority_token_account,
+            InitialBuy {
+                accounts: InitialBuyAccounts {
+                    authority: self.accounts.authority,
+                    bonding_curve: self.accounts.bonding_curve,
                     mint: self.accounts.mint,
-                    funding_account: self.accounts.payer,
+                    treasury: self.accounts.treasury,
+                    authority_token_account: self.accounts.authority_token_account,
+                    payer: self.accounts.payer,
+                    fee_recipient_account: self.accounts.fee_recipient_account,
                     system_program: self.accounts.system_program,
                     token_program: self.accounts.token_program,
-                    wallet: self.accounts.authority,
-                }
-                .invoke()?;
-            }
-
-            // Transfer SOL to treasury and fee recipient from payer
-            pinocchio::msg!("Transferring SOL to treasury");
-            pinocchio_system::instructions::Transfer {
-                from: self.accounts.payer,
-                to: self.accounts.treasury,
-                lamports: total_cost,
-            }
-            .invoke()?;
-            
-            if fee > 0 {
-                pinocchio::msg!("Transferring fee");
-                pinocchio_system::instructions::Transfer {
-                    from: self.accounts.payer,
-                    to: self.accounts.fee_recipient_account,
-                    lamports: fee,
-                }
-                .invoke()?;
-            }
-
-            // Mint tokens to authority
-            pinocchio::msg!("Minting initial tokens");
-            pinocchio_token::instructions::MintTo {
-                mint: self.accounts.mint,
-                account: self.accounts.authority_token_account,
-                mint_authority: self.accounts.bonding_curve,
-                amount: self.instruction_data.initial_buy_amount,
-            }
-            .invoke_signed(&[signer])?;
-
-            // Update state
-            {
-                let mut bonding_curve_data = self.accounts.bonding_curve.try_borrow_mut_data()?;
-                let bonding_curve = XToken::load_mut(&mut bonding_curve_data)?;
-                bonding_curve.update_buy(self.instruction_data.initial_buy_amount, total_cost)?;
+                    associated_token_program: self.accounts.associated_token_program,
+                    config: self.accounts.config,
+                    signers: &[],
+                },
+                instruction_data: InitialBuyInstructionData {
+                    token_amount: self.instruction_data.initial_buy_amount,
+                    max_sol_amount: self.instruction_data.initial_max_sol,
+                },
             }
+            .buy(&config)?;
         }
 
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,137 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+};
+
+use crate::state::{AccountData, ProtocolConfig};
+
+/// Accounts for InitializeConfig instruction
+pub struct InitializeConfigAccounts<'info> {
+    /// Protocol admin (becomes config admin)
+    pub admin: &'info AccountInfo,
+    /// Protocol config account (PDA)
+    pub config: &'info AccountInfo,
+    /// Payer for account creation
+    pub payer: &'info AccountInfo,
+    /// System program
+    pub system_program: &'info AccountInfo,
+}
+
+impl<'info> InitializeConfigAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 4 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            admin: &accounts[0],
+            config: &accounts[1],
+            payer: &accounts[2],
+            system_program: &accounts[3],
+        })
+    }
+}
+
+/// Instruction data for InitializeConfig
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct InitializeConfigInstructionData {
+    /// Default fee recipient
+    pub fee_recipient: Pubkey,
+    /// Maximum SOL (lamports) a single curve treasury may hold
+    pub sol_cap_lamports: u64,
+    /// Lowest fee a curve may charge, in basis points
+    pub min_fee_basis_points: u16,
+    /// Highest fee a curve may charge, in basis points
+    pub max_fee_basis_points: u16,
+    /// Whether Initialize creates Metaplex metadata (0 = false, 1 = true)
+    pub use_metaplex: u8,
+    /// Whether launches and trading start paused (0 = false, 1 = true)
+    pub paused: u8,
+}
+
+impl InitializeConfigInstructionData {
+    pub const LEN: usize = core::mem::size_of::<InitializeConfigInstructionData>();
+}
+
+impl<'info> TryFrom<&'info [u8]> for InitializeConfigInstructionData {
+    type Error = ProgramError;
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        bytemuck::try_from_bytes::<Self>(data)
+            .copied()
+            .map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+pub struct InitializeConfig<'info> {
+    pub accounts: InitializeConfigAccounts<'info>,
+    pub instruction_data: InitializeConfigInstructionData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for InitializeConfig<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        let accounts = InitializeConfigAccounts::try_from(accounts)?;
+        let instruction_data = InitializeConfigInstructionData::try_from(data)?;
+        Ok(Self { accounts, instruction_data })
+    }
+}
+
+impl<'info> InitializeConfig<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        if !self.accounts.admin.is_signer() || !self.accounts.payer.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // derive config PDA
+        let (config_address, bump) =
+            pinocchio::pubkey::find_program_address(&[ProtocolConfig::SEED_PREFIX], &crate::ID);
+        if config_address != *self.accounts.config.key() {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        if !self.accounts.config.data_is_empty() {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        // create config PDA account
+        let space = ProtocolConfig::LEN;
+        let lamports = Rent::get()?.minimum_balance(space);
+
+        let bump_bytes = [bump];
+        let seeds = [
+            Seed::from(ProtocolConfig::SEED_PREFIX),
+            Seed::from(&bump_bytes),
+        ];
+        let signer = Signer::from(&seeds);
+
+        pinocchio_system::instructions::CreateAccount {
+            from: self.accounts.payer,
+            to: self.accounts.config,
+            space: space as u64,
+            lamports,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&[signer])?;
+
+        let mut config_data = self.accounts.config.try_borrow_mut_data()?;
+        let config = ProtocolConfig::load_mut(&mut config_data)?;
+        config.initialize(*self.accounts.admin.key(), bump)?;
+        config.update(
+            self.instruction_data.fee_recipient,
+            self.instruction_data.sol_cap_lamports,
+            self.instruction_data.min_fee_basis_points,
+            self.instruction_data.max_fee_basis_points,
+            self.instruction_data.use_metaplex != 0,
+            self.instruction_data.paused != 0,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+};
+
+use crate::{
+    error::XTokenError,
+    state::{AccountData, XToken},
+    token_interface::mint_supply,
+};
+
+/// Accounts for MigrateAccount instruction
+pub struct MigrateAccountAccounts<'info> {
+    /// Payer covering the rent difference
+    pub payer: &'info AccountInfo,
+    /// Bonding curve state account (PDA)
+    pub bonding_curve: &'info AccountInfo,
+    /// System program
+    pub system_program: &'info AccountInfo,
+    /// Protocol config account (PDA, checked for the emergency halt)
+    pub config: &'info AccountInfo,
+    /// Token mint account of the curve (read for tokens the v1 admin minted)
+    pub mint: &'info AccountInfo,
+}
+
+impl<'info> MigrateAccountAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 5 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            payer: &accounts[0],
+            bonding_curve: &accounts[1],
+            system_program: &accounts[2],
+            config: &accounts[3],
+            mint: &accounts[4],
+        })
+    }
+}
+
+pub struct MigrateAccount<'info> {
+    pub accounts: MigrateAccountAccounts<'info>,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for MigrateAccount<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let accounts = MigrateAccountAccounts::try_from(accounts)?;
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> MigrateAccount<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        if !self.accounts.payer.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // Layout is forward-compatible: every version extends the previous one,
+        // so migrating is growing the account, stamping the version byte and
+        // filling in the fields v1 kept elsewhere
+        let version = {
+            let data = self.accounts.bonding_curve.try_borrow_data()?;
+            XToken::version_of(&data)?
+        };
+        match version {
+            1 => {}
+            XToken::CURRENT_VERSION => return Err(XTokenError::AccountAlreadyMigrated.into()),
+            _ => return Err(ProgramError::InvalidAccountData),
+        }
+
+        // Top up rent for the larger layout
+        let required = Rent::get()?.minimum_balance(XToken::LEN);
+        let current = self.accounts.bonding_curve.lamports();
+        if required > current {
+            pinocchio_system::instructions::Transfer {
+                from: self.accounts.payer,
+                to: self.accounts.bonding_curve,
+                lamports: required - current,
+            }
+            .invoke()?;
+        }
+
+        self.accounts.bonding_curve.realloc(XToken::LEN, true)?;
+
+        let mut data = self.accounts.bonding_curve.try_borrow_mut_data()?;
+        data[XToken::VERSION_OFFSET] = XToken::CURRENT_VERSION;
+        let state = XToken::load_mut(&mut data)?;
+        if state.is_initialized == 0 {
+            return Err(XTokenError::AccountNotInitialized.into());
+        }
+        if state.token_mint != *self.accounts.mint.key() {
+            return Err(XTokenError::InvalidAccountData.into());
+        }
+        // v1 is the original 216-byte layout: its admin sat where the pending
+        // authority now lives, and the bytes of version, paused and graduated
+        // were reserved. The admin (the fee recipient when unset) keeps
+        // AdminMint and WithdrawReserves as minter and treasurer; the other
+        // roles start out with the authority, as on a new curve.
+        let legacy_admin = if state.pending_authority == [0u8; 32] {
+            state.fee_recipient
+        } else {
+            state.pending_authority
+        };
+        state.pending_authority = [0; 32];
+        state.paused = 0;
+        state.graduated = (state.total_supply >= state.curve_max_supply()) as u8;
+        state.operator = state.authority;
+        state.fee_manager = state.authority;
+        state.minter = legacy_admin;
+        state.treasurer = legacy_admin;
+        // v1 AdminMint was uncapped and left no record: every token beyond
+        // what the curve sold was minted by the admin. Recording it keeps the
+        // curve from closing or releasing its reserve while those tokens
+        // circulate; with no allocation left, AdminMint stays closed.
+        state.creator_minted = mint_supply(self.accounts.mint)?.saturating_sub(state.total_supply);
+
+        Ok(())
+    }
+}
@@ -1,10 +1,40 @@
 use pinocchio::program_error::ProgramError;
 
+use crate::state::Role;
+
 pub mod initialize;
 pub mod buy_tokens;
 pub mod sell_tokens;
 pub mod withdraw_reserves;
 pub mod admin_mint;
+pub mod initialize_config;
+pub mod update_config;
+pub mod update_curve_config;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod cancel_authority_transfer;
+pub mod set_role;
+pub mod migrate_account;
+pub mod pause_curve;
+pub mod resume_curve;
+pub mod set_emergency_halt;
+pub mod set_guardian;
+pub mod create_multisig;
+pub mod queue_action;
+pub mod execute_action;
+pub mod cancel_action;
+pub mod create_vesting;
+pub mod claim_vested;
+pub mod revoke_vesting;
+pub mod renounce_authorities;
+pub mod close_curve;
+pub mod update_metadata;
+pub mod verify_collection;
+pub mod harvest_withheld_fees;
+pub mod create_curve;
+pub mod create_metadata;
+pub mod initial_buy;
+pub mod claim_creation_rebate;
 
 // Re-export structs for processor to use
 pub use initialize::Initialize;
@@ -12,6 +42,34 @@ pub use buy_tokens::BuyTokens;
 pub use sell_tokens::SellTokens;
 pub use withdraw_reserves::WithdrawReserves;
 pub use admin_mint::AdminMint;
+pub use initialize_config::InitializeConfig;
+pub use update_config::UpdateConfig;
+pub use update_curve_config::UpdateCurveConfig;
+pub use propose_authority::ProposeAuthority;
+pub use accept_authority::AcceptAuthority;
+pub use cancel_authority_transfer::CancelAuthorityTransfer;
+pub use set_role::SetRole;
+pub use migrate_account::MigrateAccount;
+pub use pause_curve::PauseCurve;
+pub use resume_curve::ResumeCurve;
+pub use set_emergency_halt::SetEmergencyHalt;
+pub use set_guardian::SetGuardian;
+pub use create_multisig::CreateMultisig;
+pub use queue_action::QueueAction;
+pub use execute_action::ExecuteAction;
+pub use cancel_action::CancelAction;
+pub use create_vesting::CreateVesting;
+pub use claim_vested::ClaimVested;
+pub use revoke_vesting::RevokeVesting;
+pub use renounce_authorities::RenounceAuthorities;
+pub use close_curve::CloseCurve;
+pub use update_metadata::UpdateMetadata;
+pub use verify_collection::VerifyCollection;
+pub use harvest_withheld_fees::HarvestWithheldFees;
+pub use create_curve::CreateCurve;
+pub use create_metadata::CreateMetadata;
+pub use initial_buy::InitialBuy;
+pub use claim_creation_rebate::ClaimCreationRebate;
 
 #[derive(Debug)]
 pub enum Instruction {
@@ -20,6 +78,36 @@ pub enum Instruction {
     SellTokens,
     WithdrawReserves,
     AdminMint,
+    InitializeConfig,
+    UpdateConfig,
+    UpdateCurveConfig,
+    ProposeAuthority,
+    AcceptAuthority,
+    CancelAuthorityTransfer,
+    SetRole,
+    MigrateAccount,
+    PauseCurve,
+    ResumeCurve,
+    SetEmergencyHalt,
+    SetGuardian,
+    CreateMultisig,
+    QueueAction,
+    ExecuteAction,
+    CancelAction,
+    CreateVesting,
+    ClaimVested,
+    RevokeVesting,
+    RenounceAuthorities,
+    CloseCurve,
+    UpdateMetadata,
+    VerifyCollection,
+    HarvestWithheldFees,
+    InitializeWithoutMetadata,
+    InitializeCompact,
+    CreateCurve,
+    CreateMetadata,
+    InitialBuy,
+    ClaimCreationRebate,
 }
 
 impl TryFrom<u8> for Instruction {
@@ -32,7 +120,86 @@ impl TryFrom<u8> for Instruction {
             2 => Ok(Instruction::SellTokens),
             3 => Ok(Instruction::WithdrawReserves),
             4 => Ok(Instruction::AdminMint),
+            5 => Ok(Instruction::InitializeConfig),
+            6 => Ok(Instruction::UpdateConfig),
+            7 => Ok(Instruction::UpdateCurveConfig),
+            8 => Ok(Instruction::ProposeAuthority),
+            9 => Ok(Instruction::AcceptAuthority),
+            10 => Ok(Instruction::CancelAuthorityTransfer),
+            11 => Ok(Instruction::SetRole),
+            12 => Ok(Instruction::MigrateAccount),
+            13 => Ok(Instruction::PauseCurve),
+            14 => Ok(Instruction::ResumeCurve),
+            15 => Ok(Instruction::SetEmergencyHalt),
+            16 => Ok(Instruction::SetGuardian),
+            17 => Ok(Instruction::CreateMultisig),
+            18 => Ok(Instruction::QueueAction),
+            19 => Ok(Instruction::ExecuteAction),
+            20 => Ok(Instruction::CancelAction),
+            21 => Ok(Instruction::CreateVesting),
+            22 => Ok(Instruction::ClaimVested),
+            23 => Ok(Instruction::RevokeVesting),
+            24 => Ok(Instruction::RenounceAuthorities),
+            25 => Ok(Instruction::CloseCurve),
+            26 => Ok(Instruction::UpdateMetadata),
+            27 => Ok(Instruction::VerifyCollection),
+            28 => Ok(Instruction::HarvestWithheldFees),
+            29 => Ok(Instruction::InitializeWithoutMetadata),
+            30 => Ok(Instruction::InitializeCompact),
+            31 => Ok(Instruction::CreateCurve),
+            32 => Ok(Instruction::CreateMetadata),
+            33 => Ok(Instruction::InitialBuy),
+            34 => Ok(Instruction::ClaimCreationRebate),
             _ => Err(ProgramError::InvalidInstructionData),
         }
     }
 }
+
+impl Instruction {
+    /// Instructions that keep working while the protocol-wide emergency halt
+    /// is active: config and guardian management, user exits, and actions
+    /// that only reduce risk or touch no curve
+    pub fn allowed_during_halt(&self) -> bool {
+        matches!(
+            self,
+            Instruction::InitializeConfig
+                | Instruction::UpdateConfig
+                | Instruction::SetEmergencyHalt
+                | Instruction::SetGuardian
+                | Instruction::SellTokens
+                | Instruction::PauseCurve
+                | Instruction::CancelAuthorityTransfer
+                | Instruction::CreateMultisig
+                | Instruction::CancelAction
+                | Instruction::ClaimVested
+        )
+    }
+
+    /// Role required to queue this instruction through QueueAction, or `None`
+    /// if it is not a timelocked action. While the protocol timelock delay is
+    /// non-zero these can only run through ExecuteAction.
+    pub fn timelock_role(&self) -> Option<Role> {
+        match self {
+            Instruction::WithdrawReserves
+            | Instruction::ProposeAuthority
+            | Instruction::SetRole
+            | Instruction::RenounceAuthorities
+            | Instruction::CloseCurve => Some(Role::Owner),
+            Instruction::AdminMint | Instruction::CreateVesting => Some(Role::Minter),
+            Instruction::UpdateCurveConfig => Some(Role::FeeManager),
+            _ => None,
+        }
+    }
+
+    /// Position, among this timelocked instruction's accounts, of the account
+    /// receiving value from it. QueueAction records its key so ExecuteAction
+    /// cannot redirect the action elsewhere.
+    pub fn timelock_destination_index(&self) -> Option<usize> {
+        match self {
+            Instruction::WithdrawReserves | Instruction::CloseCurve => Some(4), // recipient
+            Instruction::AdminMint => Some(3), // recipient token account
+            Instruction::CreateVesting => Some(5), // beneficiary
+            _ => None,
+        }
+    }
+}
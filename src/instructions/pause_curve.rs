@@ -0,0 +1,66 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    error::XTokenError,
+    state::{check_authority_signed, AccountData, Role, XToken},
+};
+
+/// Accounts for PauseCurve instruction
+pub struct PauseCurveAccounts<'info> {
+    /// Authority (must hold the owner or operator role)
+    pub authority: &'info AccountInfo,
+    /// Bonding curve state account (PDA)
+    pub bonding_curve: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig (remaining accounts)
+    pub signers: &'info [AccountInfo],
+}
+
+impl<'info> PauseCurveAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 2 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            authority: &accounts[0],
+            bonding_curve: &accounts[1],
+            signers: &accounts[2..],
+        })
+    }
+}
+
+/// Pause trading and minting on a curve
+pub struct PauseCurve<'info> {
+    pub accounts: PauseCurveAccounts<'info>,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for PauseCurve<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let accounts = PauseCurveAccounts::try_from(accounts)?;
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> PauseCurve<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
+
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let mut bonding_curve_data = self.accounts.bonding_curve.try_borrow_mut_data()?;
+        let state = XToken::load_mut(&mut bonding_curve_data)?;
+        if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+        let key = self.accounts.authority.key();
+        if !state.has_role(Role::Owner, key) && !state.has_role(Role::Operator, key) {
+            return Err(XTokenError::InvalidAuthority.into());
+        }
+
+        state.paused = 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::XTokenError,
+    state::{check_authority_signed, AccountData, Role, XToken},
+};
+
+/// Accounts for ProposeAuthority instruction
+pub struct ProposeAuthorityAccounts<'info> {
+    /// Current authority (must match bonding curve authority)
+    pub authority: &'info AccountInfo,
+    /// Bonding curve state account (PDA)
+    pub bonding_curve: &'info AccountInfo,
+    /// Protocol config account (PDA, checked for the emergency halt)
+    pub config: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig (remaining accounts)
+    pub signers: &'info [AccountInfo],
+}
+
+impl<'info> ProposeAuthorityAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 3 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            authority: &accounts[0],
+            bonding_curve: &accounts[1],
+            config: &accounts[2],
+            signers: &accounts[3..],
+        })
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct ProposeAuthorityInstructionData {
+    /// Proposed new authority (must sign AcceptAuthority)
+    pub new_authority: Pubkey,
+}
+
+impl ProposeAuthorityInstructionData {
+    pub const LEN: usize = core::mem::size_of::<ProposeAuthorityInstructionData>();
+}
+
+impl<'info> TryFrom<&'info [u8]> for ProposeAuthorityInstructionData {
+    type Error = ProgramError;
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let new_authority: Pubkey = data[0..32]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        Ok(Self { new_authority })
+    }
+}
+
+pub struct ProposeAuthority<'info> {
+    pub accounts: ProposeAuthorityAccounts<'info>,
+    pub instruction_data: ProposeAuthorityInstructionData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for ProposeAuthority<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        let accounts = ProposeAuthorityAccounts::try_from(accounts)?;
+        let instruction_data = ProposeAuthorityInstructionData::try_from(data)?;
+        Ok(Self { accounts, instruction_data })
+    }
+}
+
+impl<'info> ProposeAuthority<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
+
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let mut bonding_curve_data = self.accounts.bonding_curve.try_borrow_mut_data()?;
+        let state = XToken::load_mut(&mut bonding_curve_data)?;
+        if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+        if !state.has_role(Role::Owner, self.accounts.authority.key()) { return Err(XTokenError::InvalidAuthority.into()); }
+
+        state.propose_authority(self.instruction_data.new_authority)
+    }
+}
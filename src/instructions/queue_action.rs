@@ -0,0 +1,207 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+};
+
+use crate::{
+    error::XTokenError,
+    instructions::{
+        admin_mint::AdminMintInstructionData, create_vesting::CreateVestingInstructionData,
+        propose_authority::ProposeAuthorityInstructionData,
+        renounce_authorities::RenounceAuthoritiesInstructionData, set_role::SetRoleInstructionData,
+        update_curve_config::UpdateCurveConfigInstructionData,
+        withdraw_reserves::WithdrawReservesInstructionData, Instruction,
+    },
+    state::{check_authority_signed, AccountData, PendingAction, ProtocolConfig, XToken},
+};
+
+/// Accounts for QueueAction instruction
+pub struct QueueActionAccounts<'info> {
+    /// Authority (must hold the role the queued instruction requires)
+    pub authority: &'info AccountInfo,
+    /// Bonding curve state account (PDA)
+    pub bonding_curve: &'info AccountInfo,
+    /// Pending action account (PDA)
+    pub action: &'info AccountInfo,
+    /// Payer for account creation (receives the rent back on close)
+    pub payer: &'info AccountInfo,
+    /// System program
+    pub system_program: &'info AccountInfo,
+    /// Protocol config account (PDA)
+    pub config: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig (remaining accounts)
+    pub signers: &'info [AccountInfo],
+}
+
+impl<'info> QueueActionAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 6 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            authority: &accounts[0],
+            bonding_curve: &accounts[1],
+            action: &accounts[2],
+            payer: &accounts[3],
+            system_program: &accounts[4],
+            config: &accounts[5],
+            signers: &accounts[6..],
+        })
+    }
+}
+
+/// Instruction data for QueueAction: action id, the queued instruction's
+/// discriminator, the account it pays out to and its instruction data
+pub struct QueueActionInstructionData<'info> {
+    /// Caller-chosen id distinguishing actions on the same curve
+    pub action_id: u64,
+    /// Discriminator of the queued instruction
+    pub kind: u8,
+    /// Account the queued instruction pays out to (all zeroes when it has none)
+    pub destination: Pubkey,
+    /// Instruction data of the queued instruction
+    pub data: &'info [u8],
+}
+
+impl<'info> TryFrom<&'info [u8]> for QueueActionInstructionData<'info> {
+    type Error = ProgramError;
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        if data.len() < 41 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let action_id = u64::from_le_bytes(
+            data[0..8]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+        Ok(Self {
+            action_id,
+            kind: data[8],
+            destination: data[9..41]
+                .try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+            data: &data[41..],
+        })
+    }
+}
+
+pub struct QueueAction<'info> {
+    pub accounts: QueueActionAccounts<'info>,
+    pub instruction_data: QueueActionInstructionData<'info>,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for QueueAction<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        let accounts = QueueActionAccounts::try_from(accounts)?;
+        let instruction_data = QueueActionInstructionData::try_from(data)?;
+        Ok(Self { accounts, instruction_data })
+    }
+}
+
+impl<'info> QueueAction<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
+        if !self.accounts.payer.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let config = ProtocolConfig::from_account(self.accounts.config)?;
+
+        // only timelocked instructions can be queued, and their data must parse
+        let kind = Instruction::try_from(self.instruction_data.kind)?;
+        let role = kind.timelock_role().ok_or(ProgramError::InvalidInstructionData)?;
+        let data = self.instruction_data.data;
+        match kind {
+            Instruction::WithdrawReserves => WithdrawReservesInstructionData::try_from(data).map(|_| ()),
+            Instruction::AdminMint => AdminMintInstructionData::try_from(data).map(|_| ()),
+            Instruction::UpdateCurveConfig => UpdateCurveConfigInstructionData::try_from(data).map(|_| ()),
+            Instruction::ProposeAuthority => ProposeAuthorityInstructionData::try_from(data).map(|_| ()),
+            Instruction::SetRole => SetRoleInstructionData::try_from(data).map(|_| ()),
+            Instruction::CreateVesting => CreateVestingInstructionData::try_from(data).map(|_| ()),
+            Instruction::RenounceAuthorities => RenounceAuthoritiesInstructionData::try_from(data).map(|_| ()),
+            Instruction::CloseCurve if data.is_empty() => Ok(()),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }?;
+
+        // value-moving actions must name their destination, the others none
+        let destination = self.instruction_data.destination;
+        if kind.timelock_destination_index().is_some() == (destination == [0u8; 32]) {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        {
+            let bonding_curve_data = self.accounts.bonding_curve.try_borrow_data()?;
+            let state = XToken::load(&bonding_curve_data)?;
+            if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+            if !state.has_role(role, self.accounts.authority.key()) { return Err(XTokenError::InvalidAuthority.into()); }
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        let execute_after = now
+            .checked_add(config.timelock_delay(now))
+            .ok_or(XTokenError::ArithmeticOverflow)?;
+
+        // derive pending action PDA
+        let action_id_bytes = self.instruction_data.action_id.to_le_bytes();
+        let (action_address, bump) = pinocchio::pubkey::find_program_address(
+            &[
+                PendingAction::SEED_PREFIX,
+                self.accounts.bonding_curve.key(),
+                &action_id_bytes,
+            ],
+            &crate::ID,
+        );
+        if action_address != *self.accounts.action.key() {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        if !self.accounts.action.data_is_empty() {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        // create pending action PDA account
+        let space = PendingAction::LEN;
+        let lamports = Rent::get()?.minimum_balance(space);
+
+        let bump_bytes = [bump];
+        let seeds = [
+            Seed::from(PendingAction::SEED_PREFIX),
+            Seed::from(self.accounts.bonding_curve.key()),
+            Seed::from(&action_id_bytes),
+            Seed::from(&bump_bytes),
+        ];
+        let signer = Signer::from(&seeds);
+
+        pinocchio_system::instructions::CreateAccount {
+            from: self.accounts.payer,
+            to: self.accounts.action,
+            space: space as u64,
+            lamports,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&[signer])?;
+
+        let mut action_data = self.accounts.action.try_borrow_mut_data()?;
+        let action = PendingAction::load_mut(&mut action_data)?;
+        action.initialize(
+            *self.accounts.bonding_curve.key(),
+            *self.accounts.payer.key(),
+            self.instruction_data.action_id,
+            self.instruction_data.kind,
+            destination,
+            data,
+        )?;
+        action.execute_after = execute_after;
+        action.bump = bump;
+
+        Ok(())
+    }
+}
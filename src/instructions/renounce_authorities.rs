@@ -0,0 +1,129 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+};
+use pinocchio_token::instructions::AuthorityType;
+
+use crate::{
+    error::XTokenError,
+    state::{check_authority_signed, AccountData, Role, XToken},
+    token_interface::{check_token_program, set_authority},
+};
+
+/// Accounts for RenounceAuthorities instruction
+pub struct RenounceAuthoritiesAccounts<'info> {
+    /// Authority (must hold the owner role)
+    pub authority: &'info AccountInfo,
+    /// Bonding curve state account (PDA, current mint and freeze authority)
+    pub bonding_curve: &'info AccountInfo,
+    /// Token mint account
+    pub mint: &'info AccountInfo,
+    /// Token program
+    pub token_program: &'info AccountInfo,
+    /// Protocol config account (PDA, checked for the emergency halt)
+    pub config: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig (remaining accounts)
+    pub signers: &'info [AccountInfo],
+}
+
+impl<'info> RenounceAuthoritiesAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 5 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            authority: &accounts[0],
+            bonding_curve: &accounts[1],
+            mint: &accounts[2],
+            token_program: &accounts[3],
+            config: &accounts[4],
+            signers: &accounts[5..],
+        })
+    }
+}
+
+/// Instruction data for RenounceAuthorities
+pub struct RenounceAuthoritiesInstructionData {
+    /// Authorities to renounce (1 = mint, 2 = freeze, 3 = both)
+    pub authorities: u8,
+}
+
+impl<'info> TryFrom<&'info [u8]> for RenounceAuthoritiesInstructionData {
+    type Error = ProgramError;
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        if data.len() != 1 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok(Self { authorities: data[0] })
+    }
+}
+
+/// Permanently give up the curve's mint and/or freeze authority over its mint
+pub struct RenounceAuthorities<'info> {
+    pub accounts: RenounceAuthoritiesAccounts<'info>,
+    pub instruction_data: RenounceAuthoritiesInstructionData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for RenounceAuthorities<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        let accounts = RenounceAuthoritiesAccounts::try_from(accounts)?;
+        let instruction_data = RenounceAuthoritiesInstructionData::try_from(data)?;
+        Ok(Self { accounts, instruction_data })
+    }
+}
+
+impl<'info> RenounceAuthorities<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
+
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let authorities = self.instruction_data.authorities;
+        let bump = {
+            let mut bonding_curve_data = self.accounts.bonding_curve.try_borrow_mut_data()?;
+            let state = XToken::load_mut(&mut bonding_curve_data)?;
+            if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+            if state.token_mint != *self.accounts.mint.key() { return Err(XTokenError::InvalidAccountData.into()); }
+            if !state.has_role(Role::Owner, self.accounts.authority.key()) { return Err(XTokenError::InvalidAuthority.into()); }
+            state.renounce_authorities(authorities)?;
+            state.bump
+        };
+
+        check_token_program(self.accounts.mint, self.accounts.token_program)?;
+
+        let bump_bytes = [bump];
+        let seeds = [
+            Seed::from(XToken::SEED_PREFIX),
+            Seed::from(self.accounts.mint.key().as_ref()),
+            Seed::from(&bump_bytes),
+        ];
+
+        if authorities & XToken::RENOUNCED_MINT != 0 {
+            set_authority(
+                self.accounts.token_program,
+                self.accounts.mint,
+                self.accounts.bonding_curve,
+                AuthorityType::MintTokens,
+                None,
+                &[Signer::from(&seeds)],
+            )?;
+        }
+
+        if authorities & XToken::RENOUNCED_FREEZE != 0 {
+            set_authority(
+                self.accounts.token_program,
+                self.accounts.mint,
+                self.accounts.bonding_curve,
+                AuthorityType::FreezeAccount,
+                None,
+                &[Signer::from(&seeds)],
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    error::XTokenError,
+    state::{check_authority_signed, AccountData, Role, XToken},
+};
+
+/// Accounts for ResumeCurve instruction
+pub struct ResumeCurveAccounts<'info> {
+    /// Authority (must hold the owner or operator role)
+    pub authority: &'info AccountInfo,
+    /// Bonding curve state account (PDA)
+    pub bonding_curve: &'info AccountInfo,
+    /// Protocol config account (PDA, checked for the emergency halt)
+    pub config: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig (remaining accounts)
+    pub signers: &'info [AccountInfo],
+}
+
+impl<'info> ResumeCurveAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 3 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            authority: &accounts[0],
+            bonding_curve: &accounts[1],
+            config: &accounts[2],
+            signers: &accounts[3..],
+        })
+    }
+}
+
+/// Resume trading and minting on a paused curve
+pub struct ResumeCurve<'info> {
+    pub accounts: ResumeCurveAccounts<'info>,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for ResumeCurve<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let accounts = ResumeCurveAccounts::try_from(accounts)?;
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> ResumeCurve<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
+
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let mut bonding_curve_data = self.accounts.bonding_curve.try_borrow_mut_data()?;
+        let state = XToken::load_mut(&mut bonding_curve_data)?;
+        if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+        let key = self.accounts.authority.key();
+        if !state.has_role(Role::Owner, key) && !state.has_role(Role::Operator, key) {
+            return Err(XTokenError::InvalidAuthority.into());
+        }
+
+        state.paused = 0;
+        Ok(())
+    }
+}
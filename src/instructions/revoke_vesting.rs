@@ -0,0 +1,126 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+};
+
+use crate::{
+    error::XTokenError,
+    state::{check_authority_signed, AccountData, Role, Vesting, XToken},
+    token_interface::{burn, check_token_program},
+};
+
+/// Accounts for RevokeVesting instruction
+pub struct RevokeVestingAccounts<'info> {
+    /// Authority (must hold the owner role)
+    pub authority: &'info AccountInfo,
+    /// Bonding curve state account (PDA)
+    pub bonding_curve: &'info AccountInfo,
+    /// Token mint account
+    pub mint: &'info AccountInfo,
+    /// Vesting account (PDA)
+    pub vesting: &'info AccountInfo,
+    /// Escrow token account (ATA owned by the vesting PDA)
+    pub escrow: &'info AccountInfo,
+    /// Token program owning the mint (classic or Token-2022)
+    pub token_program: &'info AccountInfo,
+    /// Protocol config account (PDA, checked for the emergency halt)
+    pub config: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig (remaining accounts)
+    pub signers: &'info [AccountInfo],
+}
+
+impl<'info> RevokeVestingAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 7 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            authority: &accounts[0],
+            bonding_curve: &accounts[1],
+            mint: &accounts[2],
+            vesting: &accounts[3],
+            escrow: &accounts[4],
+            token_program: &accounts[5],
+            config: &accounts[6],
+            signers: &accounts[7..],
+        })
+    }
+}
+
+/// Stop a revocable vesting: vested tokens stay claimable, unvested tokens
+/// are burned and returned to the creator allocation
+pub struct RevokeVesting<'info> {
+    pub accounts: RevokeVestingAccounts<'info>,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for RevokeVesting<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let accounts = RevokeVestingAccounts::try_from(accounts)?;
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> RevokeVesting<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
+
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID
+            || unsafe { *self.accounts.vesting.owner() } != crate::ID
+        {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+
+        let (unvested, beneficiary, bump) = {
+            let mut vesting_data = self.accounts.vesting.try_borrow_mut_data()?;
+            let vesting = Vesting::load_mut(&mut vesting_data)?;
+            if vesting.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+            if vesting.bonding_curve != *self.accounts.bonding_curve.key() { return Err(XTokenError::InvalidAccountData.into()); }
+            if vesting.escrow != *self.accounts.escrow.key() { return Err(XTokenError::InvalidAccountData.into()); }
+            (vesting.revoke(now)?, vesting.beneficiary, vesting.bump)
+        };
+
+        {
+            let mut bonding_curve_data = self.accounts.bonding_curve.try_borrow_mut_data()?;
+            let state = XToken::load_mut(&mut bonding_curve_data)?;
+            if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+            if state.token_mint != *self.accounts.mint.key() { return Err(XTokenError::InvalidAccountData.into()); }
+            if !state.has_role(Role::Owner, self.accounts.authority.key()) { return Err(XTokenError::InvalidAuthority.into()); }
+            state.release_creator_mint(unvested)?;
+        }
+
+        if unvested == 0 {
+            return Ok(());
+        }
+
+        check_token_program(self.accounts.mint, self.accounts.token_program)?;
+
+        // burn the unvested tokens out of escrow using the vesting PDA as owner
+        let bump_bytes = [bump];
+        let seeds = [
+            Seed::from(Vesting::SEED_PREFIX),
+            Seed::from(self.accounts.bonding_curve.key()),
+            Seed::from(&beneficiary),
+            Seed::from(&bump_bytes),
+        ];
+        let signer = Signer::from(&seeds);
+
+        burn(
+            self.accounts.token_program,
+            self.accounts.mint,
+            self.accounts.escrow,
+            self.accounts.vesting,
+            unvested,
+            &[signer],
+        )?;
+
+        Ok(())
+    }
+}
@@ -1,9 +1,14 @@
 use bytemuck::{Pod, Zeroable};
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+};
 
 use crate::{
     error::XTokenError,
-    state::{AccountData, XToken},
+    state::{AccountData, ProtocolConfig, XToken},
+    token_interface::{burn, check_token_program},
 };
 
 /// Accounts for SellTokens instruction
@@ -21,15 +26,17 @@ pub struct SellTokensAccounts<'info> {
     /// Fee recipient account
     pub fee_recipient: &'info AccountInfo,
     // Removed trading stats account
-    /// Token program
+    /// Token program owning the mint (classic or Token-2022)
     pub token_program: &'info AccountInfo,
     /// System program
     pub system_program: &'info AccountInfo,
+    /// Protocol config account (PDA)
+    pub config: &'info AccountInfo,
 }
 
 impl<'info> SellTokensAccounts<'info> {
     pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
-        if accounts.len() < 8 {
+        if accounts.len() < 9 {
             return Err(ProgramError::NotEnoughAccountKeys);
         }
 
@@ -42,6 +49,7 @@ impl<'info> SellTokensAccounts<'info> {
             fee_recipient: &accounts[5],
             token_program: &accounts[6],
             system_program: &accounts[7],
+            config: &accounts[8],
         })
     }
 }
@@ -120,6 +128,11 @@ impl<'info> SellTokens<'info> {
             return Err(XTokenError::InvalidTokenAmount.into());
         }
 
+        let config = ProtocolConfig::from_account(self.accounts.config)?;
+        if config.paused != 0 {
+            return Err(XTokenError::ProtocolPaused.into());
+        }
+
         // -------- Phase 1: Read bonding curve snapshot (immutable borrow) --------
         let (bump, _token_mint_key, _total_supply_snapshot, total_proceeds, fee, net_proceeds) = {
             let bonding_curve_data = self.accounts.bonding_curve.try_borrow_data()?;
@@ -129,11 +142,22 @@ impl<'info> SellTokens<'info> {
                 return Err(XTokenError::AccountNotInitialized.into());
             }
 
+            if bonding_curve.paused != 0 {
+                return Err(XTokenError::CurvePaused.into());
+            }
+
+            // selling stays open after graduation: the reserve still backs every token
+
             // Verify mint matches
             if bonding_curve.token_mint != *self.accounts.mint.key() {
                 return Err(XTokenError::InvalidAccountData.into());
             }
 
+            // Fees must go to the curve's current fee recipient
+            if bonding_curve.fee_recipient != *self.accounts.fee_recipient.key() {
+                return Err(XTokenError::InvalidAccountData.into());
+            }
+
             // Calculate price and fee using immutable snapshot
             let total_proceeds =
                 bonding_curve.calculate_sell_price(self.instruction_data.token_amount)?;
@@ -159,8 +183,10 @@ impl<'info> SellTokens<'info> {
             return Err(XTokenError::SlippageExceeded.into());
         }
 
-        // Check treasury has enough SOL
-        if self.accounts.treasury.lamports() < total_proceeds {
+        // Check treasury has enough SOL above its rent-exempt minimum, so a
+        // sell can never leave it open to being garbage-collected
+        let rent_floor = Rent::get()?.minimum_balance(self.accounts.treasury.data_len());
+        if self.accounts.treasury.lamports().saturating_sub(rent_floor) < total_proceeds {
             return Err(XTokenError::InsufficientFunds.into());
         }
 
@@ -173,16 +199,19 @@ impl<'info> SellTokens<'info> {
         ];
         let _bonding_curve_signer = pinocchio::instruction::Signer::from(&bc_seeds);
 
+        check_token_program(self.accounts.mint, self.accounts.token_program)?;
+
         // -------- Phase 2: CPI calls (no bonding_curve borrow held) --------
         // Removed trading stats account creation (handled off-chain)
         // Burn tokens from seller
-        pinocchio_token::instructions::Burn {
-            mint: self.accounts.mint,
-            account: self.accounts.seller_token_account,
-            authority: self.accounts.seller,
-            amount: self.instruction_data.token_amount,
-        }
-        .invoke()?;
+        burn(
+            self.accounts.token_program,
+            self.accounts.mint,
+            self.accounts.seller_token_account,
+            self.accounts.seller,
+            self.instruction_data.token_amount,
+            &[],
+        )?;
 
         // Transfer SOL from treasury to seller/fee
         // Support both treasury owner patterns:
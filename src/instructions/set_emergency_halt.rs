@@ -0,0 +1,80 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    error::XTokenError,
+    state::{AccountData, ProtocolConfig},
+};
+
+/// Accounts for SetEmergencyHalt instruction
+pub struct SetEmergencyHaltAccounts<'info> {
+    /// Protocol guardian (must match config guardian)
+    pub guardian: &'info AccountInfo,
+    /// Protocol config account (PDA)
+    pub config: &'info AccountInfo,
+}
+
+impl<'info> SetEmergencyHaltAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 2 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            guardian: &accounts[0],
+            config: &accounts[1],
+        })
+    }
+}
+
+/// Instruction data for SetEmergencyHalt
+pub struct SetEmergencyHaltInstructionData {
+    /// Whether the protocol should be halted (0 = false, 1 = true)
+    pub halted: u8,
+}
+
+impl SetEmergencyHaltInstructionData {
+    pub const LEN: usize = 1;
+}
+
+impl<'info> TryFrom<&'info [u8]> for SetEmergencyHaltInstructionData {
+    type Error = ProgramError;
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        if data.len() != Self::LEN || data[0] > 1 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok(Self { halted: data[0] })
+    }
+}
+
+pub struct SetEmergencyHalt<'info> {
+    pub accounts: SetEmergencyHaltAccounts<'info>,
+    pub instruction_data: SetEmergencyHaltInstructionData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for SetEmergencyHalt<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        let accounts = SetEmergencyHaltAccounts::try_from(accounts)?;
+        let instruction_data = SetEmergencyHaltInstructionData::try_from(data)?;
+        Ok(Self { accounts, instruction_data })
+    }
+}
+
+impl<'info> SetEmergencyHalt<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        if !self.accounts.guardian.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // validates owner, PDA and initialization
+        let current = ProtocolConfig::from_account(self.accounts.config)?;
+        if current.guardian != *self.accounts.guardian.key() {
+            return Err(XTokenError::InvalidAuthority.into());
+        }
+
+        let mut config_data = self.accounts.config.try_borrow_mut_data()?;
+        let config = ProtocolConfig::load_mut(&mut config_data)?;
+        config.emergency_halt = self.instruction_data.halted;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::XTokenError,
+    state::{AccountData, ProtocolConfig},
+};
+
+/// Accounts for SetGuardian instruction
+pub struct SetGuardianAccounts<'info> {
+    /// Protocol admin (must match config admin)
+    pub admin: &'info AccountInfo,
+    /// Protocol config account (PDA)
+    pub config: &'info AccountInfo,
+}
+
+impl<'info> SetGuardianAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 2 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            admin: &accounts[0],
+            config: &accounts[1],
+        })
+    }
+}
+
+/// Instruction data for SetGuardian
+pub struct SetGuardianInstructionData {
+    /// New guardian allowed to toggle the emergency halt
+    pub guardian: Pubkey,
+}
+
+impl SetGuardianInstructionData {
+    pub const LEN: usize = 32;
+}
+
+impl<'info> TryFrom<&'info [u8]> for SetGuardianInstructionData {
+    type Error = ProgramError;
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let guardian: Pubkey = data[0..32]
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        Ok(Self { guardian })
+    }
+}
+
+pub struct SetGuardian<'info> {
+    pub accounts: SetGuardianAccounts<'info>,
+    pub instruction_data: SetGuardianInstructionData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for SetGuardian<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        let accounts = SetGuardianAccounts::try_from(accounts)?;
+        let instruction_data = SetGuardianInstructionData::try_from(data)?;
+        Ok(Self { accounts, instruction_data })
+    }
+}
+
+impl<'info> SetGuardian<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        if !self.accounts.admin.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if self.instruction_data.guardian == Pubkey::default() {
+            return Err(XTokenError::InvalidConfig.into());
+        }
+
+        // validates owner, PDA and initialization
+        let current = ProtocolConfig::from_account(self.accounts.config)?;
+        if current.admin != *self.accounts.admin.key() {
+            return Err(XTokenError::InvalidAuthority.into());
+        }
+
+        let mut config_data = self.accounts.config.try_borrow_mut_data()?;
+        let config = ProtocolConfig::load_mut(&mut config_data)?;
+        config.guardian = self.instruction_data.guardian;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+};
+
+use crate::{
+    error::XTokenError,
+    state::{AccountData, ProtocolConfig},
+};
+
+/// Accounts for UpdateConfig instruction
+pub struct UpdateConfigAccounts<'info> {
+    /// Protocol admin (must match config admin)
+    pub admin: &'info AccountInfo,
+    /// Protocol config account (PDA)
+    pub config: &'info AccountInfo,
+}
+
+impl<'info> UpdateConfigAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 2 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            admin: &accounts[0],
+            config: &accounts[1],
+        })
+    }
+}
+
+/// Instruction data for UpdateConfig
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct UpdateConfigInstructionData {
+    /// New protocol admin (pass the current admin to keep it; all zeroes is rejected)
+    pub new_admin: Pubkey,
+    /// Default fee recipient
+    pub fee_recipient: Pubkey,
+    /// Maximum SOL (lamports) a single curve treasury may hold
+    pub sol_cap_lamports: u64,
+    /// Lowest fee a curve may charge, in basis points
+    pub min_fee_basis_points: u16,
+    /// Highest fee a curve may charge, in basis points
+    pub max_fee_basis_points: u16,
+    /// Whether Initialize creates Metaplex metadata (0 = false, 1 = true)
+    pub use_metaplex: u8,
+    /// Whether launches and trading are paused (0 = false, 1 = true)
+    pub paused: u8,
+    /// Encoding version of the fields below; the original (v1) data ends
+    /// before it and leaves the settings below unchanged
+    pub version: u8,
+    /// Delay (seconds) before queued curve actions may execute; 0 disables the
+    /// timelock. A lower delay than the current one applies only after the
+    /// current delay has passed
+    pub timelock_delay_seconds: i64,
+    /// Fee (lamports) charged to the payer of every launch; 0 disables it
+    pub creation_fee_lamports: u64,
+    /// Share of the creation fee rebated to the creator on graduation, in basis points
+    pub creation_fee_rebate_basis_points: u16,
+}
+
+impl UpdateConfigInstructionData {
+    pub const LEN: usize = core::mem::size_of::<UpdateConfigInstructionData>();
+
+    /// Length of the original (v1) data: every field before `version`
+    pub const V1_LEN: usize = core::mem::offset_of!(UpdateConfigInstructionData, version);
+
+    /// Encoding version expected in full-length data
+    pub const CURRENT_VERSION: u8 = 2;
+}
+
+impl<'info> TryFrom<&'info [u8]> for UpdateConfigInstructionData {
+    type Error = ProgramError;
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        if data.len() != Self::LEN && data.len() != Self::V1_LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let mut instruction_data = Self::zeroed();
+        bytemuck::bytes_of_mut(&mut instruction_data)[..data.len()].copy_from_slice(data);
+        if data.len() == Self::V1_LEN {
+            instruction_data.version = 1;
+        } else if instruction_data.version != Self::CURRENT_VERSION {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok(instruction_data)
+    }
+}
+
+pub struct UpdateConfig<'info> {
+    pub accounts: UpdateConfigAccounts<'info>,
+    pub instruction_data: UpdateConfigInstructionData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for UpdateConfig<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        let accounts = UpdateConfigAccounts::try_from(accounts)?;
+        let instruction_data = UpdateConfigInstructionData::try_from(data)?;
+        Ok(Self { accounts, instruction_data })
+    }
+}
+
+impl<'info> UpdateConfig<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        if !self.accounts.admin.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // validates owner, PDA and initialization
+        let current = ProtocolConfig::from_account(self.accounts.config)?;
+        if current.admin != *self.accounts.admin.key() {
+            return Err(XTokenError::InvalidAuthority.into());
+        }
+        // an all-zero admin could never sign again
+        if self.instruction_data.new_admin == [0u8; 32] {
+            return Err(XTokenError::InvalidAuthority.into());
+        }
+
+        let mut config_data = self.accounts.config.try_borrow_mut_data()?;
+        let config = ProtocolConfig::load_mut(&mut config_data)?;
+        config.update(
+            self.instruction_data.fee_recipient,
+            self.instruction_data.sol_cap_lamports,
+            self.instruction_data.min_fee_basis_points,
+            self.instruction_data.max_fee_basis_points,
+            self.instruction_data.use_metaplex != 0,
+            self.instruction_data.paused != 0,
+        )?;
+        if self.instruction_data.version >= 2 {
+            // a lower delay only applies once the current one has passed
+            config.set_timelock_delay(
+                self.instruction_data.timelock_delay_seconds,
+                Clock::get()?.unix_timestamp,
+            )?;
+            config.set_creation_fee(
+                self.instruction_data.creation_fee_lamports,
+                self.instruction_data.creation_fee_rebate_basis_points,
+            )?;
+        }
+        config.admin = self.instruction_data.new_admin;
+
+        Ok(())
+    }
+}
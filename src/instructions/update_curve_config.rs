@@ -0,0 +1,118 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::XTokenError,
+    state::{check_authority_signed, AccountData, ProtocolConfig, Role, XToken},
+};
+
+/// Accounts for UpdateCurveConfig instruction
+pub struct UpdateCurveConfigAccounts<'info> {
+    /// Authority (must hold the fee manager role; pricing changes need the owner)
+    pub authority: &'info AccountInfo,
+    /// Bonding curve state account (PDA)
+    pub bonding_curve: &'info AccountInfo,
+    /// Protocol config account (PDA)
+    pub config: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig (remaining accounts)
+    pub signers: &'info [AccountInfo],
+}
+
+impl<'info> UpdateCurveConfigAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 3 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            authority: &accounts[0],
+            bonding_curve: &accounts[1],
+            config: &accounts[2],
+            signers: &accounts[3..],
+        })
+    }
+}
+
+/// Instruction data for UpdateCurveConfig
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct UpdateCurveConfigInstructionData {
+    /// Fees in basis points (100 = 1%)
+    pub fee_basis_points: u16,
+    /// Curve type (0 = linear, 3 = cpmm)
+    pub curve_type: u8,
+    /// Fee recipient (all zeroes is rejected)
+    pub fee_recipient: Pubkey,
+    /// Base price in lamports per token (scaled by 1e9)
+    pub base_price: u64,
+    /// Slope parameter for pricing curve (scaled by 1e9)
+    pub slope: u64,
+    /// Maximum token supply
+    pub max_supply: u64,
+}
+
+impl UpdateCurveConfigInstructionData {
+    pub const LEN: usize = core::mem::size_of::<UpdateCurveConfigInstructionData>();
+}
+
+impl<'info> TryFrom<&'info [u8]> for UpdateCurveConfigInstructionData {
+    type Error = ProgramError;
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        bytemuck::try_from_bytes::<Self>(data)
+            .copied()
+            .map_err(|_| ProgramError::InvalidInstructionData)
+    }
+}
+
+pub struct UpdateCurveConfig<'info> {
+    pub accounts: UpdateCurveConfigAccounts<'info>,
+    pub instruction_data: UpdateCurveConfigInstructionData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for UpdateCurveConfig<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        let accounts = UpdateCurveConfigAccounts::try_from(accounts)?;
+        let instruction_data = UpdateCurveConfigInstructionData::try_from(data)?;
+        Ok(Self { accounts, instruction_data })
+    }
+}
+
+impl<'info> UpdateCurveConfig<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
+
+        let config = ProtocolConfig::from_account(self.accounts.config)?;
+        if !config.fee_in_bounds(self.instruction_data.fee_basis_points) {
+            return Err(XTokenError::InvalidCurveParameters.into());
+        }
+
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let mut bonding_curve_data = self.accounts.bonding_curve.try_borrow_mut_data()?;
+        let state = XToken::load_mut(&mut bonding_curve_data)?;
+        if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+        if !state.has_role(Role::FeeManager, self.accounts.authority.key()) { return Err(XTokenError::InvalidAuthority.into()); }
+
+        let pricing_changed = self.instruction_data.curve_type != state.curve_type
+            || self.instruction_data.base_price != state.base_price
+            || self.instruction_data.slope != state.slope
+            || self.instruction_data.max_supply != state.max_supply;
+        if pricing_changed && !state.has_role(Role::Owner, self.accounts.authority.key()) {
+            return Err(XTokenError::InvalidAuthority.into());
+        }
+
+        state.update_curve_config(
+            self.instruction_data.fee_basis_points,
+            self.instruction_data.fee_recipient,
+            self.instruction_data.curve_type,
+            self.instruction_data.base_price,
+            self.instruction_data.slope,
+            self.instruction_data.max_supply,
+        )
+    }
+}
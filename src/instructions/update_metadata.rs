@@ -0,0 +1,281 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    error::XTokenError,
+    instructions::initialize::{
+        serialize_metadata_strings_fixed, write_fixed, InitializeInstructionData, METADATA_PREFIX,
+        METAPLEX_TOKEN_METADATA_ID,
+    },
+    state::{check_authority_signed, AccountData, Role, XToken},
+};
+
+// Metaplex instruction discriminator
+pub const UPDATE_METADATA_ACCOUNT_V2: u8 = 15;
+
+/// Accounts for UpdateMetadata instruction
+pub struct UpdateMetadataAccounts<'info> {
+    /// Authority (must hold the owner role)
+    pub authority: &'info AccountInfo,
+    /// Bonding curve state account (PDA)
+    pub bonding_curve: &'info AccountInfo,
+    /// Token mint account
+    pub mint: &'info AccountInfo,
+    /// Metadata account (PDA from Metaplex)
+    pub metadata_account: &'info AccountInfo,
+    /// Current metadata update authority: the authority wallet (signer) or
+    /// the bonding curve PDA (signed by the program)
+    pub update_authority: &'info AccountInfo,
+    /// Metaplex Token Metadata Program
+    pub metaplex_program: &'info AccountInfo,
+    /// Protocol config account (PDA, checked for the emergency halt)
+    pub config: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig (remaining accounts)
+    pub signers: &'info [AccountInfo],
+}
+
+impl<'info> UpdateMetadataAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 7 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            authority: &accounts[0],
+            bonding_curve: &accounts[1],
+            mint: &accounts[2],
+            metadata_account: &accounts[3],
+            update_authority: &accounts[4],
+            metaplex_program: &accounts[5],
+            config: &accounts[6],
+            signers: &accounts[7..],
+        })
+    }
+}
+
+/// Instruction data for UpdateMetadata
+#[repr(C, packed)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct UpdateMetadataInstructionData {
+    /// Replace name, symbol and URI (0 = keep, 1 = replace)
+    pub update_data: u8,
+    /// Hand metadata update authority to the bonding curve PDA (0 = false, 1 = true)
+    pub delegate_to_curve: u8,
+    /// Make the metadata immutable; this cannot be undone (0 = false, 1 = true)
+    pub lock: u8,
+    /// Token name (max 32 bytes) - includes length in first byte
+    pub token_name: [u8; 32],
+    /// Token symbol (max 10 bytes) - includes length in first byte
+    pub token_symbol: [u8; 10],
+    /// Token metadata URI (max 200 bytes) - includes length in first byte
+    pub token_uri: [u8; 200],
+}
+
+impl UpdateMetadataInstructionData {
+    pub const LEN: usize = core::mem::size_of::<UpdateMetadataInstructionData>();
+}
+
+impl<'info> TryFrom<&'info [u8]> for UpdateMetadataInstructionData {
+    type Error = ProgramError;
+    fn try_from(data: &'info [u8]) -> Result<Self, Self::Error> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let instruction_data = bytemuck::try_from_bytes::<Self>(data)
+            .copied()
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        if instruction_data.update_data > 1
+            || instruction_data.delegate_to_curve > 1
+            || instruction_data.lock > 1
+            || instruction_data.update_data | instruction_data.delegate_to_curve | instruction_data.lock == 0
+        {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok(instruction_data)
+    }
+}
+
+/// Byte ranges of an existing metadata account that UpdateMetadata carries
+/// over unchanged: royalty plus creators, and collection plus uses
+fn preserved_metadata_fields(
+    metadata: &[u8],
+) -> Result<(core::ops::Range<usize>, core::ops::Range<usize>), ProgramError> {
+    let byte = |o: usize| metadata.get(o).copied().ok_or(ProgramError::InvalidAccountData);
+    let len = |o: usize| -> Result<usize, ProgramError> {
+        let bytes = metadata.get(o..o + 4).ok_or(ProgramError::InvalidAccountData)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+    };
+
+    // key, update authority, mint, then name, symbol and uri
+    let mut o = 1 + 32 + 32;
+    for _ in 0..3 {
+        o += 4 + len(o)?;
+    }
+
+    let royalty_start = o;
+    o += 2; // seller_fee_basis_points
+    if byte(o)? == 1 {
+        o += 1 + 4 + len(o + 1)? * 34; // creators: address, verified, share
+    } else {
+        o += 1;
+    }
+    let royalty_end = o;
+
+    o += 2; // primary_sale_happened, is_mutable
+    o += if byte(o)? == 1 { 2 } else { 1 }; // edition_nonce
+    o += if byte(o)? == 1 { 2 } else { 1 }; // token_standard
+
+    let collection_start = o;
+    o += if byte(o)? == 1 { 1 + 1 + 32 } else { 1 }; // collection: verified, key
+    o += if byte(o)? == 1 { 1 + 1 + 8 + 8 } else { 1 }; // uses: method, remaining, total
+    if o > metadata.len() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok((royalty_start..royalty_end, collection_start..o))
+}
+
+/// Change the name, symbol or URI of a launched token's Metaplex metadata,
+/// move its update authority to the bonding curve PDA, or lock it for good.
+/// Token-2022 launches are rejected: their in-mint metadata names the
+/// authority as update authority, so it updates the fields itself through
+/// the token program's metadata instructions.
+pub struct UpdateMetadata<'info> {
+    pub accounts: UpdateMetadataAccounts<'info>,
+    pub instruction_data: UpdateMetadataInstructionData,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for UpdateMetadata<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        let accounts = UpdateMetadataAccounts::try_from(accounts)?;
+        let instruction_data = UpdateMetadataInstructionData::try_from(data)?;
+        Ok(Self { accounts, instruction_data })
+    }
+}
+
+impl<'info> UpdateMetadata<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
+
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let (bump, metadata_mode) = {
+            let bonding_curve_data = self.accounts.bonding_curve.try_borrow_data()?;
+            let state = XToken::load(&bonding_curve_data)?;
+            if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+            if state.token_mint != *self.accounts.mint.key() { return Err(XTokenError::InvalidAccountData.into()); }
+            if !state.has_role(Role::Owner, self.accounts.authority.key()) { return Err(XTokenError::InvalidAuthority.into()); }
+            (state.bump, state.metadata_mode)
+        };
+
+        // the update authority is either the curve PDA or the authority itself
+        let curve_is_update_authority =
+            self.accounts.update_authority.key() == self.accounts.bonding_curve.key();
+        if !curve_is_update_authority
+            && (self.accounts.update_authority.key() != self.accounts.authority.key()
+                || !self.accounts.update_authority.is_signer())
+        {
+            return Err(XTokenError::InvalidAuthority.into());
+        }
+
+        // Token-2022 in-mint metadata keeps the authority as its update
+        // authority, which changes it directly through the token program
+        if metadata_mode == XToken::METADATA_TOKEN_2022 {
+            return Err(XTokenError::MetaplexMetadataRequired.into());
+        }
+        if metadata_mode != XToken::METADATA_METAPLEX {
+            return Err(XTokenError::NoMetaplexMetadata.into());
+        }
+
+        // Derive metadata PDA and verify
+        let metaplex_program_id = Pubkey::from(METAPLEX_TOKEN_METADATA_ID);
+        let (metadata_address, _metadata_bump) = pinocchio::pubkey::find_program_address(
+            &[
+                METADATA_PREFIX,
+                metaplex_program_id.as_ref(),
+                self.accounts.mint.key().as_ref(),
+            ],
+            &metaplex_program_id,
+        );
+        if metadata_address != *self.accounts.metadata_account.key() {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        if *self.accounts.metaplex_program.key() != metaplex_program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        // Build UpdateMetadataAccountV2 with a fixed-size buffer
+        let mut ix_buf = [0u8; 600];
+        let mut offset = 0usize;
+        write_fixed(&mut ix_buf, &mut offset, &[UPDATE_METADATA_ACCOUNT_V2])?;
+
+        if self.instruction_data.update_data != 0 {
+            let token_name = InitializeInstructionData::extract_str(&self.instruction_data.token_name)?;
+            let token_symbol = InitializeInstructionData::extract_str(&self.instruction_data.token_symbol)?;
+            let token_uri = InitializeInstructionData::extract_str(&self.instruction_data.token_uri)?;
+
+            // only the strings change; royalty, creators, collection and uses are kept
+            let metadata = self.accounts.metadata_account.try_borrow_data()?;
+            let (royalty, collection) = preserved_metadata_fields(&metadata)?;
+            write_fixed(&mut ix_buf, &mut offset, &[1])?; // data Some
+            serialize_metadata_strings_fixed(&mut ix_buf, &mut offset, token_name, token_symbol, token_uri)?;
+            write_fixed(&mut ix_buf, &mut offset, &metadata[royalty])?;
+            write_fixed(&mut ix_buf, &mut offset, &metadata[collection])?;
+        } else {
+            write_fixed(&mut ix_buf, &mut offset, &[0])?; // data None
+        }
+
+        if self.instruction_data.delegate_to_curve != 0 {
+            write_fixed(&mut ix_buf, &mut offset, &[1])?;
+            write_fixed(&mut ix_buf, &mut offset, self.accounts.bonding_curve.key())?;
+        } else {
+            write_fixed(&mut ix_buf, &mut offset, &[0])?; // new_update_authority None
+        }
+
+        write_fixed(&mut ix_buf, &mut offset, &[0])?; // primary_sale_happened None
+        if self.instruction_data.lock != 0 {
+            write_fixed(&mut ix_buf, &mut offset, &[1, 0])?; // is_mutable Some(false)
+        } else {
+            write_fixed(&mut ix_buf, &mut offset, &[0])?; // is_mutable None
+        }
+
+        let metadata_instruction = pinocchio::instruction::Instruction {
+            program_id: &metaplex_program_id,
+            accounts: &[
+                AccountMeta {
+                    pubkey: self.accounts.metadata_account.key(),
+                    is_signer: false,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: self.accounts.update_authority.key(),
+                    is_signer: true,
+                    is_writable: false,
+                },
+            ],
+            data: &ix_buf[..offset],
+        };
+
+        let bump_bytes = [bump];
+        let signer_seeds = [
+            Seed::from(XToken::SEED_PREFIX),
+            Seed::from(self.accounts.mint.key().as_ref()),
+            Seed::from(&bump_bytes),
+        ];
+        let curve_signer = [Signer::from(&signer_seeds)];
+        let signers: &[Signer] = if curve_is_update_authority { &curve_signer } else { &[] };
+
+        pinocchio::program::invoke_signed(
+            &metadata_instruction,
+            &[self.accounts.metadata_account, self.accounts.update_authority],
+            signers,
+        )
+    }
+}
@@ -0,0 +1,179 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    error::XTokenError,
+    instructions::initialize::{METADATA_PREFIX, METAPLEX_TOKEN_METADATA_ID},
+    state::{AccountData, XToken},
+};
+
+// Seed of the program PDA that holds update authority over platform collections
+pub const COLLECTION_AUTHORITY_SEED: &[u8] = b"collection_authority";
+
+// Metaplex instruction discriminator
+pub const VERIFY_SIZED_COLLECTION_ITEM: u8 = 30;
+
+/// Accounts for VerifyCollection instruction
+pub struct VerifyCollectionAccounts<'info> {
+    /// Payer for the Metaplex CPI
+    pub payer: &'info AccountInfo,
+    /// Bonding curve state account (PDA), proving the token was launched here
+    pub bonding_curve: &'info AccountInfo,
+    /// Token mint account
+    pub mint: &'info AccountInfo,
+    /// Metadata account of the token (PDA from Metaplex)
+    pub metadata_account: &'info AccountInfo,
+    /// Program PDA holding update authority over the collection
+    pub collection_authority: &'info AccountInfo,
+    /// Collection NFT mint
+    pub collection_mint: &'info AccountInfo,
+    /// Collection NFT metadata account
+    pub collection_metadata: &'info AccountInfo,
+    /// Collection NFT master edition account
+    pub collection_master_edition: &'info AccountInfo,
+    /// Metaplex Token Metadata Program
+    pub metaplex_program: &'info AccountInfo,
+    /// Protocol config account (PDA, checked for the emergency halt)
+    pub config: &'info AccountInfo,
+}
+
+impl<'info> VerifyCollectionAccounts<'info> {
+    pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
+        if accounts.len() < 10 {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        Ok(Self {
+            payer: &accounts[0],
+            bonding_curve: &accounts[1],
+            mint: &accounts[2],
+            metadata_account: &accounts[3],
+            collection_authority: &accounts[4],
+            collection_mint: &accounts[5],
+            collection_metadata: &accounts[6],
+            collection_master_edition: &accounts[7],
+            metaplex_program: &accounts[8],
+            config: &accounts[9],
+        })
+    }
+}
+
+/// Verify a launched token as an item of the (sized) platform collection
+/// named in its metadata. Permissionless: only tokens launched by this
+/// program qualify, and only collections whose update authority is the
+/// program's collection authority PDA can be verified.
+pub struct VerifyCollection<'info> {
+    pub accounts: VerifyCollectionAccounts<'info>,
+}
+
+impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for VerifyCollection<'info> {
+    type Error = ProgramError;
+    fn try_from((accounts, data): (&'info [AccountInfo], &'info [u8])) -> Result<Self, Self::Error> {
+        if !data.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let accounts = VerifyCollectionAccounts::try_from(accounts)?;
+        Ok(Self { accounts })
+    }
+}
+
+impl<'info> VerifyCollection<'info> {
+    pub fn handler(&mut self) -> Result<(), ProgramError> {
+        if !self.accounts.payer.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        {
+            let bonding_curve_data = self.accounts.bonding_curve.try_borrow_data()?;
+            let state = XToken::load(&bonding_curve_data)?;
+            if state.is_initialized == 0 { return Err(XTokenError::AccountNotInitialized.into()); }
+            if state.token_mint != *self.accounts.mint.key() { return Err(XTokenError::InvalidAccountData.into()); }
+            if state.metadata_mode != XToken::METADATA_METAPLEX { return Err(XTokenError::NoMetaplexMetadata.into()); }
+        }
+
+        // Derive metadata PDA and verify
+        let metaplex_program_id = Pubkey::from(METAPLEX_TOKEN_METADATA_ID);
+        let (metadata_address, _metadata_bump) = pinocchio::pubkey::find_program_address(
+            &[
+                METADATA_PREFIX,
+                metaplex_program_id.as_ref(),
+                self.accounts.mint.key().as_ref(),
+            ],
+            &metaplex_program_id,
+        );
+        if metadata_address != *self.accounts.metadata_account.key() {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        if *self.accounts.metaplex_program.key() != metaplex_program_id {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        let (collection_authority, collection_authority_bump) =
+            pinocchio::pubkey::find_program_address(&[COLLECTION_AUTHORITY_SEED], &crate::ID);
+        if collection_authority != *self.accounts.collection_authority.key() {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        // Metaplex checks the collection mint, metadata and master edition,
+        // and that the collection authority is the collection's update authority
+        let verify_instruction = pinocchio::instruction::Instruction {
+            program_id: &metaplex_program_id,
+            accounts: &[
+                AccountMeta {
+                    pubkey: self.accounts.metadata_account.key(),
+                    is_signer: false,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: self.accounts.collection_authority.key(),
+                    is_signer: true,
+                    is_writable: false,
+                },
+                AccountMeta {
+                    pubkey: self.accounts.payer.key(),
+                    is_signer: true,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: self.accounts.collection_mint.key(),
+                    is_signer: false,
+                    is_writable: false,
+                },
+                AccountMeta {
+                    pubkey: self.accounts.collection_metadata.key(),
+                    is_signer: false,
+                    is_writable: true,
+                },
+                AccountMeta {
+                    pubkey: self.accounts.collection_master_edition.key(),
+                    is_signer: false,
+                    is_writable: false,
+                },
+            ],
+            data: &[VERIFY_SIZED_COLLECTION_ITEM],
+        };
+
+        let bump_bytes = [collection_authority_bump];
+        let signer_seeds = [Seed::from(COLLECTION_AUTHORITY_SEED), Seed::from(&bump_bytes)];
+
+        pinocchio::program::invoke_signed(
+            &verify_instruction,
+            &[
+                self.accounts.metadata_account,
+                self.accounts.collection_authority,
+                self.accounts.payer,
+                self.accounts.collection_mint,
+                self.accounts.collection_metadata,
+                self.accounts.collection_master_edition,
+            ],
+            &[Signer::from(&signer_seeds)],
+        )
+    }
+}
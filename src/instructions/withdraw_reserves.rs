@@ -1,14 +1,18 @@
 use bytemuck::{Pod, Zeroable};
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+};
 
 use crate::{
     error::XTokenError,
-    state::{AccountData, XToken},
+    state::{check_authority_signed, AccountData, Role, XToken},
 };
 
 /// Accounts for WithdrawReserves instruction
 pub struct WithdrawReservesAccounts<'info> {
-    /// Authority (must match bonding curve authority)
+    /// Authority (must hold the owner or treasurer role)
     pub authority: &'info AccountInfo,
     /// Bonding curve state account (PDA)
     pub bonding_curve: &'info AccountInfo,
@@ -20,11 +24,15 @@ pub struct WithdrawReservesAccounts<'info> {
     pub recipient: &'info AccountInfo,
     /// System program
     pub system_program: &'info AccountInfo,
+    /// Protocol config account (PDA, checked for the emergency halt)
+    pub config: &'info AccountInfo,
+    /// Multisig members approving when the authority is a multisig (remaining accounts)
+    pub signers: &'info [AccountInfo],
 }
 
 impl<'info> WithdrawReservesAccounts<'info> {
     pub fn try_from(accounts: &'info [AccountInfo]) -> Result<Self, ProgramError> {
-        if accounts.len() < 6 {
+        if accounts.len() < 7 {
             return Err(ProgramError::NotEnoughAccountKeys);
         }
         Ok(Self {
@@ -34,6 +42,8 @@ impl<'info> WithdrawReservesAccounts<'info> {
             treasury: &accounts[3],
             recipient: &accounts[4],
             system_program: &accounts[5],
+            config: &accounts[6],
+            signers: &accounts[7..],
         })
     }
 }
@@ -41,7 +51,7 @@ impl<'info> WithdrawReservesAccounts<'info> {
 #[repr(C)]
 #[derive(Clone, Copy, Pod, Zeroable)]
 pub struct WithdrawReservesInstructionData {
-    /// 0 = withdraw all, otherwise exact lamports
+    /// 0 = withdraw everything withdrawable, otherwise exact lamports
     pub lamports: u64,
 }
 
@@ -80,19 +90,11 @@ impl<'info> TryFrom<(&'info [AccountInfo], &'info [u8])> for WithdrawReserves<'i
 
 impl<'info> WithdrawReserves<'info> {
     pub fn handler(&mut self) -> Result<(), ProgramError> {
-        if !self.accounts.authority.is_signer() {
-            pinocchio_log::log!("withdraw: missing authority signature");
-            return Err(ProgramError::MissingRequiredSignature);
-        }
+        check_authority_signed(self.accounts.authority, self.accounts.signers)?;
 
-        // validate state
-        let bonding_curve_data = self.accounts.bonding_curve.try_borrow_data()?;
-        let state = XToken::load(&bonding_curve_data)?;
-        if state.is_initialized == 0 { pinocchio_log::log!("withdraw: state not initialized"); return Err(XTokenError::AccountNotInitialized.into()); }
-        if state.token_mint != *self.accounts.mint.key() { pinocchio_log::log!("withdraw: mint mismatch"); return Err(XTokenError::InvalidAccountData.into()); }
-        let admin = state.get_admin();
-        let is_auth = state.authority == *self.accounts.authority.key() || admin == *self.accounts.authority.key();
-        if !is_auth { pinocchio_log::log!("withdraw: invalid authority"); return Err(XTokenError::InvalidAuthority.into()); }
+        if unsafe { *self.accounts.bonding_curve.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
 
         // derive treasury PDA and signer seeds
         let (treasury_pda, treasury_bump) = pinocchio::pubkey::find_program_address(
@@ -104,14 +106,29 @@ impl<'info> WithdrawReserves<'info> {
             return Err(ProgramError::InvalidSeeds);
         }
 
-        // amount to withdraw
-        let available = self.accounts.treasury.lamports();
-        pinocchio_log::log!("withdraw: available={}", available);
-        let amount = if self.instruction_data.lamports == 0 {
-            available
-        } else {
-            if self.instruction_data.lamports > available { pinocchio_log::log!("withdraw: insufficient funds requested={}", self.instruction_data.lamports); return Err(XTokenError::InsufficientFunds.into()); }
-            self.instruction_data.lamports
+        let treasury_lamports = self.accounts.treasury.lamports();
+        // the treasury must stay rent-exempt whatever is withdrawn
+        let rent_floor = Rent::get()?.minimum_balance(self.accounts.treasury.data_len());
+
+        // validate state and take the amount out of the withdrawable reserves
+        let amount = {
+            let mut bonding_curve_data = self.accounts.bonding_curve.try_borrow_mut_data()?;
+            let state = XToken::load_mut(&mut bonding_curve_data)?;
+            if state.is_initialized == 0 { pinocchio_log::log!("withdraw: state not initialized"); return Err(XTokenError::AccountNotInitialized.into()); }
+            if state.token_mint != *self.accounts.mint.key() { pinocchio_log::log!("withdraw: mint mismatch"); return Err(XTokenError::InvalidAccountData.into()); }
+            let key = self.accounts.authority.key();
+            if !state.has_role(Role::Owner, key) && !state.has_role(Role::Treasurer, key) { pinocchio_log::log!("withdraw: invalid authority"); return Err(XTokenError::InvalidAuthority.into()); }
+
+            let available = state.withdrawable_reserves(treasury_lamports, rent_floor);
+            pinocchio_log::log!("withdraw: available={}", available);
+            let amount = if self.instruction_data.lamports == 0 {
+                available
+            } else {
+                if self.instruction_data.lamports > available { pinocchio_log::log!("withdraw: insufficient funds requested={}", self.instruction_data.lamports); return Err(XTokenError::InsufficientFunds.into()); }
+                self.instruction_data.lamports
+            };
+            state.record_withdrawal(amount, treasury_lamports, rent_floor)?;
+            amount
         };
         pinocchio_log::log!("withdraw: amount={}", amount);
         if amount == 0 { pinocchio_log::log!("withdraw: zero amount, skip"); return Ok(()); }
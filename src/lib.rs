@@ -7,6 +7,7 @@ pub mod error;
 pub mod instructions;
 pub mod processor;
 pub mod state;
+pub mod token_interface;
 
 pinocchio_pubkey::declare_id!("ASXm2vSkEpLKQ3YnpdCEbhADQw86gefgFQi5DbyVZonL");
 
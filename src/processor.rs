@@ -2,10 +2,20 @@ use pinocchio::{
     account_info::AccountInfo,
     program_error::ProgramError,
     pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
 };
 use pinocchio_log::log;
 
-use crate::instructions::{Instruction, Initialize, BuyTokens, SellTokens, WithdrawReserves, AdminMint};
+use crate::error::XTokenError;
+use crate::state::ProtocolConfig;
+use crate::instructions::{
+    Instruction, Initialize, BuyTokens, SellTokens, WithdrawReserves, AdminMint, InitializeConfig,
+    UpdateConfig, UpdateCurveConfig, ProposeAuthority, AcceptAuthority, CancelAuthorityTransfer,
+    SetRole, MigrateAccount, PauseCurve, ResumeCurve, SetEmergencyHalt, SetGuardian, CreateMultisig,
+    QueueAction, ExecuteAction, CancelAction, CreateVesting, ClaimVested, RevokeVesting,
+    RenounceAuthorities, CloseCurve, UpdateMetadata, VerifyCollection, HarvestWithheldFees,
+    CreateCurve, CreateMetadata, InitialBuy, ClaimCreationRebate,
+};
 
 /// Main instruction processor
 #[inline(always)]
@@ -32,19 +42,31 @@ pub fn process_instruction(
         .split_first()
         .ok_or(ProgramError::InvalidInstructionData)?;
 
-    // Validate discriminator for Initialize (0)
-    if *discriminator == 0 {
-        // ok
-    } else {
-        log!("disc_not_zero");
+    let instruction = Instruction::try_from(*discriminator)?;
+
+    // Emergency halt: state-changing instructions must pass the protocol config
+    // and are rejected while the guardian has halted the protocol
+    if !instruction.allowed_during_halt() {
+        let config = ProtocolConfig::find_in(accounts)?;
+        if config.emergency_halt != 0 {
+            log!("emergency_halt_active");
+            return Err(XTokenError::EmergencyHalt.into());
+        }
+
+        // With a timelock configured, sensitive curve actions only run via ExecuteAction
+        if instruction.timelock_role().is_some() && config.timelock_delay(Clock::get()?.unix_timestamp) > 0 {
+            log!("timelock_required");
+            return Err(XTokenError::TimelockRequired.into());
+        }
     }
 
     // Route to appropriate instruction handler
-    match Instruction::try_from(*discriminator)? {
+    match instruction {
         Instruction::Initialize => {
-            log!("INIT_MARKER_V2");
-            // Re-enable length check: data no longer includes discriminator
-            if data.len() != crate::instructions::initialize::InitializeInstructionData::LEN {
+            log!("Instruction: Initialize");
+            // data no longer includes the discriminator
+            use crate::instructions::initialize::InitializeInstructionData;
+            if data.len() != InitializeInstructionData::LEN && data.len() != InitializeInstructionData::V1_LEN {
                 log!("initialize_data_len_mismatch");
                 return Err(ProgramError::InvalidInstructionData);
             }
@@ -71,5 +93,155 @@ pub fn process_instruction(
             let mut admin_mint = AdminMint::try_from((accounts, data))?;
             admin_mint.handler()
         }
+        Instruction::InitializeConfig => {
+            log!("Instruction: InitializeConfig");
+            let mut initialize_config = InitializeConfig::try_from((accounts, data))?;
+            initialize_config.handler()
+        }
+        Instruction::UpdateConfig => {
+            log!("Instruction: UpdateConfig");
+            let mut update_config = UpdateConfig::try_from((accounts, data))?;
+            update_config.handler()
+        }
+        Instruction::UpdateCurveConfig => {
+            log!("Instruction: UpdateCurveConfig");
+            let mut update_curve_config = UpdateCurveConfig::try_from((accounts, data))?;
+            update_curve_config.handler()
+        }
+        Instruction::ProposeAuthority => {
+            log!("Instruction: ProposeAuthority");
+            let mut propose_authority = ProposeAuthority::try_from((accounts, data))?;
+            propose_authority.handler()
+        }
+        Instruction::AcceptAuthority => {
+            log!("Instruction: AcceptAuthority");
+            let mut accept_authority = AcceptAuthority::try_from((accounts, data))?;
+            accept_authority.handler()
+        }
+        Instruction::CancelAuthorityTransfer => {
+            log!("Instruction: CancelAuthorityTransfer");
+            let mut cancel_authority_transfer = CancelAuthorityTransfer::try_from((accounts, data))?;
+            cancel_authority_transfer.handler()
+        }
+        Instruction::SetRole => {
+            log!("Instruction: SetRole");
+            let mut set_role = SetRole::try_from((accounts, data))?;
+            set_role.handler()
+        }
+        Instruction::MigrateAccount => {
+            log!("Instruction: MigrateAccount");
+            let mut migrate_account = MigrateAccount::try_from((accounts, data))?;
+            migrate_account.handler()
+        }
+        Instruction::PauseCurve => {
+            log!("Instruction: PauseCurve");
+            let mut pause_curve = PauseCurve::try_from((accounts, data))?;
+            pause_curve.handler()
+        }
+        Instruction::ResumeCurve => {
+            log!("Instruction: ResumeCurve");
+            let mut resume_curve = ResumeCurve::try_from((accounts, data))?;
+            resume_curve.handler()
+        }
+        Instruction::SetEmergencyHalt => {
+            log!("Instruction: SetEmergencyHalt");
+            let mut set_emergency_halt = SetEmergencyHalt::try_from((accounts, data))?;
+            set_emergency_halt.handler()
+        }
+        Instruction::SetGuardian => {
+            log!("Instruction: SetGuardian");
+            let mut set_guardian = SetGuardian::try_from((accounts, data))?;
+            set_guardian.handler()
+        }
+        Instruction::CreateMultisig => {
+            log!("Instruction: CreateMultisig");
+            let mut create_multisig = CreateMultisig::try_from((accounts, data))?;
+            create_multisig.handler()
+        }
+        Instruction::QueueAction => {
+            log!("Instruction: QueueAction");
+            let mut queue_action = QueueAction::try_from((accounts, data))?;
+            queue_action.handler()
+        }
+        Instruction::ExecuteAction => {
+            log!("Instruction: ExecuteAction");
+            let mut execute_action = ExecuteAction::try_from((accounts, data))?;
+            execute_action.handler()
+        }
+        Instruction::CancelAction => {
+            log!("Instruction: CancelAction");
+            let mut cancel_action = CancelAction::try_from((accounts, data))?;
+            cancel_action.handler()
+        }
+        Instruction::CreateVesting => {
+            log!("Instruction: CreateVesting");
+            let mut create_vesting = CreateVesting::try_from((accounts, data))?;
+            create_vesting.handler()
+        }
+        Instruction::ClaimVested => {
+            log!("Instruction: ClaimVested");
+            let mut claim_vested = ClaimVested::try_from((accounts, data))?;
+            claim_vested.handler()
+        }
+        Instruction::RevokeVesting => {
+            log!("Instruction: RevokeVesting");
+            let mut revoke_vesting = RevokeVesting::try_from((accounts, data))?;
+            revoke_vesting.handler()
+        }
+        Instruction::RenounceAuthorities => {
+            log!("Instruction: RenounceAuthorities");
+            let mut renounce_authorities = RenounceAuthorities::try_from((accounts, data))?;
+            renounce_authorities.handler()
+        }
+        Instruction::CloseCurve => {
+            log!("Instruction: CloseCurve");
+            let mut close_curve = CloseCurve::try_from((accounts, data))?;
+            close_curve.handler()
+        }
+        Instruction::UpdateMetadata => {
+            log!("Instruction: UpdateMetadata");
+            let mut update_metadata = UpdateMetadata::try_from((accounts, data))?;
+            update_metadata.handler()
+        }
+        Instruction::VerifyCollection => {
+            log!("Instruction: VerifyCollection");
+            let mut verify_collection = VerifyCollection::try_from((accounts, data))?;
+            verify_collection.handler()
+        }
+        Instruction::HarvestWithheldFees => {
+            log!("Instruction: HarvestWithheldFees");
+            let mut harvest_withheld_fees = HarvestWithheldFees::try_from((accounts, data))?;
+            harvest_withheld_fees.handler()
+        }
+        Instruction::InitializeWithoutMetadata => {
+            log!("Instruction: InitializeWithoutMetadata");
+            let mut initialize = Initialize::without_metadata((accounts, data))?;
+            initialize.handler()
+        }
+        Instruction::InitializeCompact => {
+            log!("Instruction: InitializeCompact");
+            let mut initialize = Initialize::compact((accounts, data))?;
+            initialize.handler()
+        }
+        Instruction::CreateCurve => {
+            log!("Instruction: CreateCurve");
+            let mut create_curve = CreateCurve::try_from((accounts, data))?;
+            create_curve.handler()
+        }
+        Instruction::CreateMetadata => {
+            log!("Instruction: CreateMetadata");
+            let mut create_metadata = CreateMetadata::try_from((accounts, data))?;
+            create_metadata.handler()
+        }
+        Instruction::InitialBuy => {
+            log!("Instruction: InitialBuy");
+            let mut initial_buy = InitialBuy::try_from((accounts, data))?;
+            initial_buy.handler()
+        }
+        Instruction::ClaimCreationRebate => {
+            log!("Instruction: ClaimCreationRebate");
+            let mut claim_creation_rebate = ClaimCreationRebate::try_from((accounts, data))?;
+            claim_creation_rebate.handler()
+        }
     }
 }
@@ -1,8 +1,18 @@
 use bytemuck::{Pod, Zeroable};
-use pinocchio::program_error::ProgramError;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
 
+use crate::error::XTokenError;
+
+pub mod multisig;
+pub mod pending_action;
+pub mod protocol_config;
+pub mod vesting;
 pub mod x_token;
 
+pub use multisig::*;
+pub use pending_action::*;
+pub use protocol_config::*;
+pub use vesting::*;
 pub use x_token::*;
 
 /// Trait for loading and storing account data
@@ -22,4 +32,16 @@ pub trait AccountData: Pod + Zeroable {
         }
         Ok(bytemuck::from_bytes_mut(data))
     }
+
+    /// Close a program-owned account, returning its rent to `recipient`
+    fn close(account: &AccountInfo, recipient: &AccountInfo) -> Result<(), ProgramError> {
+        let lamports = account.lamports();
+        {
+            let mut recipient_lamports = recipient.try_borrow_mut_lamports()?;
+            *recipient_lamports = recipient_lamports
+                .checked_add(lamports)
+                .ok_or(XTokenError::ArithmeticOverflow)?;
+        }
+        account.close()
+    }
 }
@@ -0,0 +1,111 @@
+use super::AccountData;
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::error::XTokenError;
+
+/// Maximum number of member keys a multisig can hold
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+/// M-of-N signer set usable as a curve authority (PDA seeded by
+/// `SEED_PREFIX` and a creation key)
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct Multisig {
+    /// Member keys; only the first `num_signers` entries are used
+    pub signers: [Pubkey; MAX_MULTISIG_SIGNERS],
+    /// Number of member signatures required
+    pub threshold: u8,
+    /// Number of member keys
+    pub num_signers: u8,
+    /// Whether the multisig is initialized (0 = false, 1 = true)
+    pub is_initialized: u8,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Reserved space for future use
+    pub reserved: [u8; 28],
+}
+
+impl AccountData for Multisig {}
+
+impl Multisig {
+    pub const SEED_PREFIX: &'static [u8] = b"multisig";
+
+    /// Initialize the multisig with its members and threshold
+    pub fn initialize(
+        &mut self,
+        signers: &[Pubkey],
+        threshold: u8,
+        bump: u8,
+    ) -> Result<(), ProgramError> {
+        if self.is_initialized != 0 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        if signers.is_empty()
+            || signers.len() > MAX_MULTISIG_SIGNERS
+            || threshold == 0
+            || threshold as usize > signers.len()
+        {
+            return Err(XTokenError::InvalidMultisig.into());
+        }
+
+        // duplicate or empty members would let fewer people meet the threshold
+        for (i, signer) in signers.iter().enumerate() {
+            if *signer == Pubkey::default() || signers[..i].contains(signer) {
+                return Err(XTokenError::InvalidMultisig.into());
+            }
+        }
+
+        self.signers = [Pubkey::default(); MAX_MULTISIG_SIGNERS];
+        self.signers[..signers.len()].copy_from_slice(signers);
+        self.threshold = threshold;
+        self.num_signers = signers.len() as u8;
+        self.is_initialized = 1;
+        self.bump = bump;
+        self.reserved = [0; 28];
+
+        Ok(())
+    }
+
+    /// Check that at least `threshold` distinct members signed among `accounts`
+    pub fn verify(&self, accounts: &[AccountInfo]) -> Result<(), ProgramError> {
+        let approvals = self.signers[..self.num_signers as usize]
+            .iter()
+            .filter(|member| {
+                accounts
+                    .iter()
+                    .any(|account| account.is_signer() && account.key() == *member)
+            })
+            .count();
+
+        if approvals < self.threshold as usize {
+            return Err(XTokenError::NotEnoughMultisigSigners.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Verify that `authority` signed the transaction, either directly or as a
+/// program-owned multisig whose threshold is met by `signers`
+pub fn check_authority_signed(
+    authority: &AccountInfo,
+    signers: &[AccountInfo],
+) -> Result<(), ProgramError> {
+    if authority.is_signer() {
+        return Ok(());
+    }
+
+    if unsafe { *authority.owner() } != crate::ID || authority.data_len() != Multisig::LEN {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let data = authority.try_borrow_data()?;
+    let multisig = Multisig::load(&data)?;
+    if multisig.is_initialized == 0 {
+        return Err(XTokenError::AccountNotInitialized.into());
+    }
+
+    multisig.verify(signers)
+}
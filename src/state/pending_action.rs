@@ -0,0 +1,103 @@
+use super::AccountData;
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::error::XTokenError;
+
+/// Largest instruction payload a queued action can carry
+pub const MAX_ACTION_DATA_LEN: usize = 64;
+
+/// Timelocked curve action waiting for its delay to pass (PDA seeded by
+/// `SEED_PREFIX`, the bonding curve and the action id)
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct PendingAction {
+    /// Bonding curve the action applies to
+    pub bonding_curve: Pubkey,
+    /// Account that queued the action (receives the rent back on close)
+    pub proposer: Pubkey,
+    /// Unix timestamp after which the action may execute
+    pub execute_after: i64,
+    /// Caller-chosen id distinguishing actions on the same curve
+    pub action_id: u64,
+    /// Instruction data of the queued action (without discriminator)
+    pub data: [u8; MAX_ACTION_DATA_LEN],
+    /// Number of used bytes in `data`
+    pub data_len: u8,
+    /// Discriminator of the queued instruction
+    pub kind: u8,
+    /// Whether the action is initialized (0 = false, 1 = true)
+    pub is_initialized: u8,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Account the action pays out to, fixed at queue time (all zeroes for
+    /// actions without one)
+    pub destination: Pubkey,
+    /// Reserved space for future use
+    pub reserved: [u8; 4],
+}
+
+impl AccountData for PendingAction {}
+
+impl PendingAction {
+    pub const SEED_PREFIX: &'static [u8] = b"action";
+
+    /// Initialize the pending action
+    pub fn initialize(
+        &mut self,
+        bonding_curve: Pubkey,
+        proposer: Pubkey,
+        action_id: u64,
+        kind: u8,
+        destination: Pubkey,
+        data: &[u8],
+    ) -> Result<(), ProgramError> {
+        if self.is_initialized != 0 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        if data.len() > MAX_ACTION_DATA_LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        self.bonding_curve = bonding_curve;
+        self.proposer = proposer;
+        self.action_id = action_id;
+        self.kind = kind;
+        self.destination = destination;
+        self.data = [0; MAX_ACTION_DATA_LEN];
+        self.data[..data.len()].copy_from_slice(data);
+        self.data_len = data.len() as u8;
+        self.is_initialized = 1;
+        self.reserved = [0; 4];
+
+        Ok(())
+    }
+
+    /// Queued instruction data
+    pub fn action_data(&self) -> &[u8] {
+        &self.data[..self.data_len as usize]
+    }
+
+    /// Load a copy of the action from `account`, verifying ownership and
+    /// that it belongs to `bonding_curve`
+    pub fn from_account(
+        account: &AccountInfo,
+        bonding_curve: &Pubkey,
+    ) -> Result<Self, ProgramError> {
+        if unsafe { *account.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data = account.try_borrow_data()?;
+        let action = Self::load(&data)?;
+        if action.is_initialized == 0 {
+            return Err(XTokenError::AccountNotInitialized.into());
+        }
+        if action.bonding_curve != *bonding_curve {
+            return Err(XTokenError::InvalidAccountData.into());
+        }
+
+        Ok(*action)
+    }
+}
@@ -0,0 +1,217 @@
+use super::AccountData;
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::error::XTokenError;
+
+/// Default treasury cap per curve (84 SOL)
+pub const DEFAULT_SOL_CAP_LAMPORTS: u64 = 84_000_000_000;
+
+/// Hard ceiling for any fee bound (100%)
+pub const MAX_FEE_BASIS_POINTS: u16 = 10_000;
+
+/// Protocol-wide settings singleton (PDA seeded by `SEED_PREFIX`)
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct ProtocolConfig {
+    /// Protocol admin allowed to update this config
+    pub admin: Pubkey,
+    /// Default fee recipient for curves launched without an explicit one
+    pub fee_recipient: Pubkey,
+    /// Maximum SOL (lamports) a single curve treasury may hold
+    pub sol_cap_lamports: u64,
+    /// Lowest fee a curve may charge, in basis points
+    pub min_fee_basis_points: u16,
+    /// Highest fee a curve may charge, in basis points
+    pub max_fee_basis_points: u16,
+    /// Whether Initialize creates Metaplex metadata (0 = false, 1 = true)
+    pub use_metaplex: u8,
+    /// Whether launches and trading are paused (0 = false, 1 = true)
+    pub paused: u8,
+    /// Whether the config is initialized (0 = false, 1 = true)
+    pub is_initialized: u8,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Guardian key allowed to toggle the emergency halt
+    pub guardian: Pubkey,
+    /// Whether the emergency halt is active (0 = false, 1 = true)
+    pub emergency_halt: u8,
+    /// Alignment padding
+    pub padding: [u8; 7],
+    /// Delay (seconds) before queued curve actions may execute; 0 disables
+    /// the timelock and allows direct execution
+    pub timelock_delay_seconds: i64,
+    /// Fee (lamports) the payer of every launch pays the protocol fee
+    /// recipient; 0 disables it
+    pub creation_fee_lamports: u64,
+    /// Share of the creation fee held in the curve account and paid back to
+    /// the creator once the curve graduates, in basis points
+    pub creation_fee_rebate_basis_points: u16,
+    /// Reserved space for future use
+    pub reserved: [u8; 6],
+    /// Lower timelock delay waiting to replace `timelock_delay_seconds`
+    pub pending_timelock_delay_seconds: i64,
+    /// Unix timestamp from which `pending_timelock_delay_seconds` applies;
+    /// 0 when no lower delay is scheduled
+    pub timelock_delay_lowers_at: i64,
+}
+
+impl AccountData for ProtocolConfig {}
+
+impl ProtocolConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"protocol_config";
+
+    /// Initialize the protocol config with its admin
+    pub fn initialize(&mut self, admin: Pubkey, bump: u8) -> Result<(), ProgramError> {
+        if self.is_initialized != 0 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        self.admin = admin;
+        self.guardian = admin;
+        self.emergency_halt = 0;
+        self.padding = [0; 7];
+        self.timelock_delay_seconds = 0;
+        self.pending_timelock_delay_seconds = 0;
+        self.timelock_delay_lowers_at = 0;
+        self.creation_fee_lamports = 0;
+        self.creation_fee_rebate_basis_points = 0;
+        self.bump = bump;
+        self.is_initialized = 1;
+        self.reserved = [0; 6];
+
+        Ok(())
+    }
+
+    /// Update mutable settings, validating fee bounds and cap
+    pub fn update(
+        &mut self,
+        fee_recipient: Pubkey,
+        sol_cap_lamports: u64,
+        min_fee_basis_points: u16,
+        max_fee_basis_points: u16,
+        use_metaplex: bool,
+        paused: bool,
+    ) -> Result<(), ProgramError> {
+        if min_fee_basis_points > max_fee_basis_points
+            || max_fee_basis_points > MAX_FEE_BASIS_POINTS
+        {
+            return Err(XTokenError::InvalidConfig.into());
+        }
+
+        if sol_cap_lamports == 0 {
+            return Err(XTokenError::InvalidConfig.into());
+        }
+
+        self.fee_recipient = fee_recipient;
+        self.sol_cap_lamports = sol_cap_lamports;
+        self.min_fee_basis_points = min_fee_basis_points;
+        self.max_fee_basis_points = max_fee_basis_points;
+        self.use_metaplex = use_metaplex as u8;
+        self.paused = paused as u8;
+
+        Ok(())
+    }
+
+    /// Set the launch creation fee and the share of it rebated on graduation
+    pub fn set_creation_fee(
+        &mut self,
+        creation_fee_lamports: u64,
+        creation_fee_rebate_basis_points: u16,
+    ) -> Result<(), ProgramError> {
+        if creation_fee_rebate_basis_points > MAX_FEE_BASIS_POINTS {
+            return Err(XTokenError::InvalidConfig.into());
+        }
+
+        self.creation_fee_lamports = creation_fee_lamports;
+        self.creation_fee_rebate_basis_points = creation_fee_rebate_basis_points;
+
+        Ok(())
+    }
+
+    /// Timelock delay in force at `now`, including a scheduled lower delay
+    /// whose waiting period has passed
+    pub fn timelock_delay(&self, now: i64) -> i64 {
+        if self.timelock_delay_lowers_at != 0 && now >= self.timelock_delay_lowers_at {
+            self.pending_timelock_delay_seconds
+        } else {
+            self.timelock_delay_seconds
+        }
+    }
+
+    /// Change the timelock delay. Raising (or keeping) it applies at once;
+    /// lowering it is only scheduled and applies after the current delay has
+    /// passed, so queued-action watchers always get the notice they were
+    /// promised
+    pub fn set_timelock_delay(&mut self, delay_seconds: i64, now: i64) -> Result<(), ProgramError> {
+        if delay_seconds < 0 {
+            return Err(XTokenError::InvalidConfig.into());
+        }
+
+        let current = self.timelock_delay(now);
+        if delay_seconds >= current {
+            self.timelock_delay_seconds = delay_seconds;
+            self.pending_timelock_delay_seconds = 0;
+            self.timelock_delay_lowers_at = 0;
+        } else {
+            self.timelock_delay_seconds = current;
+            self.pending_timelock_delay_seconds = delay_seconds;
+            self.timelock_delay_lowers_at =
+                now.checked_add(current).ok_or(XTokenError::ArithmeticOverflow)?;
+        }
+
+        Ok(())
+    }
+
+    /// Split the creation fee into the part the protocol keeps and the rebate
+    /// held for the creator
+    pub fn creation_fee_split(&self) -> (u64, u64) {
+        let rebate = (self.creation_fee_lamports as u128 * self.creation_fee_rebate_basis_points as u128
+            / MAX_FEE_BASIS_POINTS as u128) as u64;
+        (self.creation_fee_lamports - rebate, rebate)
+    }
+
+    /// Whether `fee_basis_points` lies within the configured bounds
+    pub fn fee_in_bounds(&self, fee_basis_points: u16) -> bool {
+        fee_basis_points >= self.min_fee_basis_points
+            && fee_basis_points <= self.max_fee_basis_points
+    }
+
+    /// Load a copy of the config from `account`, verifying it is the
+    /// program-owned config PDA
+    pub fn from_account(account: &AccountInfo) -> Result<Self, ProgramError> {
+        let (config_address, _) =
+            pinocchio::pubkey::find_program_address(&[Self::SEED_PREFIX], &crate::ID);
+        if config_address != *account.key() {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        Self::load_checked(account)
+    }
+
+    /// Locate the config PDA anywhere in `accounts` and load a copy of it
+    pub fn find_in(accounts: &[AccountInfo]) -> Result<Self, ProgramError> {
+        let (config_address, _) =
+            pinocchio::pubkey::find_program_address(&[Self::SEED_PREFIX], &crate::ID);
+        let account = accounts
+            .iter()
+            .find(|account| *account.key() == config_address)
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        Self::load_checked(account)
+    }
+
+    fn load_checked(account: &AccountInfo) -> Result<Self, ProgramError> {
+        if unsafe { *account.owner() } != crate::ID {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data = account.try_borrow_data()?;
+        let config = Self::load(&data)?;
+        if config.is_initialized == 0 {
+            return Err(XTokenError::AccountNotInitialized.into());
+        }
+
+        Ok(*config)
+    }
+}
@@ -0,0 +1,128 @@
+use super::AccountData;
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::error::XTokenError;
+
+/// Linear vesting schedule for tokens held in a program-controlled escrow
+/// (PDA seeded by `SEED_PREFIX`, the bonding curve and the beneficiary)
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct Vesting {
+    /// Bonding curve whose creator allocation funds the vesting
+    pub bonding_curve: Pubkey,
+    /// Account allowed to claim unlocked tokens
+    pub beneficiary: Pubkey,
+    /// Escrow token account (ATA owned by this PDA)
+    pub escrow: Pubkey,
+    /// Total tokens under vesting (reduced to the vested amount on revoke)
+    pub total_amount: u64,
+    /// Tokens already claimed
+    pub claimed_amount: u64,
+    /// Unix timestamp unlocking starts from
+    pub start_ts: i64,
+    /// Unix timestamp before which nothing can be claimed
+    pub cliff_ts: i64,
+    /// Unix timestamp at which everything is unlocked
+    pub end_ts: i64,
+    /// Whether the curve owner may revoke unvested tokens (0 = false, 1 = true)
+    pub revocable: u8,
+    /// Whether the vesting was revoked (0 = false, 1 = true)
+    pub revoked: u8,
+    /// Whether the vesting is initialized (0 = false, 1 = true)
+    pub is_initialized: u8,
+    /// Bump seed for PDA
+    pub bump: u8,
+    /// Reserved space for future use
+    pub reserved: [u8; 36],
+}
+
+impl AccountData for Vesting {}
+
+impl Vesting {
+    pub const SEED_PREFIX: &'static [u8] = b"vesting";
+
+    /// Initialize the vesting account
+    pub fn initialize(
+        &mut self,
+        bonding_curve: Pubkey,
+        beneficiary: Pubkey,
+        escrow: Pubkey,
+        bump: u8,
+    ) -> Result<(), ProgramError> {
+        if self.is_initialized != 0 {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+
+        self.bonding_curve = bonding_curve;
+        self.beneficiary = beneficiary;
+        self.escrow = escrow;
+        self.claimed_amount = 0;
+        self.revoked = 0;
+        self.is_initialized = 1;
+        self.bump = bump;
+        self.reserved = [0; 36];
+
+        Ok(())
+    }
+
+    /// Set the schedule; requires `start <= cliff <= end` and `start < end`
+    pub fn set_schedule(
+        &mut self,
+        total_amount: u64,
+        start_ts: i64,
+        cliff_ts: i64,
+        end_ts: i64,
+        revocable: bool,
+    ) -> Result<(), ProgramError> {
+        if total_amount == 0 || start_ts > cliff_ts || cliff_ts > end_ts || start_ts >= end_ts {
+            return Err(XTokenError::InvalidVestingSchedule.into());
+        }
+
+        self.total_amount = total_amount;
+        self.start_ts = start_ts;
+        self.cliff_ts = cliff_ts;
+        self.end_ts = end_ts;
+        self.revocable = revocable as u8;
+
+        Ok(())
+    }
+
+    /// Tokens unlocked at `now`: nothing before the cliff, then linear from
+    /// `start_ts` to `end_ts`
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        if now < self.cliff_ts {
+            return 0;
+        }
+        if now >= self.end_ts {
+            return self.total_amount;
+        }
+
+        let elapsed = (now - self.start_ts) as u128;
+        let duration = (self.end_ts - self.start_ts) as u128;
+        (self.total_amount as u128 * elapsed / duration) as u64
+    }
+
+    /// Unlocked tokens not yet claimed at `now`
+    pub fn claimable_amount(&self, now: i64) -> u64 {
+        self.vested_amount(now).saturating_sub(self.claimed_amount)
+    }
+
+    /// Stop vesting at `now`: what has vested stays claimable, the rest is
+    /// returned. Returns the unvested amount.
+    pub fn revoke(&mut self, now: i64) -> Result<u64, ProgramError> {
+        if self.revocable == 0 || self.revoked != 0 {
+            return Err(XTokenError::VestingNotRevocable.into());
+        }
+
+        let vested = self.vested_amount(now);
+        let unvested = self.total_amount - vested;
+        self.total_amount = vested;
+        // everything left is unlocked from now on
+        self.cliff_ts = now;
+        self.end_ts = now;
+        self.revoked = 1;
+
+        Ok(unvested)
+    }
+}
@@ -2,11 +2,44 @@ use super::AccountData;
 use bytemuck::{Pod, Zeroable};
 use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
 
+use crate::error::XTokenError;
+
+/// Roles that gate privileged curve instructions
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// Curve authority; manages roles and ownership
+    Owner = 0,
+    /// Day-to-day operations (pause/resume)
+    Operator = 1,
+    /// Fee rate and fee recipient changes
+    FeeManager = 2,
+    /// AdminMint
+    Minter = 3,
+    /// WithdrawReserves alongside the owner
+    Treasurer = 4,
+}
+
+impl TryFrom<u8> for Role {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Role::Owner),
+            1 => Ok(Role::Operator),
+            2 => Ok(Role::FeeManager),
+            3 => Ok(Role::Minter),
+            4 => Ok(Role::Treasurer),
+            _ => Err(XTokenError::InvalidRole.into()),
+        }
+    }
+}
+
 /// Bonding curve state account
 #[repr(C)]
 #[derive(Clone, Copy, Pod, Zeroable)]
 pub struct XToken {
-    /// Authority that can update curve parameters
+    /// Authority that can update curve parameters (owner role)
     pub authority: Pubkey,
     /// Token mint address
     pub token_mint: Pubkey,
@@ -34,15 +67,114 @@ pub struct XToken {
     pub is_initialized: u8,
     /// Bump seed for PDA
     pub bump: u8,
+    /// Proposed new authority awaiting acceptance (all zeroes = none). Kept in
+    /// the bytes v1 accounts reserved, which held their legacy admin
+    pub pending_authority: Pubkey,
+    /// Account layout version (v1 accounts predate this field and are shorter)
+    pub version: u8,
+    /// Whether trading and minting are paused (0 = false, 1 = true)
+    pub paused: u8,
+    /// Whether the curve sold its whole supply (0 = false, 1 = true); trading
+    /// stops and the reserves become withdrawable
+    pub graduated: u8,
+    /// Operator role (all zeroes = unassigned)
+    pub operator: Pubkey,
+    /// Fee manager role (all zeroes = unassigned)
+    pub fee_manager: Pubkey,
+    /// Minter role (all zeroes = unassigned)
+    pub minter: Pubkey,
+    /// Tokens reserved for AdminMint, fixed at Initialize. The allocation is
+    /// minted outside the curve: it never moves the price and is not counted
+    /// in `total_supply`, but it comes out of `max_supply`, so the curve can
+    /// only sell `max_supply - creator_allocation` tokens.
+    pub creator_allocation: u64,
+    /// Tokens minted so far from `creator_allocation`; on curves migrated
+    /// from v1, the tokens the legacy admin minted, which may exceed it
+    pub creator_minted: u64,
+    /// Mint authorities given up through RenounceAuthorities
+    /// (`RENOUNCED_MINT` | `RENOUNCED_FREEZE`)
+    pub renounced_authorities: u8,
+    /// Where the token metadata lives (`METADATA_METAPLEX`, `METADATA_NONE` or
+    /// `METADATA_TOKEN_2022`); curves created before this field read as Metaplex
+    pub metadata_mode: u8,
+    /// Alignment padding
+    pub padding: [u8; 6],
+    /// Part of the creation fee held in this account for the creator until
+    /// the curve graduates (ClaimCreationRebate); forfeited to the protocol
+    /// if the curve is closed instead
+    pub creation_rebate: u64,
+    /// Treasurer role (all zeroes = unassigned)
+    pub treasurer: Pubkey,
     /// Reserved space for future use
-    pub reserved: [u8; 35],
+    pub reserved: [u8; 136],
 }
 
-impl AccountData for XToken {}
+impl AccountData for XToken {
+    fn load(data: &[u8]) -> Result<&Self, ProgramError> {
+        Self::check_version(data)?;
+        Ok(bytemuck::from_bytes(data))
+    }
+
+    fn load_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        Self::check_version(data)?;
+        Ok(bytemuck::from_bytes_mut(data))
+    }
+}
 
 impl XToken {
     pub const SEED_PREFIX: &'static [u8] = b"x_token";
 
+    /// Layout version written by this program
+    pub const CURRENT_VERSION: u8 = 2;
+
+    /// `renounced_authorities` bit for the mint authority
+    pub const RENOUNCED_MINT: u8 = 1 << 0;
+
+    /// `renounced_authorities` bit for the freeze authority
+    pub const RENOUNCED_FREEZE: u8 = 1 << 1;
+
+    /// `metadata_mode`: Metaplex metadata account
+    pub const METADATA_METAPLEX: u8 = 0;
+
+    /// `metadata_mode`: launched without any metadata
+    pub const METADATA_NONE: u8 = 1;
+
+    /// `metadata_mode`: TokenMetadata extension inside the Token-2022 mint
+    pub const METADATA_TOKEN_2022: u8 = 2;
+
+    /// Offset of the version byte
+    pub const VERSION_OFFSET: usize = core::mem::offset_of!(XToken, version);
+
+    /// Size of v1 accounts: every field before the roles. Later versions only
+    /// append fields, so the v1 offsets stay valid.
+    pub const V1_LEN: usize = core::mem::offset_of!(XToken, operator);
+
+    /// Determine the layout version of raw curve account data
+    pub fn version_of(data: &[u8]) -> Result<u8, ProgramError> {
+        match data.len() {
+            Self::V1_LEN => Ok(1),
+            Self::LEN => Ok(data[Self::VERSION_OFFSET]),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    /// Reject anything but the current layout, pointing older accounts at MigrateAccount
+    fn check_version(data: &[u8]) -> Result<(), ProgramError> {
+        match Self::version_of(data)? {
+            Self::CURRENT_VERSION => Ok(()),
+            1 => Err(XTokenError::AccountNeedsMigration.into()),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    /// Load a freshly allocated (zeroed) account so it can be initialized
+    pub fn load_uninitialized_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(bytemuck::from_bytes_mut(data))
+    }
+
     /// Initialize a new bonding curve
     pub fn initialize(
         &mut self,
@@ -79,7 +211,22 @@ impl XToken {
         self.fee_recipient = fee_recipient;
         self.is_initialized = 1; // true
         self.bump = bump;
-        self.reserved = [0; 35];
+        self.pending_authority = [0; 32];
+        // Creator starts out holding every role
+        self.operator = authority;
+        self.fee_manager = authority;
+        self.minter = authority;
+        self.treasurer = [0; 32];
+        self.version = Self::CURRENT_VERSION;
+        self.paused = 0;
+        self.graduated = 0;
+        self.creator_allocation = 0;
+        self.creator_minted = 0;
+        self.renounced_authorities = 0;
+        self.metadata_mode = Self::METADATA_METAPLEX;
+        self.padding = [0; 6];
+        self.creation_rebate = 0;
+        self.reserved = [0; 136];
 
         // Store owner: first byte is length, rest is the string
         self.owner = [0; 32];
@@ -88,24 +235,211 @@ impl XToken {
             self.owner[1..=owner.len()].copy_from_slice(owner.as_bytes());
         }
 
-        // Set admin into reserved bytes (first 32 bytes of reserved)
-        self.set_admin(fee_recipient);
+        Ok(())
+    }
+
+    /// Update fee settings and, while no tokens are in circulation, pricing parameters
+    pub fn update_curve_config(
+        &mut self,
+        fee_basis_points: u16,
+        fee_recipient: Pubkey,
+        curve_type: u8,
+        base_price: u64,
+        slope: u64,
+        max_supply: u64,
+    ) -> Result<(), ProgramError> {
+        // an all-zero recipient would burn every trading fee
+        if fee_recipient == [0u8; 32] {
+            return Err(XTokenError::InvalidCurveParameters.into());
+        }
+
+        let pricing_changed = curve_type != self.curve_type
+            || base_price != self.base_price
+            || slope != self.slope
+            || max_supply != self.max_supply;
+
+        if self.total_supply > 0 {
+            // Holders bought against the current curve: pricing is frozen and fees may only go down
+            if pricing_changed || fee_basis_points > self.fee_basis_points {
+                return Err(XTokenError::CurveParametersLocked.into());
+            }
+        } else if !matches!(curve_type, 0 | 3) || base_price == 0 || max_supply <= self.creator_allocation {
+            // only linear (0) and cpmm (3) curves have pricing
+            return Err(XTokenError::InvalidCurveParameters.into());
+        }
+
+        self.fee_basis_points = fee_basis_points;
+        self.fee_recipient = fee_recipient;
+        self.curve_type = curve_type;
+        self.base_price = base_price;
+        self.slope = slope;
+        self.max_supply = max_supply;
+
+        Ok(())
+    }
+
+    /// Fix the creator allocation; the curve must keep some supply to sell
+    pub fn set_creator_allocation(&mut self, creator_allocation: u64) -> Result<(), ProgramError> {
+        if creator_allocation >= self.max_supply {
+            return Err(XTokenError::InvalidCurveParameters.into());
+        }
+        self.creator_allocation = creator_allocation;
+        Ok(())
+    }
+
+    /// Supply the curve itself may sell
+    pub fn curve_max_supply(&self) -> u64 {
+        self.max_supply.saturating_sub(self.creator_allocation)
+    }
+
+    /// Account for an AdminMint against the creator allocation
+    pub fn record_creator_mint(&mut self, amount: u64) -> Result<(), ProgramError> {
+        if self.renounced_authorities & Self::RENOUNCED_MINT != 0 {
+            return Err(XTokenError::AuthorityRenounced.into());
+        }
+        let minted = self
+            .creator_minted
+            .checked_add(amount)
+            .ok_or(XTokenError::ArithmeticOverflow)?;
+        if minted > self.creator_allocation {
+            return Err(XTokenError::CreatorAllocationExceeded.into());
+        }
+        self.creator_minted = minted;
+        Ok(())
+    }
+
+    /// Return revoked, never-released tokens to the creator allocation
+    pub fn release_creator_mint(&mut self, amount: u64) -> Result<(), ProgramError> {
+        self.creator_minted = self
+            .creator_minted
+            .checked_sub(amount)
+            .ok_or(XTokenError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Lamports WithdrawReserves may take from a treasury holding
+    /// `treasury_lamports`. Holders can sell back against `sol_reserve` even
+    /// after graduation, so only the surplus above it (donations, rounding
+    /// dust) is available until a completed wind-down (no tokens left in
+    /// circulation, creator allocation included). The rent floor always stays.
+    pub fn withdrawable_reserves(&self, treasury_lamports: u64, rent_floor: u64) -> u64 {
+        let spendable = treasury_lamports.saturating_sub(rent_floor);
+        if self.total_supply == 0 && self.creator_minted == 0 {
+            spendable
+        } else {
+            spendable.saturating_sub(self.sol_reserve)
+        }
+    }
+
+    /// Account for a withdrawal, drawing on the surplus before `sol_reserve`
+    pub fn record_withdrawal(
+        &mut self,
+        amount: u64,
+        treasury_lamports: u64,
+        rent_floor: u64,
+    ) -> Result<(), ProgramError> {
+        if amount > self.withdrawable_reserves(treasury_lamports, rent_floor) {
+            return Err(XTokenError::InsufficientFunds.into());
+        }
+
+        let surplus = treasury_lamports
+            .saturating_sub(rent_floor)
+            .saturating_sub(self.sol_reserve);
+        let from_reserve = amount.saturating_sub(surplus);
+        self.sol_reserve = self
+            .sol_reserve
+            .checked_sub(from_reserve)
+            .ok_or(XTokenError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    /// Record mint and/or freeze authorities as renounced. The mint authority
+    /// can only go once the curve has graduated, as buys still mint; whatever
+    /// is left of the creator allocation is forfeited with it. The program
+    /// never freezes accounts, so the freeze authority can go at any time.
+    pub fn renounce_authorities(&mut self, authorities: u8) -> Result<(), ProgramError> {
+        if authorities == 0 || authorities & !(Self::RENOUNCED_MINT | Self::RENOUNCED_FREEZE) != 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        if self.renounced_authorities & authorities != 0 {
+            return Err(XTokenError::AuthorityRenounced.into());
+        }
+        if authorities & Self::RENOUNCED_MINT != 0 && self.graduated == 0 {
+            return Err(XTokenError::CurveNotGraduated.into());
+        }
+        self.renounced_authorities |= authorities;
+        Ok(())
+    }
+
+    /// Release the creation rebate to the creator once the curve has graduated
+    pub fn claim_creation_rebate(&mut self) -> Result<u64, ProgramError> {
+        if self.graduated == 0 {
+            return Err(XTokenError::CurveNotGraduated.into());
+        }
+        if self.creation_rebate == 0 {
+            return Err(XTokenError::NoCreationRebate.into());
+        }
+        let rebate = self.creation_rebate;
+        self.creation_rebate = 0;
+        Ok(rebate)
+    }
 
+    /// Propose a new authority; it only takes effect once the new key accepts
+    pub fn propose_authority(&mut self, new_authority: Pubkey) -> Result<(), ProgramError> {
+        if new_authority == [0u8; 32] || new_authority == self.authority {
+            return Err(XTokenError::InvalidAuthority.into());
+        }
+        self.pending_authority = new_authority;
+        Ok(())
+    }
+
+    /// Complete a pending authority transfer signed by `signer`. Roles still
+    /// held by the outgoing authority move with it, so the old key keeps none.
+    pub fn accept_authority(&mut self, signer: &Pubkey) -> Result<(), ProgramError> {
+        if self.pending_authority == [0u8; 32] || self.pending_authority != *signer {
+            return Err(XTokenError::InvalidAuthority.into());
+        }
+        let outgoing = self.authority;
+        let incoming = self.pending_authority;
+        for holder in [&mut self.operator, &mut self.fee_manager, &mut self.minter, &mut self.treasurer] {
+            if *holder == outgoing {
+                *holder = incoming;
+            }
+        }
+        self.authority = incoming;
+        self.pending_authority = [0; 32];
         Ok(())
     }
 
-    /// Set admin pubkey into reserved bytes [0..32]
-    pub fn set_admin(&mut self, admin: Pubkey) {
-        // reserved has length 35; store first 32 bytes as admin
-        self.reserved[0..32].copy_from_slice(&admin);
+    /// Drop any pending authority transfer
+    pub fn cancel_authority_transfer(&mut self) {
+        self.pending_authority = [0; 32];
     }
 
-    /// Get admin pubkey from reserved bytes [0..32]
-    pub fn get_admin(&self) -> Pubkey {
-        let mut bytes = [0u8; 32];
-        bytes.copy_from_slice(&self.reserved[0..32]);
-        let is_zero = bytes.iter().all(|b| *b == 0);
-        if is_zero { self.fee_recipient } else { bytes }
+    /// Whether `key` currently holds `role`
+    pub fn has_role(&self, role: Role, key: &Pubkey) -> bool {
+        let holder = match role {
+            Role::Owner => &self.authority,
+            Role::Operator => &self.operator,
+            Role::FeeManager => &self.fee_manager,
+            Role::Minter => &self.minter,
+            Role::Treasurer => &self.treasurer,
+        };
+        *holder != [0u8; 32] && holder == key
+    }
+
+    /// Assign a non-owner role (all zeroes revokes it); ownership moves via
+    /// ProposeAuthority/AcceptAuthority
+    pub fn set_role(&mut self, role: Role, key: Pubkey) -> Result<(), ProgramError> {
+        match role {
+            Role::Owner => return Err(XTokenError::InvalidRole.into()),
+            Role::Operator => self.operator = key,
+            Role::FeeManager => self.fee_manager = key,
+            Role::Minter => self.minter = key,
+            Role::Treasurer => self.treasurer = key,
+        }
+        Ok(())
     }
 
     /// Get owner username as string
@@ -131,7 +465,7 @@ impl XToken {
             .checked_add(token_amount)
             .ok_or(ProgramError::ArithmeticOverflow)?;
 
-        if new_supply > self.max_supply {
+        if new_supply > self.curve_max_supply() {
             return Err(ProgramError::InvalidArgument);
         }
 
@@ -206,7 +540,7 @@ impl XToken {
             .ok_or(ProgramError::ArithmeticOverflow)?;
 
         let remaining_before = self
-            .max_supply
+            .curve_max_supply()
             .checked_sub(start_supply)
             .ok_or(ProgramError::ArithmeticOverflow)?;
         let remaining_after = remaining_before
@@ -263,7 +597,7 @@ impl XToken {
 
         // remaining tokens before/after
         let remaining_before = self
-            .max_supply
+            .curve_max_supply()
             .checked_sub(start_supply)
             .ok_or(ProgramError::ArithmeticOverflow)?;
         let remaining_after = remaining_before
@@ -349,6 +683,10 @@ impl XToken {
             .checked_add(sol_amount)
             .ok_or(ProgramError::ArithmeticOverflow)?;
 
+        if self.total_supply >= self.curve_max_supply() {
+            self.graduated = 1;
+        }
+
         Ok(())
     }
 
@@ -0,0 +1,401 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+};
+use pinocchio_token::instructions::AuthorityType;
+
+use crate::instructions::initialize::write_fixed;
+
+// CPIs shared by the classic token program and Token-2022: both accept the
+// same layouts for these instructions, only the program id differs
+
+// Token-2022 Program ID: TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = [ 6, 221, 246, 225, 238, 117, 143, 222, 24, 66, 93, 188, 228, 108, 205, 218, 182, 26, 252, 77, 131, 185, 13, 39, 254, 189, 249, 40, 216, 161, 139, 252, ];
+
+// Token instruction discriminators
+const INITIALIZE_MINT_2: u8 = 20;
+const MINT_TO: u8 = 7;
+const SET_AUTHORITY: u8 = 6;
+const BURN: u8 = 8;
+const TRANSFER_CHECKED: u8 = 12;
+const TRANSFER_FEE_EXTENSION: u8 = 26;
+const METADATA_POINTER_EXTENSION: u8 = 39;
+
+// TransferFeeExtension sub-instructions
+const INITIALIZE_TRANSFER_FEE_CONFIG: u8 = 0;
+const TRANSFER_CHECKED_WITH_FEE: u8 = 1;
+const WITHDRAW_WITHHELD_TOKENS_FROM_MINT: u8 = 2;
+const HARVEST_WITHHELD_TOKENS_TO_MINT: u8 = 4;
+
+// Token-2022 mint layout: base mint padded to the token account size, the
+// account type, then type-length-value extensions
+const MINT_SUPPLY_OFFSET: usize = 36;
+const MINT_DECIMALS_OFFSET: usize = 44;
+const EXTENSIONS_OFFSET: usize = 165 + 1;
+const TRANSFER_FEE_CONFIG_EXTENSION: u16 = 1;
+
+// Mint sizes: the classic layout, and each Token-2022 extension as type,
+// length and value
+const MINT_LEN: usize = 82;
+const METADATA_POINTER_LEN: usize = 4 + 64;
+const TRANSFER_FEE_CONFIG_LEN: usize = 4 + 108;
+
+// spl-token-metadata-interface Initialize discriminator
+const TOKEN_METADATA_INITIALIZE: [u8; 8] = [210, 225, 30, 162, 88, 184, 77, 141];
+
+/// Current supply of `mint`, owned by either token program
+pub fn mint_supply(mint: &AccountInfo) -> Result<u64, ProgramError> {
+    if !is_token_program(unsafe { mint.owner() }) { return Err(ProgramError::InvalidAccountOwner); }
+    let data = mint.try_borrow_data()?;
+    let supply = data
+        .get(MINT_SUPPLY_OFFSET..MINT_SUPPLY_OFFSET + 8)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    Ok(u64::from_le_bytes(supply.try_into().map_err(|_| ProgramError::InvalidAccountData)?))
+}
+
+/// Whether `program_id` is one of the supported token programs
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    *program_id == pinocchio_token::ID || *program_id == TOKEN_2022_PROGRAM_ID
+}
+
+/// Check that `token_program` is the program owning `mint`, and that it is
+/// the classic token program or Token-2022
+pub fn check_token_program(mint: &AccountInfo, token_program: &AccountInfo) -> Result<(), ProgramError> {
+    let owner = unsafe { mint.owner() };
+    if !is_token_program(owner) { return Err(ProgramError::InvalidAccountOwner); }
+    if token_program.key() != owner { return Err(ProgramError::IncorrectProgramId); }
+    Ok(())
+}
+
+/// Size of a mint account with the given Token-2022 extensions; classic
+/// mints have none
+pub fn mint_len(token_program: &Pubkey, metadata_pointer: bool, transfer_fee: bool) -> usize {
+    if *token_program != TOKEN_2022_PROGRAM_ID || !(metadata_pointer || transfer_fee) {
+        return MINT_LEN;
+    }
+    let mut len = EXTENSIONS_OFFSET;
+    if metadata_pointer { len += METADATA_POINTER_LEN; }
+    if transfer_fee { len += TRANSFER_FEE_CONFIG_LEN; }
+    len
+}
+
+/// InitializeMint2 with `mint_authority` and an optional freeze authority
+pub fn initialize_mint2(
+    token_program: &AccountInfo,
+    mint: &AccountInfo,
+    decimals: u8,
+    mint_authority: &Pubkey,
+    freeze_authority: Option<&Pubkey>,
+) -> Result<(), ProgramError> {
+    let mut data = [0u8; 67];
+    data[0] = INITIALIZE_MINT_2;
+    data[1] = decimals;
+    data[2..34].copy_from_slice(mint_authority);
+    let data_len = match freeze_authority {
+        Some(freeze_authority) => {
+            data[34] = 1;
+            data[35..67].copy_from_slice(freeze_authority);
+            67
+        }
+        None => 35,
+    };
+
+    let instruction = Instruction {
+        program_id: token_program.key(),
+        accounts: &[AccountMeta { pubkey: mint.key(), is_signer: false, is_writable: true }],
+        data: &data[..data_len],
+    };
+    pinocchio::program::invoke(&instruction, &[mint])
+}
+
+/// MintTo signed by `mint_authority`
+pub fn mint_to(
+    token_program: &AccountInfo,
+    mint: &AccountInfo,
+    account: &AccountInfo,
+    mint_authority: &AccountInfo,
+    amount: u64,
+    signers: &[Signer],
+) -> Result<(), ProgramError> {
+    let mut data = [0u8; 9];
+    data[0] = MINT_TO;
+    data[1..9].copy_from_slice(&amount.to_le_bytes());
+
+    let instruction = Instruction {
+        program_id: token_program.key(),
+        accounts: &[
+            AccountMeta { pubkey: mint.key(), is_signer: false, is_writable: true },
+            AccountMeta { pubkey: account.key(), is_signer: false, is_writable: true },
+            AccountMeta { pubkey: mint_authority.key(), is_signer: true, is_writable: false },
+        ],
+        data: &data,
+    };
+    pinocchio::program::invoke_signed(&instruction, &[mint, account, mint_authority], signers)
+}
+
+/// SetAuthority on `account`, signed by its current `authority`; `None`
+/// removes the authority for good
+pub fn set_authority(
+    token_program: &AccountInfo,
+    account: &AccountInfo,
+    authority: &AccountInfo,
+    authority_type: AuthorityType,
+    new_authority: Option<&Pubkey>,
+    signers: &[Signer],
+) -> Result<(), ProgramError> {
+    if !is_token_program(token_program.key()) { return Err(ProgramError::IncorrectProgramId); }
+
+    let mut data = [0u8; 35];
+    data[0] = SET_AUTHORITY;
+    data[1] = authority_type as u8;
+    let data_len = match new_authority {
+        Some(new_authority) => {
+            data[2] = 1;
+            data[3..35].copy_from_slice(new_authority);
+            35
+        }
+        None => 3,
+    };
+
+    let instruction = Instruction {
+        program_id: token_program.key(),
+        accounts: &[
+            AccountMeta { pubkey: account.key(), is_signer: false, is_writable: true },
+            AccountMeta { pubkey: authority.key(), is_signer: true, is_writable: false },
+        ],
+        data: &data[..data_len],
+    };
+    pinocchio::program::invoke_signed(&instruction, &[account, authority], signers)
+}
+
+/// Burn from `account`, approved by its owner `authority`
+pub fn burn(
+    token_program: &AccountInfo,
+    mint: &AccountInfo,
+    account: &AccountInfo,
+    authority: &AccountInfo,
+    amount: u64,
+    signers: &[Signer],
+) -> Result<(), ProgramError> {
+    let mut data = [0u8; 9];
+    data[0] = BURN;
+    data[1..9].copy_from_slice(&amount.to_le_bytes());
+
+    let instruction = Instruction {
+        program_id: token_program.key(),
+        accounts: &[
+            AccountMeta { pubkey: account.key(), is_signer: false, is_writable: true },
+            AccountMeta { pubkey: mint.key(), is_signer: false, is_writable: true },
+            AccountMeta { pubkey: authority.key(), is_signer: true, is_writable: false },
+        ],
+        data: &data,
+    };
+    pinocchio::program::invoke_signed(&instruction, &[account, mint, authority], signers)
+}
+
+/// Token-2022 only: charge `transfer_fee_basis_points` (capped at
+/// `maximum_fee`) on every transfer, withdrawable by `withdraw_withheld_authority`;
+/// the fee can never be changed. Must run before InitializeMint2.
+pub fn initialize_transfer_fee_config(
+    token_program: &AccountInfo,
+    mint: &AccountInfo,
+    withdraw_withheld_authority: &Pubkey,
+    transfer_fee_basis_points: u16,
+    maximum_fee: u64,
+) -> Result<(), ProgramError> {
+    let mut data = [0u8; 46];
+    data[0] = TRANSFER_FEE_EXTENSION;
+    data[1] = INITIALIZE_TRANSFER_FEE_CONFIG;
+    data[2] = 0; // transfer_fee_config_authority None
+    data[3] = 1;
+    data[4..36].copy_from_slice(withdraw_withheld_authority);
+    data[36..38].copy_from_slice(&transfer_fee_basis_points.to_le_bytes());
+    data[38..46].copy_from_slice(&maximum_fee.to_le_bytes());
+
+    let instruction = Instruction {
+        program_id: token_program.key(),
+        accounts: &[AccountMeta { pubkey: mint.key(), is_signer: false, is_writable: true }],
+        data: &data,
+    };
+    pinocchio::program::invoke(&instruction, &[mint])
+}
+
+/// Transfer fee the mint charges on `amount` in `epoch`; 0 for mints without
+/// the TransferFeeConfig extension
+pub fn transfer_fee(mint: &AccountInfo, amount: u64, epoch: u64) -> Result<u64, ProgramError> {
+    let data = mint.try_borrow_data()?;
+    let mut offset = EXTENSIONS_OFFSET;
+    while offset + 4 <= data.len() {
+        let extension_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+        let len = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+        let value = data.get(offset + 4..offset + 4 + len).ok_or(ProgramError::InvalidAccountData)?;
+        if extension_type == TRANSFER_FEE_CONFIG_EXTENSION {
+            if value.len() < 108 { return Err(ProgramError::InvalidAccountData); }
+            // authorities and withheld amount, then the older and newer fee
+            // (epoch, maximum_fee, basis points)
+            let u64_at = |o: usize| {
+                let mut bytes = [0u8; 8];
+                bytes.copy_from_slice(&value[o..o + 8]);
+                u64::from_le_bytes(bytes)
+            };
+            let fee_at = |o: usize| (u64_at(o), u64_at(o + 8), u16::from_le_bytes([value[o + 16], value[o + 17]]));
+            let newer = fee_at(90);
+            let (_, maximum_fee, basis_points) = if epoch >= newer.0 { newer } else { fee_at(72) };
+            let fee = (amount as u128 * basis_points as u128).div_ceil(10_000);
+            return Ok((fee as u64).min(maximum_fee));
+        }
+        offset += 4 + len;
+    }
+    Ok(0)
+}
+
+/// TransferChecked signed by `authority`, passing the expected transfer fee
+/// for Token-2022 mints that charge one; returns that fee, which the
+/// destination receives less of
+pub fn transfer_checked(
+    token_program: &AccountInfo,
+    source: &AccountInfo,
+    mint: &AccountInfo,
+    destination: &AccountInfo,
+    authority: &AccountInfo,
+    amount: u64,
+    signers: &[Signer],
+) -> Result<u64, ProgramError> {
+    let decimals = *mint.try_borrow_data()?.get(MINT_DECIMALS_OFFSET).ok_or(ProgramError::InvalidAccountData)?;
+    let fee = if *token_program.key() == TOKEN_2022_PROGRAM_ID {
+        transfer_fee(mint, amount, Clock::get()?.epoch)?
+    } else {
+        0
+    };
+
+    let mut data = [0u8; 19];
+    let data_len = if fee > 0 {
+        data[0] = TRANSFER_FEE_EXTENSION;
+        data[1] = TRANSFER_CHECKED_WITH_FEE;
+        data[2..10].copy_from_slice(&amount.to_le_bytes());
+        data[10] = decimals;
+        data[11..19].copy_from_slice(&fee.to_le_bytes());
+        19
+    } else {
+        data[0] = TRANSFER_CHECKED;
+        data[1..9].copy_from_slice(&amount.to_le_bytes());
+        data[9] = decimals;
+        10
+    };
+
+    let instruction = Instruction {
+        program_id: token_program.key(),
+        accounts: &[
+            AccountMeta { pubkey: source.key(), is_signer: false, is_writable: true },
+            AccountMeta { pubkey: mint.key(), is_signer: false, is_writable: false },
+            AccountMeta { pubkey: destination.key(), is_signer: false, is_writable: true },
+            AccountMeta { pubkey: authority.key(), is_signer: true, is_writable: false },
+        ],
+        data: &data[..data_len],
+    };
+    pinocchio::program::invoke_signed(&instruction, &[source, mint, destination, authority], signers)?;
+    Ok(fee)
+}
+
+/// Token-2022 only: move the fees withheld in `source` into the mint (permissionless)
+pub fn harvest_withheld_tokens_to_mint(
+    token_program: &AccountInfo,
+    mint: &AccountInfo,
+    source: &AccountInfo,
+) -> Result<(), ProgramError> {
+    let instruction = Instruction {
+        program_id: token_program.key(),
+        accounts: &[
+            AccountMeta { pubkey: mint.key(), is_signer: false, is_writable: true },
+            AccountMeta { pubkey: source.key(), is_signer: false, is_writable: true },
+        ],
+        data: &[TRANSFER_FEE_EXTENSION, HARVEST_WITHHELD_TOKENS_TO_MINT],
+    };
+    pinocchio::program::invoke(&instruction, &[mint, source])
+}
+
+/// Token-2022 only: move every fee withheld in the mint to `destination`,
+/// signed by the withdraw withheld authority
+pub fn withdraw_withheld_tokens_from_mint(
+    token_program: &AccountInfo,
+    mint: &AccountInfo,
+    destination: &AccountInfo,
+    authority: &AccountInfo,
+    signers: &[Signer],
+) -> Result<(), ProgramError> {
+    let instruction = Instruction {
+        program_id: token_program.key(),
+        accounts: &[
+            AccountMeta { pubkey: mint.key(), is_signer: false, is_writable: true },
+            AccountMeta { pubkey: destination.key(), is_signer: false, is_writable: true },
+            AccountMeta { pubkey: authority.key(), is_signer: true, is_writable: false },
+        ],
+        data: &[TRANSFER_FEE_EXTENSION, WITHDRAW_WITHHELD_TOKENS_FROM_MINT],
+    };
+    pinocchio::program::invoke_signed(&instruction, &[mint, destination, authority], signers)
+}
+
+/// Token-2022 only: point the mint's metadata at `metadata_address`; must
+/// run before InitializeMint2
+pub fn initialize_metadata_pointer(
+    token_program: &AccountInfo,
+    mint: &AccountInfo,
+    authority: &Pubkey,
+    metadata_address: &Pubkey,
+) -> Result<(), ProgramError> {
+    let mut data = [0u8; 66];
+    data[0] = METADATA_POINTER_EXTENSION;
+    data[1] = 0; // Initialize
+    data[2..34].copy_from_slice(authority);
+    data[34..66].copy_from_slice(metadata_address);
+
+    let instruction = Instruction {
+        program_id: token_program.key(),
+        accounts: &[AccountMeta { pubkey: mint.key(), is_signer: false, is_writable: true }],
+        data: &data,
+    };
+    pinocchio::program::invoke(&instruction, &[mint])
+}
+
+/// Serialized size of a TokenMetadata TLV entry with no additional metadata
+pub fn token_metadata_len(name: &str, symbol: &str, uri: &str) -> usize {
+    2 + 2 + // TLV type and length
+    32 + 32 + // update_authority, mint
+    4 + name.len() + 4 + symbol.len() + 4 + uri.len() +
+    4 // additional_metadata (empty)
+}
+
+/// Token-2022 only: write the TokenMetadata extension into the mint itself,
+/// signed by `mint_authority`. The mint must already hold enough lamports for
+/// the grown account.
+pub fn initialize_token_metadata(
+    token_program: &AccountInfo,
+    mint: &AccountInfo,
+    update_authority: &AccountInfo,
+    mint_authority: &AccountInfo,
+    (name, symbol, uri): (&str, &str, &str),
+    signers: &[Signer],
+) -> Result<(), ProgramError> {
+    let mut data = [0u8; 8 + 4 + 32 + 4 + 10 + 4 + 200];
+    let mut offset = 0usize;
+    write_fixed(&mut data, &mut offset, &TOKEN_METADATA_INITIALIZE)?;
+    for field in [name, symbol, uri] {
+        write_fixed(&mut data, &mut offset, &(field.len() as u32).to_le_bytes())?;
+        write_fixed(&mut data, &mut offset, field.as_bytes())?;
+    }
+
+    let instruction = Instruction {
+        program_id: token_program.key(),
+        accounts: &[
+            AccountMeta { pubkey: mint.key(), is_signer: false, is_writable: true }, // metadata
+            AccountMeta { pubkey: update_authority.key(), is_signer: false, is_writable: false },
+            AccountMeta { pubkey: mint.key(), is_signer: false, is_writable: false },
+            AccountMeta { pubkey: mint_authority.key(), is_signer: true, is_writable: false },
+        ],
+        data: &data[..offset],
+    };
+    pinocchio::program::invoke_signed(&instruction, &[mint, update_authority, mint, mint_authority], signers)
+}
@@ -1,5 +1,6 @@
 use litesvm::LiteSVM;
 use solana_sdk::{
+    clock::Clock,
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     rent::Rent,
@@ -27,6 +28,353 @@ fn derive_metadata_pda(mint: &Pubkey) -> Pubkey {
     metadata_pda
 }
 
+// Helper function to derive the protocol config PDA
+fn derive_config_pda(program_id: &Pubkey) -> Pubkey {
+    derive_pda(&[b"protocol_config"], program_id).0
+}
+
+// Helper function to build InitializeConfig instruction data
+fn initialize_config_data(
+    fee_recipient: &Pubkey,
+    sol_cap_lamports: u64,
+    min_fee_basis_points: u16,
+    max_fee_basis_points: u16,
+    use_metaplex: u8,
+    paused: u8,
+) -> Vec<u8> {
+    let mut data = vec![5u8]; // InitializeConfig discriminator
+    data.extend_from_slice(&fee_recipient.to_bytes());
+    data.extend_from_slice(&sol_cap_lamports.to_le_bytes());
+    data.extend_from_slice(&min_fee_basis_points.to_le_bytes());
+    data.extend_from_slice(&max_fee_basis_points.to_le_bytes());
+    data.push(use_metaplex);
+    data.push(paused);
+    data
+}
+
+// Helper function to create the protocol config with fee_payer as admin
+fn initialize_config(svm: &mut LiteSVM, fee_payer: &Keypair, program_id: &Pubkey) -> Pubkey {
+    let config = derive_config_pda(program_id);
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(fee_payer.pubkey(), true), // admin
+            AccountMeta::new(config, false),                     // config
+            AccountMeta::new(fee_payer.pubkey(), true),          // payer
+            AccountMeta::new_readonly(system_program::ID, false), // system_program
+        ],
+        data: initialize_config_data(&fee_payer.pubkey(), 84_000_000_000, 0, 1_000, 0, 0),
+    };
+    send_ix_and_check(svm, fee_payer, ix, true);
+    config
+}
+
+const TOKEN_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const TOKEN_2022_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+const ATA_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+const METAPLEX_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+// Token-2022 mint with only the MetadataPointer extension: base mint padded to
+// the token account size, account type, then the extension's TLV entry
+const MINT_2022_WITH_METADATA_POINTER_LEN: usize = 165 + 1 + 4 + 64;
+// TLV entry of the TransferFeeConfig extension
+const TRANSFER_FEE_CONFIG_LEN: usize = 4 + 108;
+
+// Creator allocation used by `launch_token`
+const CREATOR_ALLOCATION: u64 = 1_000_000_000;
+
+// A launched bonding curve and its accounts
+struct Launch {
+    mint: Pubkey,
+    authority: Keypair,
+    bonding_curve: Pubkey,
+    treasury: Pubkey,
+    config: Pubkey,
+    token_program: Pubkey,
+}
+
+impl Launch {
+    // Associated token account of `owner` for the launched mint
+    fn ata(&self, owner: &Pubkey) -> Pubkey {
+        spl_associated_token_account::get_associated_token_address_with_program_id(owner, &self.mint, &self.token_program)
+    }
+}
+
+// Helper function to launch a linear-curve token (no metadata, no pre-buy)
+// with fee_payer as protocol admin and the authority as fee recipient
+fn launch_token(svm: &mut LiteSVM, fee_payer: &Keypair, program_id: &Pubkey) -> Launch {
+    try_launch_token(svm, fee_payer, program_id, LaunchOptions::default()).expect("Initialize should succeed")
+}
+
+// Optional Initialize settings for try_launch_token
+#[derive(Default)]
+struct LaunchOptions {
+    seller_fee_basis_points: u16,
+    creator_share: u8,
+    collection: Pubkey,
+    token_2022: bool,
+    transfer_fee_basis_points: u16,
+    maximum_transfer_fee: u64,
+    // Send InitializeCompact with this encoding version instead of Initialize
+    compact_version: Option<u8>,
+    // Version byte of the fixed Initialize data; 1 sends the original data,
+    // which ends before it
+    fixed_version: Option<u8>,
+    // Trailing protocol fee recipient account for the creation fee
+    protocol_fee_recipient: Option<Pubkey>,
+}
+
+// Same as launch_token with the given options; None if Initialize fails
+fn try_launch_token(
+    svm: &mut LiteSVM,
+    fee_payer: &Keypair,
+    program_id: &Pubkey,
+    options: LaunchOptions,
+) -> Option<Launch> {
+    let config = derive_config_pda(program_id);
+    if svm.get_account(&config).is_none() {
+        initialize_config(svm, fee_payer, program_id);
+    }
+
+    // Token-2022 mints are sized for the MetadataPointer extension and any
+    // TransferFeeConfig; the program funds the metadata itself
+    let (token_program, mint_len) = if options.token_2022 {
+        let transfer_fee_len = if options.transfer_fee_basis_points != 0 { TRANSFER_FEE_CONFIG_LEN } else { 0 };
+        (TOKEN_2022_PROGRAM_ID, MINT_2022_WITH_METADATA_POINTER_LEN + transfer_fee_len)
+    } else {
+        (TOKEN_PROGRAM_ID, 82)
+    };
+    let mint_keypair = Keypair::new();
+    let create_mint_ix = solana_sdk::system_instruction::create_account(
+        &fee_payer.pubkey(),
+        &mint_keypair.pubkey(),
+        Rent::default().minimum_balance(mint_len),
+        mint_len as u64,
+        &token_program,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[create_mint_ix],
+        Some(&fee_payer.pubkey()),
+        &[fee_payer, &mint_keypair],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let mint = mint_keypair.pubkey();
+    let (bonding_curve, _) = derive_pda(&[b"x_token", mint.as_ref()], program_id);
+    let (treasury, _) = derive_pda(&[b"treasury", mint.as_ref()], program_id);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 1_000_000_000).unwrap();
+    let authority_ata = spl_associated_token_account::get_associated_token_address_with_program_id(
+        &authority.pubkey(),
+        &mint,
+        &token_program,
+    );
+
+    let mut data = match options.compact_version {
+        Some(version) => compact_initialize_data(version, &authority.pubkey(), &options),
+        None => fixed_initialize_data(&authority.pubkey(), &options),
+    };
+    if let Some(version) = options.fixed_version {
+        let version_offset = 1 + 351; // discriminator and the original fields
+        if version == 1 {
+            data.truncate(version_offset);
+        } else {
+            data[version_offset] = version;
+        }
+    }
+
+    let mut ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(authority.pubkey(), true), // authority
+            AccountMeta::new(bonding_curve, false),               // bonding_curve
+            AccountMeta::new(mint, false),                        // mint
+            AccountMeta::new(treasury, false),                    // treasury
+            AccountMeta::new(authority_ata, false),               // authority_token_account
+            AccountMeta::new(fee_payer.pubkey(), true),           // payer
+            AccountMeta::new_readonly(system_program::ID, false), // system_program
+            AccountMeta::new_readonly(token_program, false),      // token_program
+            AccountMeta::new_readonly(ATA_PROGRAM_ID, false),     // associated_token_program
+            AccountMeta::new_readonly(solana_sdk::sysvar::rent::ID, false), // rent
+            AccountMeta::new(authority.pubkey(), false),          // fee_recipient_account
+            AccountMeta::new(derive_metadata_pda(&mint), false),  // metadata_account
+            AccountMeta::new_readonly(METAPLEX_PROGRAM_ID, false), // metaplex_program
+            AccountMeta::new_readonly(config, false),             // config
+        ],
+        data,
+    };
+    if let Some(protocol_fee_recipient) = options.protocol_fee_recipient {
+        ix.accounts.push(AccountMeta::new(protocol_fee_recipient, false));
+    }
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&fee_payer.pubkey()),
+        &[fee_payer, &authority],
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx).ok()?;
+
+    Some(Launch {
+        mint,
+        authority,
+        bonding_curve,
+        treasury,
+        config,
+        token_program,
+    })
+}
+
+// Initialize data in the fixed-size encoding
+fn fixed_initialize_data(authority: &Pubkey, options: &LaunchOptions) -> Vec<u8> {
+    let mut data = vec![0u8]; // Initialize discriminator
+    data.push(9); // decimals
+    data.push(0); // curve_type (linear)
+    data.extend_from_slice(&100u16.to_le_bytes()); // fee_basis_points
+    data.extend_from_slice(&[0u8; 32]); // owner (empty)
+    data.extend_from_slice(&1_000_000u64.to_le_bytes()); // base_price
+    data.extend_from_slice(&1_000u64.to_le_bytes()); // slope
+    data.extend_from_slice(&1_000_000_000_000u64.to_le_bytes()); // max_supply
+    data.extend_from_slice(&authority.to_bytes()); // fee_recipient
+    data.extend_from_slice(&0u64.to_le_bytes()); // initial_buy_amount
+    data.extend_from_slice(&0u64.to_le_bytes()); // initial_max_sol
+    data.extend_from_slice(&[0u8; 32]); // token_name (empty)
+    data.extend_from_slice(&[0u8; 10]); // token_symbol (empty)
+    data.extend_from_slice(&[0u8; 200]); // token_uri (empty)
+    data.push(2); // version
+    data.extend_from_slice(&options.seller_fee_basis_points.to_le_bytes()); // seller_fee_basis_points
+    data.push(options.creator_share); // creator_share
+    data.extend_from_slice(options.collection.as_ref()); // collection
+    data.extend_from_slice(&options.transfer_fee_basis_points.to_le_bytes()); // transfer_fee_basis_points
+    data.extend_from_slice(&options.maximum_transfer_fee.to_le_bytes()); // maximum_transfer_fee
+    data.extend_from_slice(&CREATOR_ALLOCATION.to_le_bytes()); // creator_allocation
+    data
+}
+
+// InitializeCompact data: length-prefixed strings and only the optional fields in use
+fn compact_initialize_data(version: u8, authority: &Pubkey, options: &LaunchOptions) -> Vec<u8> {
+    let mut data = vec![30u8, version]; // InitializeCompact discriminator, encoding version
+    data.push(9); // decimals
+    data.push(0); // curve_type (linear)
+    data.extend_from_slice(&100u16.to_le_bytes()); // fee_basis_points
+    data.push(0); // owner (empty)
+    data.extend_from_slice(&1_000_000u64.to_le_bytes()); // base_price
+    data.extend_from_slice(&1_000u64.to_le_bytes()); // slope
+    data.extend_from_slice(&1_000_000_000_000u64.to_le_bytes()); // max_supply
+    data.extend_from_slice(&CREATOR_ALLOCATION.to_le_bytes()); // creator_allocation
+    data.push(1); // fee_recipient present
+    data.extend_from_slice(&authority.to_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes()); // initial_buy_amount
+    data.extend_from_slice(&0u64.to_le_bytes()); // initial_max_sol
+    for field in ["XT", "XT", "https://x.test/xt.json"] {
+        data.push(field.len() as u8); // token_name, token_symbol, token_uri
+        data.extend_from_slice(field.as_bytes());
+    }
+    if options.seller_fee_basis_points != 0 || options.creator_share != 0 {
+        data.push(1); // royalty present
+        data.extend_from_slice(&options.seller_fee_basis_points.to_le_bytes());
+        data.push(options.creator_share);
+    } else {
+        data.push(0);
+    }
+    if options.collection != Pubkey::default() {
+        data.push(1); // collection present
+        data.extend_from_slice(options.collection.as_ref());
+    } else {
+        data.push(0);
+    }
+    if options.transfer_fee_basis_points != 0 {
+        data.push(1); // transfer fee present
+        data.extend_from_slice(&options.transfer_fee_basis_points.to_le_bytes());
+        data.extend_from_slice(&options.maximum_transfer_fee.to_le_bytes());
+    } else {
+        data.push(0);
+    }
+    data
+}
+
+// Helper function to build a BuyTokens instruction for `buyer`
+fn buy_tokens_ix(
+    program_id: &Pubkey,
+    launch: &Launch,
+    buyer: &Pubkey,
+    token_amount: u64,
+    max_sol_amount: u64,
+) -> Instruction {
+    let buyer_ata = launch.ata(buyer);
+    let mut data = vec![1u8]; // BuyTokens discriminator
+    data.extend_from_slice(&token_amount.to_le_bytes());
+    data.extend_from_slice(&max_sol_amount.to_le_bytes());
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*buyer, true),                          // buyer
+            AccountMeta::new(launch.bonding_curve, false),           // bonding_curve
+            AccountMeta::new(launch.mint, false),                    // mint
+            AccountMeta::new(buyer_ata, false),                      // buyer_token_account
+            AccountMeta::new(launch.treasury, false),                // treasury
+            AccountMeta::new(launch.authority.pubkey(), false),      // fee_recipient
+            AccountMeta::new_readonly(system_program::ID, false),    // system_program
+            AccountMeta::new_readonly(launch.token_program, false),  // token_program
+            AccountMeta::new_readonly(ATA_PROGRAM_ID, false),        // associated_token_program
+            AccountMeta::new_readonly(launch.config, false),         // config
+        ],
+        data,
+    }
+}
+
+// Helper function to build a SellTokens instruction for `seller`
+fn sell_tokens_ix(
+    program_id: &Pubkey,
+    launch: &Launch,
+    seller: &Pubkey,
+    token_amount: u64,
+    min_sol_amount: u64,
+) -> Instruction {
+    let seller_ata = launch.ata(seller);
+    let mut data = vec![2u8]; // SellTokens discriminator
+    data.extend_from_slice(&token_amount.to_le_bytes());
+    data.extend_from_slice(&min_sol_amount.to_le_bytes());
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*seller, true),                         // seller
+            AccountMeta::new(launch.bonding_curve, false),           // bonding_curve
+            AccountMeta::new(launch.mint, false),                    // mint
+            AccountMeta::new(seller_ata, false),                     // seller_token_account
+            AccountMeta::new(launch.treasury, false),                // treasury
+            AccountMeta::new(launch.authority.pubkey(), false),      // fee_recipient
+            AccountMeta::new_readonly(launch.token_program, false),  // token_program
+            AccountMeta::new_readonly(system_program::ID, false),    // system_program
+            AccountMeta::new_readonly(launch.config, false),         // config
+        ],
+        data,
+    }
+}
+
+// Helper function to send an instruction signed by the fee payer and `signers`
+fn send_signed(
+    svm: &mut LiteSVM,
+    fee_payer: &Keypair,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> bool {
+    let mut all_signers = vec![fee_payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&fee_payer.pubkey()),
+        &all_signers,
+        svm.latest_blockhash(),
+    );
+    let result = svm.send_transaction(tx).is_ok();
+    svm.expire_blockhash();
+    result
+}
+
 const PROGRAM_ID: &str = "ASXm2vSkEpLKQ3YnpdCEbhADQw86gefgFQi5DbyVZonL";
 
 fn setup() -> (LiteSVM, Keypair, Pubkey) {
@@ -338,6 +686,7 @@ fn test_multiple_transactions() {
 #[test]
 fn initialize_success_path() {
     let (mut svm, fee_payer, program_id) = setup();
+    let config = initialize_config(&mut svm, &fee_payer, &program_id);
 
     // 1. Create mint account properly
     let mint_keypair = Keypair::new();
@@ -394,6 +743,13 @@ fn initialize_success_path() {
     data.extend_from_slice(&[0u8; 32]); // token_name (empty)
     data.extend_from_slice(&[0u8; 10]); // token_symbol (empty)
     data.extend_from_slice(&[0u8; 200]); // token_uri (empty)
+    data.push(2); // version
+    data.extend_from_slice(&0u16.to_le_bytes()); // seller_fee_basis_points
+    data.push(0); // creator_share (no creators)
+    data.extend_from_slice(&[0u8; 32]); // collection (none)
+    data.extend_from_slice(&0u16.to_le_bytes()); // transfer_fee_basis_points (none)
+    data.extend_from_slice(&0u64.to_le_bytes()); // maximum_transfer_fee
+    data.extend_from_slice(&0u64.to_le_bytes()); // creator_allocation
 
     // 6. Create instruction with all required accounts
     let accounts = vec![
@@ -462,6 +818,11 @@ fn initialize_success_path() {
             is_signer: false,
             is_writable: false,
         }, // metaplex_program
+        AccountMeta {
+            pubkey: config,
+            is_signer: false,
+            is_writable: false,
+        }, // config
     ];
 
     let ix = Instruction {
@@ -921,3 +1282,2731 @@ fn test_wrong_program_id() {
         "Transaction should fail with wrong program ID"
     );
 }
+
+#[test]
+fn initialize_config_success_path() {
+    let (mut svm, fee_payer, program_id) = setup();
+
+    let config = initialize_config(&mut svm, &fee_payer, &program_id);
+
+    let account = svm.get_account(&config).unwrap();
+    assert_eq!(account.owner, program_id);
+    assert_eq!(&account.data[0..32], fee_payer.pubkey().as_ref()); // admin
+    assert_eq!(&account.data[32..64], fee_payer.pubkey().as_ref()); // fee_recipient
+    assert_eq!(&account.data[64..72], &84_000_000_000u64.to_le_bytes()); // sol_cap_lamports
+    assert_eq!(&account.data[74..76], &1_000u16.to_le_bytes()); // max_fee_basis_points
+    assert_eq!(account.data[78], 1); // is_initialized
+}
+
+#[test]
+fn initialize_config_twice_should_fail() {
+    let (mut svm, fee_payer, program_id) = setup();
+
+    let config = initialize_config(&mut svm, &fee_payer, &program_id);
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(fee_payer.pubkey(), true),
+            AccountMeta::new(config, false),
+            AccountMeta::new(fee_payer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: initialize_config_data(&fee_payer.pubkey(), 1_000_000_000, 0, 500, 0, 0),
+    };
+    send_ix_and_check(&mut svm, &fee_payer, ix, false);
+}
+
+#[test]
+fn initialize_config_with_invalid_fee_bounds_should_fail() {
+    let (mut svm, fee_payer, program_id) = setup();
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(fee_payer.pubkey(), true),
+            AccountMeta::new(derive_config_pda(&program_id), false),
+            AccountMeta::new(fee_payer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        // min fee above max fee
+        data: initialize_config_data(&fee_payer.pubkey(), 84_000_000_000, 500, 100, 0, 0),
+    };
+    send_ix_and_check(&mut svm, &fee_payer, ix, false);
+}
+
+#[test]
+fn update_config_by_non_admin_should_fail() {
+    let (mut svm, fee_payer, program_id) = setup();
+
+    let config = initialize_config(&mut svm, &fee_payer, &program_id);
+
+    let attacker = Keypair::new();
+    svm.airdrop(&attacker.pubkey(), 1_000_000_000).unwrap();
+
+    let mut data = vec![6u8]; // UpdateConfig discriminator
+    data.extend_from_slice(&attacker.pubkey().to_bytes()); // new_admin
+    data.extend_from_slice(&initialize_config_data(&attacker.pubkey(), 84_000_000_000, 0, 10_000, 0, 0)[1..]);
+    data.push(2); // version
+    data.extend_from_slice(&0i64.to_le_bytes()); // timelock_delay_seconds
+    data.extend_from_slice(&0u64.to_le_bytes()); // creation_fee_lamports
+    data.extend_from_slice(&0u16.to_le_bytes()); // creation_fee_rebate_basis_points
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(attacker.pubkey(), true),
+            AccountMeta::new(config, false),
+        ],
+        data,
+    };
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&attacker.pubkey()),
+        &[&attacker],
+        svm.latest_blockhash(),
+    );
+    assert!(svm.send_transaction(tx).is_err(), "Non-admin update should fail");
+
+    let account = svm.get_account(&config).unwrap();
+    assert_eq!(&account.data[0..32], fee_payer.pubkey().as_ref());
+}
+
+#[test]
+fn update_config_rejects_zero_admin() {
+    let (mut svm, fee_payer, program_id) = setup();
+
+    let config = initialize_config(&mut svm, &fee_payer, &program_id);
+
+    let mut data = vec![6u8]; // UpdateConfig discriminator
+    data.extend_from_slice(&[0u8; 32]); // new_admin
+    data.extend_from_slice(&initialize_config_data(&fee_payer.pubkey(), 84_000_000_000, 0, 1_000, 0, 0)[1..]);
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(fee_payer.pubkey(), true),
+            AccountMeta::new(config, false),
+        ],
+        data,
+    };
+    send_ix_and_check(&mut svm, &fee_payer, ix, false);
+
+    let account = svm.get_account(&config).unwrap();
+    assert_eq!(&account.data[0..32], fee_payer.pubkey().as_ref());
+}
+
+#[test]
+fn update_config_pauses_protocol() {
+    let (mut svm, fee_payer, program_id) = setup();
+
+    let config = initialize_config(&mut svm, &fee_payer, &program_id);
+
+    let mut data = vec![6u8]; // UpdateConfig discriminator
+    data.extend_from_slice(&fee_payer.pubkey().to_bytes()); // new_admin
+    data.extend_from_slice(&initialize_config_data(&fee_payer.pubkey(), 84_000_000_000, 0, 1_000, 0, 1)[1..]);
+    data.push(2); // version
+    data.extend_from_slice(&0i64.to_le_bytes()); // timelock_delay_seconds
+    data.extend_from_slice(&0u64.to_le_bytes()); // creation_fee_lamports
+    data.extend_from_slice(&0u16.to_le_bytes()); // creation_fee_rebate_basis_points
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(fee_payer.pubkey(), true),
+            AccountMeta::new(config, false),
+        ],
+        data,
+    };
+    send_ix_and_check(&mut svm, &fee_payer, ix, true);
+
+    let account = svm.get_account(&config).unwrap();
+    assert_eq!(account.data[77], 1); // paused
+}
+
+#[test]
+fn update_config_original_data_keeps_later_settings() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let config = initialize_config(&mut svm, &fee_payer, &program_id);
+    set_timelock_delay(&mut svm, &fee_payer, &program_id, 60);
+
+    // the original data ends after `paused`, before the version byte
+    let mut data = vec![6u8]; // UpdateConfig discriminator
+    data.extend_from_slice(&fee_payer.pubkey().to_bytes()); // new_admin
+    data.extend_from_slice(&initialize_config_data(&fee_payer.pubkey(), 84_000_000_000, 0, 500, 0, 0)[1..]);
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(fee_payer.pubkey(), true),
+            AccountMeta::new(config, false),
+        ],
+        data: data.clone(),
+    };
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+
+    let account = svm.get_account(&config).unwrap();
+    assert_eq!(&account.data[74..76], &500u16.to_le_bytes()); // max_fee_basis_points
+    assert_eq!(&account.data[120..128], &60i64.to_le_bytes()); // timelock_delay_seconds
+
+    // full-length data must carry a known version
+    data.push(3); // version
+    data.extend_from_slice(&0i64.to_le_bytes()); // timelock_delay_seconds
+    data.extend_from_slice(&0u64.to_le_bytes()); // creation_fee_lamports
+    data.extend_from_slice(&0u16.to_le_bytes()); // creation_fee_rebate_basis_points
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(fee_payer.pubkey(), true),
+            AccountMeta::new(config, false),
+        ],
+        data,
+    };
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[]));
+}
+
+// Helper function to build UpdateCurveConfig instruction data
+fn update_curve_config_data(
+    fee_basis_points: u16,
+    fee_recipient: &Pubkey,
+    base_price: u64,
+    max_supply: u64,
+) -> Vec<u8> {
+    let mut data = vec![7u8]; // UpdateCurveConfig discriminator
+    data.extend_from_slice(&fee_basis_points.to_le_bytes());
+    data.push(0); // curve_type (linear)
+    data.extend_from_slice(&fee_recipient.to_bytes());
+    data.extend_from_slice(&base_price.to_le_bytes());
+    data.extend_from_slice(&1_000u64.to_le_bytes()); // slope
+    data.extend_from_slice(&max_supply.to_le_bytes());
+    data
+}
+
+#[test]
+fn update_curve_config_before_trading_success_path() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let new_recipient = Pubkey::new_unique();
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(launch.authority.pubkey(), true),
+            AccountMeta::new(launch.bonding_curve, false),
+            AccountMeta::new_readonly(launch.config, false),
+        ],
+        data: update_curve_config_data(50, &new_recipient, 2_000_000, 1_000_000_000_000),
+    };
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let account = svm.get_account(&launch.bonding_curve).unwrap();
+    assert_eq!(&account.data[64..96], new_recipient.as_ref()); // fee_recipient
+    assert_eq!(&account.data[152..160], &2_000_000u64.to_le_bytes()); // base_price
+    assert_eq!(&account.data[176..178], &50u16.to_le_bytes()); // fee_basis_points
+}
+
+#[test]
+fn update_curve_config_rejects_unpriced_curve_types_and_zero_recipient() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+
+    let update = |data: Vec<u8>| Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(launch.authority.pubkey(), true),
+            AccountMeta::new(launch.bonding_curve, false),
+            AccountMeta::new_readonly(launch.config, false),
+        ],
+        data,
+    };
+    let with_curve_type = |curve_type: u8| {
+        let mut data = update_curve_config_data(50, &fee_payer.pubkey(), 1_000_000, 1_000_000_000_000);
+        data[3] = curve_type;
+        data
+    };
+
+    // exponential (1) and logarithmic (2) have no pricing to trade against
+    for curve_type in [1, 2, 4] {
+        assert!(!send_signed(&mut svm, &fee_payer, update(with_curve_type(curve_type)), &[&launch.authority]));
+    }
+    assert!(send_signed(&mut svm, &fee_payer, update(with_curve_type(3)), &[&launch.authority]));
+    assert_eq!(svm.get_account(&launch.bonding_curve).unwrap().data[178], 3); // curve_type
+
+    let data = update_curve_config_data(50, &Pubkey::default(), 1_000_000, 1_000_000_000_000);
+    assert!(!send_signed(&mut svm, &fee_payer, update(data), &[&launch.authority]));
+    assert_eq!(&svm.get_account(&launch.bonding_curve).unwrap().data[64..96], fee_payer.pubkey().as_ref());
+}
+
+#[test]
+fn update_curve_config_by_non_authority_should_fail() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(fee_payer.pubkey(), true),
+            AccountMeta::new(launch.bonding_curve, false),
+            AccountMeta::new_readonly(launch.config, false),
+        ],
+        data: update_curve_config_data(0, &fee_payer.pubkey(), 1_000_000, 1_000_000_000_000),
+    };
+    send_ix_and_check(&mut svm, &fee_payer, ix, false);
+}
+
+#[test]
+fn update_curve_config_after_trading_locks_pricing() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let fee_recipient = launch.authority.pubkey();
+
+    let ix = buy_tokens_ix(&program_id, &launch, &fee_payer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+
+    let update = |fee: u16, base_price: u64| Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(launch.authority.pubkey(), true),
+            AccountMeta::new(launch.bonding_curve, false),
+            AccountMeta::new_readonly(launch.config, false),
+        ],
+        data: update_curve_config_data(fee, &fee_recipient, base_price, 1_000_000_000_000),
+    };
+
+    // Pricing change rejected
+    assert!(!send_signed(&mut svm, &fee_payer, update(100, 2_000_000), &[&launch.authority]));
+    // Fee increase rejected
+    assert!(!send_signed(&mut svm, &fee_payer, update(200, 1_000_000), &[&launch.authority]));
+    // Fee decrease allowed
+    assert!(send_signed(&mut svm, &fee_payer, update(50, 1_000_000), &[&launch.authority]));
+
+    let account = svm.get_account(&launch.bonding_curve).unwrap();
+    assert_eq!(&account.data[176..178], &50u16.to_le_bytes());
+}
+
+#[test]
+fn buy_tokens_with_wrong_fee_recipient_should_fail() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+
+    let mut ix = buy_tokens_ix(&program_id, &launch, &fee_payer.pubkey(), 1_000_000_000, 1_000_000_000);
+    ix.accounts[5] = AccountMeta::new(Pubkey::new_unique(), false);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[]));
+}
+
+// Helper function to build an authority handover instruction (ProposeAuthority,
+// AcceptAuthority or CancelAuthorityTransfer)
+fn authority_ix(
+    program_id: &Pubkey,
+    discriminator: u8,
+    signer: &Pubkey,
+    bonding_curve: &Pubkey,
+    new_authority: Option<&Pubkey>,
+) -> Instruction {
+    let mut data = vec![discriminator];
+    if let Some(new_authority) = new_authority {
+        data.extend_from_slice(&new_authority.to_bytes());
+    }
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new(*bonding_curve, false),
+            AccountMeta::new_readonly(derive_config_pda(program_id), false),
+        ],
+        data,
+    }
+}
+
+#[test]
+fn authority_transfer_success_path() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let new_authority = Keypair::new();
+
+    let propose = authority_ix(
+        &program_id,
+        8,
+        &launch.authority.pubkey(),
+        &launch.bonding_curve,
+        Some(&new_authority.pubkey()),
+    );
+    assert!(send_signed(&mut svm, &fee_payer, propose, &[&launch.authority]));
+
+    // Proposal alone does not move control
+    let account = svm.get_account(&launch.bonding_curve).unwrap();
+    assert_eq!(&account.data[0..32], launch.authority.pubkey().as_ref());
+    assert_eq!(&account.data[181..213], new_authority.pubkey().as_ref()); // pending_authority
+
+    let accept = authority_ix(&program_id, 9, &new_authority.pubkey(), &launch.bonding_curve, None);
+    assert!(send_signed(&mut svm, &fee_payer, accept, &[&new_authority]));
+
+    let account = svm.get_account(&launch.bonding_curve).unwrap();
+    assert_eq!(&account.data[0..32], new_authority.pubkey().as_ref());
+    assert_eq!(&account.data[181..213], &[0u8; 32]);
+}
+
+#[test]
+fn authority_transfer_moves_roles_of_the_old_key() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let old_authority = launch.authority.pubkey();
+    let new_authority = Keypair::new();
+    svm.airdrop(&new_authority.pubkey(), 1_000_000_000).unwrap();
+
+    // a role already handed to someone else stays with them
+    let operator = Keypair::new();
+    let ix = set_role_ix(&program_id, &old_authority, &launch.bonding_curve, 1, &operator.pubkey());
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let ix = authority_ix(&program_id, 8, &old_authority, &launch.bonding_curve, Some(&new_authority.pubkey()));
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    let ix = authority_ix(&program_id, 9, &new_authority.pubkey(), &launch.bonding_curve, None);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&new_authority]));
+
+    let account = svm.get_account(&launch.bonding_curve).unwrap();
+    assert_eq!(&account.data[216..248], operator.pubkey().as_ref()); // operator
+    assert_eq!(&account.data[248..280], new_authority.pubkey().as_ref()); // fee_manager
+    assert_eq!(&account.data[280..312], new_authority.pubkey().as_ref()); // minter
+
+    // the old key lost AdminMint and UpdateCurveConfig
+    let recipient_ata = create_ata(&mut svm, &fee_payer, &fee_payer.pubkey(), &launch.mint);
+    let ix = admin_mint_ix(&program_id, &launch, &old_authority, &recipient_ata, 1_000);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let update_ix = |authority: &Pubkey| Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(launch.bonding_curve, false),
+            AccountMeta::new_readonly(launch.config, false),
+        ],
+        data: update_curve_config_data(50, authority, 1_000_000, 1_000_000_000_000),
+    };
+    assert!(!send_signed(&mut svm, &fee_payer, update_ix(&old_authority), &[&launch.authority]));
+
+    let ix = admin_mint_ix(&program_id, &launch, &new_authority.pubkey(), &recipient_ata, 1_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&new_authority]));
+    assert!(send_signed(&mut svm, &fee_payer, update_ix(&new_authority.pubkey()), &[&new_authority]));
+}
+
+#[test]
+fn authority_transfer_hijack_attempts_should_fail() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let new_authority = Keypair::new();
+    let attacker = Keypair::new();
+
+    // Attacker cannot propose themselves
+    let propose = authority_ix(
+        &program_id,
+        8,
+        &attacker.pubkey(),
+        &launch.bonding_curve,
+        Some(&attacker.pubkey()),
+    );
+    assert!(!send_signed(&mut svm, &fee_payer, propose, &[&attacker]));
+
+    // Nothing pending: accept is rejected
+    let accept = authority_ix(&program_id, 9, &attacker.pubkey(), &launch.bonding_curve, None);
+    assert!(!send_signed(&mut svm, &fee_payer, accept, &[&attacker]));
+
+    let propose = authority_ix(
+        &program_id,
+        8,
+        &launch.authority.pubkey(),
+        &launch.bonding_curve,
+        Some(&new_authority.pubkey()),
+    );
+    assert!(send_signed(&mut svm, &fee_payer, propose, &[&launch.authority]));
+
+    // Only the proposed key can accept
+    let accept = authority_ix(&program_id, 9, &attacker.pubkey(), &launch.bonding_curve, None);
+    assert!(!send_signed(&mut svm, &fee_payer, accept, &[&attacker]));
+
+    // Attacker cannot cancel either
+    let cancel = authority_ix(&program_id, 10, &attacker.pubkey(), &launch.bonding_curve, None);
+    assert!(!send_signed(&mut svm, &fee_payer, cancel, &[&attacker]));
+
+    let account = svm.get_account(&launch.bonding_curve).unwrap();
+    assert_eq!(&account.data[0..32], launch.authority.pubkey().as_ref());
+    assert_eq!(&account.data[181..213], new_authority.pubkey().as_ref());
+}
+
+#[test]
+fn authority_transfer_cancel_path() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let new_authority = Keypair::new();
+
+    let propose = authority_ix(
+        &program_id,
+        8,
+        &launch.authority.pubkey(),
+        &launch.bonding_curve,
+        Some(&new_authority.pubkey()),
+    );
+    assert!(send_signed(&mut svm, &fee_payer, propose, &[&launch.authority]));
+
+    let cancel = authority_ix(&program_id, 10, &launch.authority.pubkey(), &launch.bonding_curve, None);
+    assert!(send_signed(&mut svm, &fee_payer, cancel, &[&launch.authority]));
+
+    // Cancelled proposal can no longer be accepted
+    let accept = authority_ix(&program_id, 9, &new_authority.pubkey(), &launch.bonding_curve, None);
+    assert!(!send_signed(&mut svm, &fee_payer, accept, &[&new_authority]));
+
+    let account = svm.get_account(&launch.bonding_curve).unwrap();
+    assert_eq!(&account.data[0..32], launch.authority.pubkey().as_ref());
+    assert_eq!(&account.data[181..213], &[0u8; 32]);
+}
+
+// Helper function to create an associated token account for `owner`
+fn create_ata(svm: &mut LiteSVM, fee_payer: &Keypair, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    let token_program = svm.get_account(mint).unwrap().owner;
+    let ix = spl_associated_token_account::instruction::create_associated_token_account(
+        &fee_payer.pubkey(),
+        owner,
+        mint,
+        &token_program,
+    );
+    assert!(send_signed(svm, fee_payer, ix, &[]));
+    spl_associated_token_account::get_associated_token_address_with_program_id(owner, mint, &token_program)
+}
+
+// Helper function to build an AdminMint instruction
+fn admin_mint_ix(
+    program_id: &Pubkey,
+    launch: &Launch,
+    minter: &Pubkey,
+    recipient_token_account: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let mut data = vec![4u8]; // AdminMint discriminator
+    data.extend_from_slice(&amount.to_le_bytes());
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*minter, true),              // authority
+            AccountMeta::new(launch.bonding_curve, false),         // bonding_curve
+            AccountMeta::new(launch.mint, false),                  // mint
+            AccountMeta::new(*recipient_token_account, false),     // recipient_token_account
+            AccountMeta::new_readonly(launch.token_program, false), // token_program
+            AccountMeta::new_readonly(launch.config, false),       // config
+        ],
+        data,
+    }
+}
+
+// Helper function to build a WithdrawReserves instruction
+fn withdraw_reserves_ix(
+    program_id: &Pubkey,
+    launch: &Launch,
+    authority: &Pubkey,
+    recipient: &Pubkey,
+    lamports: u64,
+) -> Instruction {
+    let mut data = vec![3u8]; // WithdrawReserves discriminator
+    data.extend_from_slice(&lamports.to_le_bytes());
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),           // authority
+            AccountMeta::new(launch.bonding_curve, false),         // bonding_curve
+            AccountMeta::new_readonly(launch.mint, false),         // mint
+            AccountMeta::new(launch.treasury, false),              // treasury
+            AccountMeta::new(*recipient, false),                   // recipient
+            AccountMeta::new_readonly(system_program::ID, false),  // system_program
+            AccountMeta::new_readonly(launch.config, false),       // config
+        ],
+        data,
+    }
+}
+
+// Helper function to build a SetRole instruction
+fn set_role_ix(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    bonding_curve: &Pubkey,
+    role: u8,
+    holder: &Pubkey,
+) -> Instruction {
+    let mut data = vec![11u8]; // SetRole discriminator
+    data.push(role);
+    data.extend_from_slice(&holder.to_bytes());
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(*bonding_curve, false),
+            AccountMeta::new_readonly(derive_config_pda(program_id), false),
+        ],
+        data,
+    }
+}
+
+#[test]
+fn set_role_moves_minter_permission() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let minter = Keypair::new();
+    let recipient_ata = create_ata(&mut svm, &fee_payer, &fee_payer.pubkey(), &launch.mint);
+
+    let ix = set_role_ix(&program_id, &launch.authority.pubkey(), &launch.bonding_curve, 3, &minter.pubkey());
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let account = svm.get_account(&launch.bonding_curve).unwrap();
+    assert_eq!(&account.data[280..312], minter.pubkey().as_ref()); // minter
+
+    // Previous holder (the owner) lost the minter role
+    let ix = admin_mint_ix(&program_id, &launch, &launch.authority.pubkey(), &recipient_ata, 1_000);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let ix = admin_mint_ix(&program_id, &launch, &minter.pubkey(), &recipient_ata, 1_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&minter]));
+}
+
+#[test]
+fn set_role_by_non_owner_should_fail() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let attacker = Keypair::new();
+
+    let ix = set_role_ix(&program_id, &attacker.pubkey(), &launch.bonding_curve, 3, &attacker.pubkey());
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&attacker]));
+
+    // Ownership cannot be reassigned through SetRole
+    let ix = set_role_ix(&program_id, &launch.authority.pubkey(), &launch.bonding_curve, 0, &attacker.pubkey());
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+}
+
+#[test]
+fn fee_manager_cannot_withdraw_reserves() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let fee_manager = Keypair::new();
+
+    let ix = set_role_ix(&program_id, &launch.authority.pubkey(), &launch.bonding_curve, 2, &fee_manager.pubkey());
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let ix = withdraw_reserves_ix(&program_id, &launch, &fee_manager.pubkey(), &fee_manager.pubkey(), 0);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&fee_manager]));
+
+    // the treasurer role is unassigned until the owner grants it
+    assert_eq!(&svm.get_account(&launch.bonding_curve).unwrap().data[344..376], &[0u8; 32]);
+    let ix = set_role_ix(&program_id, &launch.authority.pubkey(), &launch.bonding_curve, 4, &fee_manager.pubkey());
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    let ix = withdraw_reserves_ix(&program_id, &launch, &fee_manager.pubkey(), &fee_manager.pubkey(), 0);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&fee_manager]));
+}
+
+// Helper function to replace a launched curve with the account the original
+// (v1) Initialize wrote: 216 bytes, the admin in reserved[0..32], no version
+fn write_v1_curve(svm: &mut LiteSVM, program_id: &Pubkey, launch: &Launch, admin: &Pubkey) -> Vec<u8> {
+    let current = svm.get_account(&launch.bonding_curve).unwrap();
+    let u64_at = |offset: usize| u64::from_le_bytes(current.data[offset..offset + 8].try_into().unwrap());
+
+    let mut data = Vec::with_capacity(216);
+    data.extend_from_slice(launch.authority.pubkey().as_ref()); // authority
+    data.extend_from_slice(launch.mint.as_ref()); // token_mint
+    data.extend_from_slice(launch.authority.pubkey().as_ref()); // fee_recipient
+    data.extend_from_slice(&[0u8; 32]); // owner (empty)
+    data.extend_from_slice(&u64_at(128).to_le_bytes()); // sol_reserve
+    data.extend_from_slice(&u64_at(136).to_le_bytes()); // token_reserve
+    data.extend_from_slice(&u64_at(144).to_le_bytes()); // total_supply
+    data.extend_from_slice(&1_000_000u64.to_le_bytes()); // base_price
+    data.extend_from_slice(&1_000u64.to_le_bytes()); // slope
+    data.extend_from_slice(&1_000_000_000_000u64.to_le_bytes()); // max_supply
+    data.extend_from_slice(&100u16.to_le_bytes()); // fee_basis_points
+    data.push(0); // curve_type (linear)
+    data.push(1); // is_initialized
+    data.push(derive_pda(&[b"x_token", launch.mint.as_ref()], program_id).1); // bump
+    data.extend_from_slice(admin.as_ref()); // reserved[0..32]: admin
+    data.extend_from_slice(&[0u8; 3]); // reserved[32..35]
+    assert_eq!(data.len(), 216);
+
+    let mut account = current;
+    account.data = data.clone();
+    account.lamports = Rent::default().minimum_balance(216);
+    svm.set_account(launch.bonding_curve, account).unwrap();
+    data
+}
+
+fn migrate_account_ix(program_id: &Pubkey, payer: &Pubkey, launch: &Launch) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(launch.bonding_curve, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(derive_config_pda(program_id), false),
+            AccountMeta::new_readonly(launch.mint, false),
+        ],
+        data: vec![12u8], // MigrateAccount discriminator
+    }
+}
+
+#[test]
+fn migrate_v1_account_success_path() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+
+    let ix = buy_tokens_ix(&program_id, &launch, &fee_payer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+
+    let authority = launch.authority.pubkey();
+    let admin = Keypair::new();
+    let v1 = write_v1_curve(&mut svm, &program_id, &launch, &admin.pubkey());
+
+    // v1 accounts are rejected until migrated
+    let ix = buy_tokens_ix(&program_id, &launch, &fee_payer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[]));
+
+    let ix = migrate_account_ix(&program_id, &fee_payer.pubkey(), &launch);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+
+    let after = svm.get_account(&launch.bonding_curve).unwrap();
+    assert_eq!(after.data.len(), 512);
+    assert_eq!(&after.data[..181], &v1[..181]); // v1 curve fields preserved
+    assert_eq!(&after.data[181..213], &[0u8; 32]); // admin bytes no longer read as a pending authority
+    assert_eq!(after.data[213], 2); // version
+    assert_eq!(&after.data[216..248], authority.as_ref()); // operator
+    assert_eq!(&after.data[248..280], authority.as_ref()); // fee_manager
+    assert_eq!(&after.data[280..312], admin.pubkey().as_ref()); // minter: the v1 admin
+    assert_eq!(&after.data[344..376], admin.pubkey().as_ref()); // treasurer: the v1 admin
+    assert!(after.lamports >= Rent::default().minimum_balance(after.data.len()));
+
+    // the v1 admin keeps its withdraw rights next to the authority
+    let ix = withdraw_reserves_ix(&program_id, &launch, &authority, &authority, 0);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    let ix = withdraw_reserves_ix(&program_id, &launch, &admin.pubkey(), &admin.pubkey(), 0);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&admin]));
+
+    let ix = buy_tokens_ix(&program_id, &launch, &fee_payer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+    let ix = migrate_account_ix(&program_id, &fee_payer.pubkey(), &launch);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[]));
+}
+
+#[test]
+fn migrated_curve_accounts_for_legacy_admin_mints() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let authority = launch.authority.pubkey();
+
+    // the v1 admin minted outside the curve, which sold nothing
+    let recipient_ata = create_ata(&mut svm, &fee_payer, &fee_payer.pubkey(), &launch.mint);
+    let ix = admin_mint_ix(&program_id, &launch, &authority, &recipient_ata, 5_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    write_v1_curve(&mut svm, &program_id, &launch, &authority);
+
+    // the mint must be the curve's own
+    let other = launch_token(&mut svm, &fee_payer, &program_id);
+    let mut ix = migrate_account_ix(&program_id, &fee_payer.pubkey(), &launch);
+    ix.accounts[4] = AccountMeta::new_readonly(other.mint, false);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[]));
+
+    let ix = migrate_account_ix(&program_id, &fee_payer.pubkey(), &launch);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+    let data = svm.get_account(&launch.bonding_curve).unwrap().data;
+    assert_eq!(&data[312..320], &0u64.to_le_bytes()); // creator_allocation
+    assert_eq!(&data[320..328], &5_000u64.to_le_bytes()); // creator_minted: the legacy mints
+
+    // those tokens keep the curve open and AdminMint closed
+    let ix = close_curve_ix(&program_id, &launch, &authority, &authority);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    let ix = admin_mint_ix(&program_id, &launch, &authority, &recipient_ata, 1);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+}
+
+#[test]
+fn migrate_current_account_should_fail() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+
+    let ix = migrate_account_ix(&program_id, &fee_payer.pubkey(), &launch);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[]));
+}
+
+fn pause_ix(program_id: &Pubkey, discriminator: u8, authority: &Pubkey, bonding_curve: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new(*bonding_curve, false),
+            AccountMeta::new_readonly(derive_config_pda(program_id), false),
+        ],
+        data: vec![discriminator], // PauseCurve (13) or ResumeCurve (14)
+    }
+}
+
+#[test]
+fn paused_curve_rejects_trades_without_touching_state() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let buyer = Keypair::new();
+    svm.airdrop(&buyer.pubkey(), 2_000_000_000).unwrap();
+    let operator = Keypair::new();
+
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+
+    let ix = set_role_ix(&program_id, &launch.authority.pubkey(), &launch.bonding_curve, 1, &operator.pubkey());
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let ix = pause_ix(&program_id, 13, &operator.pubkey(), &launch.bonding_curve);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&operator]));
+
+    let curve_before = svm.get_account(&launch.bonding_curve).unwrap();
+    assert_eq!(curve_before.data[214], 1); // paused
+    let treasury_before = svm.get_account(&launch.treasury).unwrap().lamports;
+    let buyer_before = svm.get_account(&buyer.pubkey()).unwrap().lamports;
+
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+
+    let ix = sell_tokens_ix(&program_id, &launch, &buyer.pubkey(), 500_000_000, 0);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+
+    let buyer_ata = spl_associated_token_account::get_associated_token_address(&buyer.pubkey(), &launch.mint);
+    let ix = admin_mint_ix(&program_id, &launch, &launch.authority.pubkey(), &buyer_ata, 1_000);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    assert_eq!(svm.get_account(&launch.bonding_curve).unwrap().data, curve_before.data);
+    assert_eq!(svm.get_account(&launch.treasury).unwrap().lamports, treasury_before);
+    assert_eq!(svm.get_account(&buyer.pubkey()).unwrap().lamports, buyer_before);
+
+    let ix = pause_ix(&program_id, 14, &operator.pubkey(), &launch.bonding_curve);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&operator]));
+
+    let ix = sell_tokens_ix(&program_id, &launch, &buyer.pubkey(), 500_000_000, 0);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+}
+
+#[test]
+fn pause_by_non_operator_should_fail() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let attacker = Keypair::new();
+
+    let ix = pause_ix(&program_id, 13, &attacker.pubkey(), &launch.bonding_curve);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&attacker]));
+
+    assert_eq!(svm.get_account(&launch.bonding_curve).unwrap().data[214], 0);
+}
+
+// Helper function to build a SetEmergencyHalt instruction
+fn set_emergency_halt_ix(program_id: &Pubkey, guardian: &Pubkey, halted: u8) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*guardian, true),
+            AccountMeta::new(derive_config_pda(program_id), false),
+        ],
+        data: vec![15u8, halted], // SetEmergencyHalt discriminator
+    }
+}
+
+#[test]
+fn emergency_halt_blocks_buys_but_allows_sells() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let buyer = Keypair::new();
+    svm.airdrop(&buyer.pubkey(), 2_000_000_000).unwrap();
+
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+
+    // the config admin starts out as guardian
+    let ix = set_emergency_halt_ix(&program_id, &fee_payer.pubkey(), 1);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+    assert_eq!(svm.get_account(&launch.config).unwrap().data[112], 1); // emergency_halt
+
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+
+    let ix = set_role_ix(&program_id, &launch.authority.pubkey(), &launch.bonding_curve, 1, &buyer.pubkey());
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let ix = withdraw_reserves_ix(&program_id, &launch, &launch.authority.pubkey(), &launch.authority.pubkey(), 1);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    // users can still exit
+    let ix = sell_tokens_ix(&program_id, &launch, &buyer.pubkey(), 500_000_000, 0);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+
+    let ix = set_emergency_halt_ix(&program_id, &fee_payer.pubkey(), 0);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+}
+
+#[test]
+fn emergency_halt_only_by_guardian() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let config = initialize_config(&mut svm, &fee_payer, &program_id);
+    let guardian = Keypair::new();
+
+    let mut data = vec![16u8]; // SetGuardian discriminator
+    data.extend_from_slice(&guardian.pubkey().to_bytes());
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(fee_payer.pubkey(), true),
+            AccountMeta::new(config, false),
+        ],
+        data,
+    };
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+    assert_eq!(&svm.get_account(&config).unwrap().data[80..112], guardian.pubkey().as_ref());
+
+    // the admin is no longer guardian
+    let ix = set_emergency_halt_ix(&program_id, &fee_payer.pubkey(), 1);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[]));
+
+    let attacker = Keypair::new();
+    let ix = set_emergency_halt_ix(&program_id, &attacker.pubkey(), 1);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&attacker]));
+    assert_eq!(svm.get_account(&config).unwrap().data[112], 0);
+
+    let ix = set_emergency_halt_ix(&program_id, &guardian.pubkey(), 1);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&guardian]));
+    assert_eq!(svm.get_account(&config).unwrap().data[112], 1);
+}
+
+// Helper function to create a multisig PDA seeded by a fresh creation key
+fn create_multisig(
+    svm: &mut LiteSVM,
+    fee_payer: &Keypair,
+    program_id: &Pubkey,
+    threshold: u8,
+    members: &[Pubkey],
+) -> Option<Pubkey> {
+    let create_key = Keypair::new();
+    let multisig = derive_pda(&[b"multisig", create_key.pubkey().as_ref()], program_id).0;
+    let mut data = vec![17u8, threshold]; // CreateMultisig discriminator
+    for member in members {
+        data.extend_from_slice(&member.to_bytes());
+    }
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(create_key.pubkey(), true),
+            AccountMeta::new(multisig, false),
+            AccountMeta::new(fee_payer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+    send_signed(svm, fee_payer, ix, &[&create_key]).then_some(multisig)
+}
+
+// Turn a single-signer authority instruction into a multisig-approved one
+fn with_multisig_signers(mut ix: Instruction, members: &[&Keypair]) -> Instruction {
+    ix.accounts[0].is_signer = false;
+    for member in members {
+        ix.accounts.push(AccountMeta::new_readonly(member.pubkey(), true));
+    }
+    ix
+}
+
+#[test]
+fn multisig_authority_requires_threshold() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let members = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let keys: Vec<Pubkey> = members.iter().map(|m| m.pubkey()).collect();
+    let multisig = create_multisig(&mut svm, &fee_payer, &program_id, 2, &keys).unwrap();
+
+    let ix = buy_tokens_ix(&program_id, &launch, &fee_payer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+    svm.airdrop(&launch.treasury, 100_000_000).unwrap(); // surplus above the reserve
+
+    // hand the curve over to the multisig
+    let ix = authority_ix(&program_id, 8, &launch.authority.pubkey(), &launch.bonding_curve, Some(&multisig));
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let ix = authority_ix(&program_id, 9, &multisig, &launch.bonding_curve, None);
+    let ix = with_multisig_signers(ix, &[&members[0]]);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&members[0]]));
+
+    let ix = authority_ix(&program_id, 9, &multisig, &launch.bonding_curve, None);
+    let ix = with_multisig_signers(ix, &[&members[0], &members[2]]);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&members[0], &members[2]]));
+    assert_eq!(&svm.get_account(&launch.bonding_curve).unwrap().data[0..32], multisig.as_ref());
+
+    // the previous single-key authority lost access
+    let recipient = Keypair::new().pubkey();
+    let ix = withdraw_reserves_ix(&program_id, &launch, &launch.authority.pubkey(), &recipient, 100_000_000);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    // one approval, or the same member listed twice, is not enough
+    let ix = withdraw_reserves_ix(&program_id, &launch, &multisig, &recipient, 100_000_000);
+    let ix = with_multisig_signers(ix, &[&members[1], &members[1]]);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&members[1]]));
+
+    // a non-member signature does not count
+    let outsider = Keypair::new();
+    let ix = withdraw_reserves_ix(&program_id, &launch, &multisig, &recipient, 100_000_000);
+    let ix = with_multisig_signers(ix, &[&members[1], &outsider]);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&members[1], &outsider]));
+
+    let ix = withdraw_reserves_ix(&program_id, &launch, &multisig, &recipient, 100_000_000);
+    let ix = with_multisig_signers(ix, &[&members[1], &members[2]]);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&members[1], &members[2]]));
+    assert_eq!(svm.get_account(&recipient).unwrap().lamports, 100_000_000);
+}
+
+#[test]
+fn multisig_authority_pauses_and_updates_curve() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let members = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let keys: Vec<Pubkey> = members.iter().map(|m| m.pubkey()).collect();
+    let multisig = create_multisig(&mut svm, &fee_payer, &program_id, 2, &keys).unwrap();
+
+    let ix = authority_ix(&program_id, 8, &launch.authority.pubkey(), &launch.bonding_curve, Some(&multisig));
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    let ix = authority_ix(&program_id, 9, &multisig, &launch.bonding_curve, None);
+    let ix = with_multisig_signers(ix, &[&members[0], &members[1]]);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&members[0], &members[1]]));
+
+    // PauseCurve and ResumeCurve need the multisig threshold
+    let ix = with_multisig_signers(pause_ix(&program_id, 13, &multisig, &launch.bonding_curve), &[&members[0]]);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&members[0]]));
+    let ix = with_multisig_signers(pause_ix(&program_id, 13, &multisig, &launch.bonding_curve), &[&members[0], &members[2]]);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&members[0], &members[2]]));
+    assert_eq!(svm.get_account(&launch.bonding_curve).unwrap().data[214], 1); // paused
+
+    let ix = with_multisig_signers(pause_ix(&program_id, 14, &multisig, &launch.bonding_curve), &[&members[1]]);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&members[1]]));
+    let ix = with_multisig_signers(pause_ix(&program_id, 14, &multisig, &launch.bonding_curve), &[&members[1], &members[2]]);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&members[1], &members[2]]));
+    assert_eq!(svm.get_account(&launch.bonding_curve).unwrap().data[214], 0);
+
+    // UpdateCurveConfig once the multisig also holds the fee manager role
+    let ix = set_role_ix(&program_id, &multisig, &launch.bonding_curve, 2, &multisig);
+    let ix = with_multisig_signers(ix, &[&members[0], &members[1]]);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&members[0], &members[1]]));
+
+    let new_recipient = Pubkey::new_unique();
+    let update_ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(multisig, true),
+            AccountMeta::new(launch.bonding_curve, false),
+            AccountMeta::new_readonly(launch.config, false),
+        ],
+        data: update_curve_config_data(50, &new_recipient, 2_000_000, 1_000_000_000_000),
+    };
+    let ix = with_multisig_signers(update_ix.clone(), &[&members[2]]);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&members[2]]));
+    let ix = with_multisig_signers(update_ix, &[&members[0], &members[2]]);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&members[0], &members[2]]));
+    assert_eq!(&svm.get_account(&launch.bonding_curve).unwrap().data[64..96], new_recipient.as_ref());
+}
+
+#[test]
+fn withdrawals_are_limited_to_surplus_while_trading() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let authority = launch.authority.pubkey();
+    let buyer = Keypair::new();
+    svm.airdrop(&buyer.pubkey(), 2_000_000_000).unwrap();
+
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+    let sol_reserve = |svm: &LiteSVM| {
+        u64::from_le_bytes(svm.get_account(&launch.bonding_curve).unwrap().data[128..136].try_into().unwrap())
+    };
+    let reserve = sol_reserve(&svm);
+    assert!(reserve > 0);
+
+    // the reserve backing outstanding tokens cannot be taken
+    let ix = withdraw_reserves_ix(&program_id, &launch, &authority, &authority, 1);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    // a donation on top of the reserve is surplus; "withdraw all" takes only that
+    svm.airdrop(&launch.treasury, 50_000_000).unwrap();
+    let recipient = Keypair::new().pubkey();
+    let ix = withdraw_reserves_ix(&program_id, &launch, &authority, &recipient, 0);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(svm.get_account(&recipient).unwrap().lamports, 50_000_000);
+    assert_eq!(sol_reserve(&svm), reserve);
+
+    let ix = withdraw_reserves_ix(&program_id, &launch, &authority, &authority, 1);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    // once every curve token is sold back the rest is withdrawable, down to the rent floor
+    let ix = sell_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_000, 0);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+    let ix = withdraw_reserves_ix(&program_id, &launch, &authority, &authority, 0);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(sol_reserve(&svm), 0);
+    assert_eq!(svm.get_account(&launch.treasury).unwrap().lamports, Rent::default().minimum_balance(0));
+}
+
+#[test]
+fn graduated_curve_keeps_sells_open_and_reserve_locked() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let authority = launch.authority.pubkey();
+    let buyer = Keypair::new();
+    svm.airdrop(&buyer.pubkey(), 2_000_000_000).unwrap();
+
+    // leave room for two tokens on the curve
+    let mut curve = svm.get_account(&launch.bonding_curve).unwrap();
+    curve.data[168..176].copy_from_slice(&(CREATOR_ALLOCATION + 2_000_000_000).to_le_bytes()); // max_supply
+    svm.set_account(launch.bonding_curve, curve).unwrap();
+
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_000, 100_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_001, 100_000_000);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+    assert_eq!(svm.get_account(&launch.bonding_curve).unwrap().data[215], 0);
+
+    // graduation happens when the curve supply reaches its cap exactly
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_000, 100_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+    assert_eq!(svm.get_account(&launch.bonding_curve).unwrap().data[215], 1); // graduated
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1, 100_000_000);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+
+    // the reserve still backs the holders
+    let ix = withdraw_reserves_ix(&program_id, &launch, &authority, &authority, 1);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    let ix = sell_tokens_ix(&program_id, &launch, &buyer.pubkey(), 2_000_000_000, 0);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+    assert_eq!(token_balance(&svm, &launch.ata(&buyer.pubkey())), 0);
+}
+
+#[test]
+fn treasury_cannot_be_drained_below_rent_floor() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let authority = launch.authority.pubkey();
+    let buyer = Keypair::new();
+    svm.airdrop(&buyer.pubkey(), 2_000_000_000).unwrap();
+    let rent_floor = Rent::default().minimum_balance(0);
+
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+    let funded = svm.get_account(&launch.treasury).unwrap();
+
+    // a treasury holding only the reserve cannot pay the sale out of its rent
+    let mut short = funded.clone();
+    short.lamports -= rent_floor;
+    svm.set_account(launch.treasury, short).unwrap();
+    let ix = sell_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_000, 0);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+    svm.set_account(launch.treasury, funded).unwrap();
+
+    let ix = sell_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_000, 0);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+
+    // with the curve wound down the whole balance is still out of reach
+    let balance = svm.get_account(&launch.treasury).unwrap().lamports;
+    assert!(balance >= rent_floor);
+    let ix = withdraw_reserves_ix(&program_id, &launch, &authority, &authority, balance);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let ix = withdraw_reserves_ix(&program_id, &launch, &authority, &authority, 0);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(svm.get_account(&launch.treasury).unwrap().lamports, rent_floor);
+
+    // and trading keeps working on the surviving treasury
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+}
+
+#[test]
+fn create_multisig_with_invalid_threshold_should_fail() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let keys = [Keypair::new().pubkey(), Keypair::new().pubkey()];
+
+    assert!(create_multisig(&mut svm, &fee_payer, &program_id, 0, &keys).is_none());
+    assert!(create_multisig(&mut svm, &fee_payer, &program_id, 3, &keys).is_none());
+    assert!(create_multisig(&mut svm, &fee_payer, &program_id, 2, &[keys[0], keys[0]]).is_none());
+}
+
+// Helper function to set the protocol timelock delay through UpdateConfig
+fn set_timelock_delay(svm: &mut LiteSVM, fee_payer: &Keypair, program_id: &Pubkey, delay: i64) {
+    let mut data = vec![6u8]; // UpdateConfig discriminator
+    data.extend_from_slice(&fee_payer.pubkey().to_bytes()); // new_admin
+    data.extend_from_slice(&initialize_config_data(&fee_payer.pubkey(), 84_000_000_000, 0, 1_000, 0, 0)[1..]);
+    data.push(2); // version
+    data.extend_from_slice(&delay.to_le_bytes()); // timelock_delay_seconds
+    data.extend_from_slice(&0u64.to_le_bytes()); // creation_fee_lamports
+    data.extend_from_slice(&0u16.to_le_bytes()); // creation_fee_rebate_basis_points
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(fee_payer.pubkey(), true),
+            AccountMeta::new(derive_config_pda(program_id), false),
+        ],
+        data,
+    };
+    assert!(send_signed(svm, fee_payer, ix, &[]));
+}
+
+fn derive_action_pda(program_id: &Pubkey, bonding_curve: &Pubkey, action_id: u64) -> Pubkey {
+    derive_pda(&[b"action", bonding_curve.as_ref(), &action_id.to_le_bytes()], program_id).0
+}
+
+// Helper function to queue `queued` (an instruction built by one of the helpers above)
+fn queue_action_ix(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    bonding_curve: &Pubkey,
+    payer: &Pubkey,
+    action_id: u64,
+    queued: &Instruction,
+) -> Instruction {
+    // value-moving actions name the account they pay out to
+    let destination = match queued.data[0] {
+        3 | 25 => queued.accounts[4].pubkey, // recipient
+        4 => queued.accounts[3].pubkey,      // recipient token account
+        21 => queued.accounts[5].pubkey,     // beneficiary
+        _ => Pubkey::default(),
+    };
+    let mut data = vec![18u8]; // QueueAction discriminator
+    data.extend_from_slice(&action_id.to_le_bytes());
+    data.push(queued.data[0]); // kind
+    data.extend_from_slice(destination.as_ref());
+    data.extend_from_slice(&queued.data[1..]); // queued instruction data
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(*bonding_curve, false),
+            AccountMeta::new(derive_action_pda(program_id, bonding_curve, action_id), false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(derive_config_pda(program_id), false),
+        ],
+        data,
+    }
+}
+
+// Helper function to execute the queued `queued` instruction with its own accounts
+fn execute_action_ix(
+    program_id: &Pubkey,
+    bonding_curve: &Pubkey,
+    rent_recipient: &Pubkey,
+    action_id: u64,
+    queued: &Instruction,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(derive_action_pda(program_id, bonding_curve, action_id), false),
+        AccountMeta::new(*rent_recipient, false),
+    ];
+    accounts.extend_from_slice(&queued.accounts);
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: vec![19u8], // ExecuteAction discriminator
+    }
+}
+
+fn warp_clock(svm: &mut LiteSVM, seconds: i64) {
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp += seconds;
+    svm.set_sysvar(&clock);
+}
+
+#[test]
+fn timelocked_withdrawal_waits_for_delay() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let authority = launch.authority.pubkey();
+    let recipient = Keypair::new().pubkey();
+
+    let ix = buy_tokens_ix(&program_id, &launch, &fee_payer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+    svm.airdrop(&launch.treasury, 100_000_000).unwrap(); // surplus above the reserve
+
+    set_timelock_delay(&mut svm, &fee_payer, &program_id, 3_600);
+
+    // direct withdrawals are no longer possible
+    let withdraw = withdraw_reserves_ix(&program_id, &launch, &authority, &recipient, 100_000_000);
+    assert!(!send_signed(&mut svm, &fee_payer, withdraw.clone(), &[&launch.authority]));
+
+    let ix = queue_action_ix(&program_id, &authority, &launch.bonding_curve, &fee_payer.pubkey(), 1, &withdraw);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let ix = execute_action_ix(&program_id, &launch.bonding_curve, &fee_payer.pubkey(), 1, &withdraw);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert!(svm.get_account(&recipient).is_none());
+
+    warp_clock(&mut svm, 3_600);
+
+    let ix = execute_action_ix(&program_id, &launch.bonding_curve, &fee_payer.pubkey(), 1, &withdraw);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(svm.get_account(&recipient).unwrap().lamports, 100_000_000);
+
+    // the action is consumed
+    let action = derive_action_pda(&program_id, &launch.bonding_curve, 1);
+    assert!(svm.get_account(&action).map_or(true, |a| a.lamports == 0));
+    let ix = execute_action_ix(&program_id, &launch.bonding_curve, &fee_payer.pubkey(), 1, &withdraw);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+}
+
+#[test]
+fn executed_action_pays_only_the_queued_destination() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let authority = launch.authority.pubkey();
+    let recipient = Keypair::new().pubkey();
+    let other = Keypair::new().pubkey();
+
+    let ix = buy_tokens_ix(&program_id, &launch, &fee_payer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+    svm.airdrop(&launch.treasury, 100_000_000).unwrap(); // surplus above the reserve
+
+    set_timelock_delay(&mut svm, &fee_payer, &program_id, 60);
+
+    // a withdrawal must name its recipient when queued
+    let withdraw = withdraw_reserves_ix(&program_id, &launch, &authority, &recipient, 100_000_000);
+    let mut ix = queue_action_ix(&program_id, &authority, &launch.bonding_curve, &fee_payer.pubkey(), 1, &withdraw);
+    ix.data[10..42].copy_from_slice(&[0u8; 32]); // destination
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let ix = queue_action_ix(&program_id, &authority, &launch.bonding_curve, &fee_payer.pubkey(), 1, &withdraw);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    warp_clock(&mut svm, 60);
+
+    // the executor cannot swap in another recipient
+    let redirected = withdraw_reserves_ix(&program_id, &launch, &authority, &other, 100_000_000);
+    let ix = execute_action_ix(&program_id, &launch.bonding_curve, &fee_payer.pubkey(), 1, &redirected);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert!(svm.get_account(&other).is_none());
+
+    let ix = execute_action_ix(&program_id, &launch.bonding_curve, &fee_payer.pubkey(), 1, &withdraw);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(svm.get_account(&recipient).unwrap().lamports, 100_000_000);
+}
+
+#[test]
+fn timelock_delay_lowers_only_after_current_delay() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let config = derive_config_pda(&program_id);
+    let authority = launch.authority.pubkey();
+    let recipient = Keypair::new().pubkey();
+
+    let ix = buy_tokens_ix(&program_id, &launch, &fee_payer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+    svm.airdrop(&launch.treasury, 100_000_000).unwrap(); // surplus above the reserve
+
+    set_timelock_delay(&mut svm, &fee_payer, &program_id, 3_600);
+
+    // dropping the delay to 0 is only scheduled, even while halted
+    let ix = set_emergency_halt_ix(&program_id, &fee_payer.pubkey(), 1);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+    set_timelock_delay(&mut svm, &fee_payer, &program_id, 0);
+    let ix = set_emergency_halt_ix(&program_id, &fee_payer.pubkey(), 0);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+
+    let data = svm.get_account(&config).unwrap().data;
+    assert_eq!(&data[120..128], &3_600i64.to_le_bytes()); // timelock_delay_seconds
+    assert_ne!(&data[152..160], &0i64.to_le_bytes()); // timelock_delay_lowers_at
+
+    let withdraw = withdraw_reserves_ix(&program_id, &launch, &authority, &recipient, 100_000_000);
+    assert!(!send_signed(&mut svm, &fee_payer, withdraw.clone(), &[&launch.authority]));
+
+    warp_clock(&mut svm, 3_599);
+    assert!(!send_signed(&mut svm, &fee_payer, withdraw.clone(), &[&launch.authority]));
+
+    warp_clock(&mut svm, 1);
+    assert!(send_signed(&mut svm, &fee_payer, withdraw, &[&launch.authority]));
+    assert_eq!(svm.get_account(&recipient).unwrap().lamports, 100_000_000);
+
+    // raising the delay applies at once and drops nothing scheduled
+    set_timelock_delay(&mut svm, &fee_payer, &program_id, 60);
+    let data = svm.get_account(&config).unwrap().data;
+    assert_eq!(&data[120..128], &60i64.to_le_bytes());
+    assert_eq!(&data[152..160], &0i64.to_le_bytes()); // timelock_delay_lowers_at
+}
+
+#[test]
+fn cancelled_action_cannot_execute() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let authority = launch.authority.pubkey();
+    let attacker = Keypair::new();
+
+    set_timelock_delay(&mut svm, &fee_payer, &program_id, 60);
+
+    // only role holders can queue
+    let set_role = set_role_ix(&program_id, &attacker.pubkey(), &launch.bonding_curve, 3, &attacker.pubkey());
+    let ix = queue_action_ix(&program_id, &attacker.pubkey(), &launch.bonding_curve, &fee_payer.pubkey(), 7, &set_role);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&attacker]));
+
+    let set_role = set_role_ix(&program_id, &authority, &launch.bonding_curve, 3, &attacker.pubkey());
+    let ix = queue_action_ix(&program_id, &authority, &launch.bonding_curve, &fee_payer.pubkey(), 7, &set_role);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let action = derive_action_pda(&program_id, &launch.bonding_curve, 7);
+    let cancel = |signer: &Pubkey| Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*signer, true),
+            AccountMeta::new_readonly(launch.bonding_curve, false),
+            AccountMeta::new(action, false),
+            AccountMeta::new(fee_payer.pubkey(), false),
+        ],
+        data: vec![20u8], // CancelAction discriminator
+    };
+    assert!(!send_signed(&mut svm, &fee_payer, cancel(&attacker.pubkey()), &[&attacker]));
+    assert!(send_signed(&mut svm, &fee_payer, cancel(&authority), &[&launch.authority]));
+
+    warp_clock(&mut svm, 60);
+    let ix = execute_action_ix(&program_id, &launch.bonding_curve, &fee_payer.pubkey(), 7, &set_role);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(&svm.get_account(&launch.bonding_curve).unwrap().data[280..312], authority.as_ref());
+}
+
+#[test]
+fn admin_mint_is_capped_by_creator_allocation() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let recipient_ata = create_ata(&mut svm, &fee_payer, &fee_payer.pubkey(), &launch.mint);
+    let authority = launch.authority.pubkey();
+
+    let ix = admin_mint_ix(&program_id, &launch, &authority, &recipient_ata, CREATOR_ALLOCATION - 1);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let ix = admin_mint_ix(&program_id, &launch, &authority, &recipient_ata, 2);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let ix = admin_mint_ix(&program_id, &launch, &authority, &recipient_ata, 1);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let data = svm.get_account(&launch.bonding_curve).unwrap().data;
+    assert_eq!(&data[312..320], &CREATOR_ALLOCATION.to_le_bytes()); // creator_allocation
+    assert_eq!(&data[320..328], &CREATOR_ALLOCATION.to_le_bytes()); // creator_minted
+    assert_eq!(&data[144..152], &0u64.to_le_bytes()); // total_supply untouched by admin mints
+}
+
+fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+    let data = svm.get_account(token_account).unwrap().data;
+    u64::from_le_bytes(data[64..72].try_into().unwrap())
+}
+
+struct VestingAccounts {
+    vesting: Pubkey,
+    escrow: Pubkey,
+    mint: Pubkey,
+    token_program: Pubkey,
+}
+
+// Helper function to build a CreateVesting instruction
+fn create_vesting_ix(
+    program_id: &Pubkey,
+    launch: &Launch,
+    beneficiary: &Pubkey,
+    amount: u64,
+    schedule: (i64, i64, i64),
+    payer: &Pubkey,
+) -> (Instruction, VestingAccounts) {
+    let vesting = derive_pda(&[b"vesting", launch.bonding_curve.as_ref(), beneficiary.as_ref()], program_id).0;
+    let escrow = launch.ata(&vesting);
+    let mut data = vec![21u8]; // CreateVesting discriminator
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&schedule.0.to_le_bytes()); // start_ts
+    data.extend_from_slice(&schedule.1.to_le_bytes()); // cliff_ts
+    data.extend_from_slice(&schedule.2.to_le_bytes()); // end_ts
+    data.push(1); // revocable
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(launch.authority.pubkey(), true), // authority
+            AccountMeta::new(launch.bonding_curve, false),              // bonding_curve
+            AccountMeta::new(launch.mint, false),                       // mint
+            AccountMeta::new(vesting, false),                           // vesting
+            AccountMeta::new(escrow, false),                            // escrow
+            AccountMeta::new_readonly(*beneficiary, false),             // beneficiary
+            AccountMeta::new(*payer, true),                             // payer
+            AccountMeta::new_readonly(system_program::ID, false),       // system_program
+            AccountMeta::new_readonly(launch.token_program, false),     // token_program
+            AccountMeta::new_readonly(ATA_PROGRAM_ID, false),           // associated_token_program
+            AccountMeta::new_readonly(launch.config, false),            // config
+        ],
+        data,
+    };
+    let mint = launch.mint;
+    let token_program = launch.token_program;
+    (ix, VestingAccounts { vesting, escrow, mint, token_program })
+}
+
+fn claim_vested_ix(program_id: &Pubkey, beneficiary: &Pubkey, accounts: &VestingAccounts, destination: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*beneficiary, true),
+            AccountMeta::new(accounts.vesting, false),
+            AccountMeta::new(accounts.escrow, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(accounts.token_program, false),
+            AccountMeta::new_readonly(accounts.mint, false),
+        ],
+        data: vec![22u8], // ClaimVested discriminator
+    }
+}
+
+#[test]
+fn vesting_unlocks_linearly_and_revokes_unvested() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let beneficiary = Keypair::new();
+    let destination = create_ata(&mut svm, &fee_payer, &beneficiary.pubkey(), &launch.mint);
+    let now = svm.get_sysvar::<Clock>().unix_timestamp;
+
+    let (ix, vesting) = create_vesting_ix(
+        &program_id,
+        &launch,
+        &beneficiary.pubkey(),
+        1_000,
+        (now, now + 100, now + 1_000),
+        &fee_payer.pubkey(),
+    );
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(token_balance(&svm, &vesting.escrow), 1_000);
+
+    // nothing before the cliff
+    let ix = claim_vested_ix(&program_id, &beneficiary.pubkey(), &vesting, &destination);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&beneficiary]));
+
+    warp_clock(&mut svm, 500);
+    let ix = claim_vested_ix(&program_id, &beneficiary.pubkey(), &vesting, &destination);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&beneficiary]));
+    assert_eq!(token_balance(&svm, &destination), 500);
+
+    // only the beneficiary can claim
+    let attacker = Keypair::new();
+    let ix = claim_vested_ix(&program_id, &attacker.pubkey(), &vesting, &destination);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&attacker]));
+
+    warp_clock(&mut svm, 100);
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(launch.authority.pubkey(), true),
+            AccountMeta::new(launch.bonding_curve, false),
+            AccountMeta::new(launch.mint, false),
+            AccountMeta::new(vesting.vesting, false),
+            AccountMeta::new(vesting.escrow, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(launch.config, false),
+        ],
+        data: vec![23u8], // RevokeVesting discriminator
+    };
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    // 600 vested: 100 still claimable, 400 burned and back in the allocation
+    assert_eq!(token_balance(&svm, &vesting.escrow), 100);
+    let data = svm.get_account(&launch.bonding_curve).unwrap().data;
+    assert_eq!(&data[320..328], &600u64.to_le_bytes()); // creator_minted
+
+    warp_clock(&mut svm, 10_000);
+    let ix = claim_vested_ix(&program_id, &beneficiary.pubkey(), &vesting, &destination);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&beneficiary]));
+    assert_eq!(token_balance(&svm, &destination), 600);
+    assert_eq!(token_balance(&svm, &vesting.escrow), 0);
+}
+
+#[test]
+fn vesting_beyond_creator_allocation_should_fail() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let now = svm.get_sysvar::<Clock>().unix_timestamp;
+
+    let (ix, _) = create_vesting_ix(
+        &program_id,
+        &launch,
+        &Keypair::new().pubkey(),
+        CREATOR_ALLOCATION + 1,
+        (now, now, now + 1_000),
+        &fee_payer.pubkey(),
+    );
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+}
+
+fn renounce_authorities_ix(program_id: &Pubkey, launch: &Launch, authority: &Pubkey, authorities: u8) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),          // authority
+            AccountMeta::new(launch.bonding_curve, false),        // bonding_curve
+            AccountMeta::new(launch.mint, false),                 // mint
+            AccountMeta::new_readonly(launch.token_program, false), // token_program
+            AccountMeta::new_readonly(launch.config, false),      // config
+        ],
+        data: vec![24u8, authorities], // RenounceAuthorities discriminator, authorities
+    }
+}
+
+#[test]
+fn renounce_authorities_makes_supply_final_after_graduation() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let authority = launch.authority.pubkey();
+    let recipient_ata = create_ata(&mut svm, &fee_payer, &fee_payer.pubkey(), &launch.mint);
+    let ix = admin_mint_ix(&program_id, &launch, &authority, &recipient_ata, 1_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    // the freeze authority is unused and can go at any time, once
+    let ix = renounce_authorities_ix(&program_id, &launch, &authority, 2);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(&svm.get_account(&launch.mint).unwrap().data[46..50], &[0u8; 4]); // freeze_authority = None
+    let ix = renounce_authorities_ix(&program_id, &launch, &authority, 2);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    // the mint authority stays while the curve is still selling
+    let ix = renounce_authorities_ix(&program_id, &launch, &authority, 1);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let mut curve = svm.get_account(&launch.bonding_curve).unwrap();
+    curve.data[215] = 1; // graduated
+    svm.set_account(launch.bonding_curve, curve).unwrap();
+
+    let outsider = Keypair::new();
+    let ix = renounce_authorities_ix(&program_id, &launch, &outsider.pubkey(), 1);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&outsider]));
+
+    let ix = renounce_authorities_ix(&program_id, &launch, &authority, 1);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(&svm.get_account(&launch.mint).unwrap().data[0..4], &[0u8; 4]); // mint_authority = None
+    assert_eq!(svm.get_account(&launch.bonding_curve).unwrap().data[328], 3);
+
+    // the unminted creator allocation is forfeited
+    let ix = admin_mint_ix(&program_id, &launch, &authority, &recipient_ata, 1_000);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+}
+
+#[test]
+fn renounce_authorities_on_token_2022_mint() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let options = LaunchOptions { token_2022: true, ..Default::default() };
+    let launch = try_launch_token(&mut svm, &fee_payer, &program_id, options).expect("Initialize should succeed");
+    let authority = launch.authority.pubkey();
+
+    let ix = renounce_authorities_ix(&program_id, &launch, &authority, 2);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(&svm.get_account(&launch.mint).unwrap().data[46..50], &[0u8; 4]); // freeze_authority = None
+
+    let mut curve = svm.get_account(&launch.bonding_curve).unwrap();
+    curve.data[215] = 1; // graduated
+    svm.set_account(launch.bonding_curve, curve).unwrap();
+
+    // the token program must be the one owning the mint
+    let mut ix = renounce_authorities_ix(&program_id, &launch, &authority, 1);
+    ix.accounts[3] = AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let ix = renounce_authorities_ix(&program_id, &launch, &authority, 1);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(&svm.get_account(&launch.mint).unwrap().data[0..4], &[0u8; 4]); // mint_authority = None
+}
+
+fn close_curve_ix(program_id: &Pubkey, launch: &Launch, authority: &Pubkey, recipient: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),          // authority
+            AccountMeta::new(launch.bonding_curve, false),        // bonding_curve
+            AccountMeta::new(launch.mint, false),                 // mint
+            AccountMeta::new(launch.treasury, false),             // treasury
+            AccountMeta::new(*recipient, false),                  // recipient
+            AccountMeta::new_readonly(system_program::ID, false), // system_program
+            AccountMeta::new_readonly(launch.token_program, false), // token_program
+            AccountMeta::new_readonly(launch.config, false),      // config
+        ],
+        data: vec![25u8], // CloseCurve discriminator
+    }
+}
+
+#[test]
+fn close_curve_only_once_supply_is_gone() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let authority = launch.authority.pubkey();
+    let recipient = Keypair::new().pubkey();
+    let buyer = Keypair::new();
+    svm.airdrop(&buyer.pubkey(), 2_000_000_000).unwrap();
+
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+
+    // a live curve cannot be closed
+    let ix = close_curve_ix(&program_id, &launch, &authority, &recipient);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let ix = sell_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_000, 0);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+
+    let outsider = Keypair::new();
+    let ix = close_curve_ix(&program_id, &launch, &outsider.pubkey(), &outsider.pubkey());
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&outsider]));
+
+    let locked = svm.get_account(&launch.bonding_curve).unwrap().lamports
+        + svm.get_account(&launch.treasury).unwrap().lamports;
+    let ix = close_curve_ix(&program_id, &launch, &authority, &recipient);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    assert_eq!(svm.get_account(&recipient).unwrap().lamports, locked);
+    assert!(svm.get_account(&launch.bonding_curve).map_or(true, |a| a.lamports == 0));
+    assert!(svm.get_account(&launch.treasury).map_or(true, |a| a.lamports == 0));
+    let mint = svm.get_account(&launch.mint).unwrap();
+    assert_eq!(&mint.data[0..4], &[0u8; 4]); // mint_authority = None
+    assert_eq!(&mint.data[46..50], &[0u8; 4]); // freeze_authority = None
+
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000_000_000, 1_000_000_000);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+}
+
+#[test]
+fn close_curve_rejects_minted_creator_allocation() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let authority = launch.authority.pubkey();
+    let recipient = Keypair::new().pubkey();
+
+    // creator tokens circulate even though the curve sold nothing
+    let recipient_ata = create_ata(&mut svm, &fee_payer, &fee_payer.pubkey(), &launch.mint);
+    let ix = admin_mint_ix(&program_id, &launch, &authority, &recipient_ata, 1_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let ix = close_curve_ix(&program_id, &launch, &authority, &recipient);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert!(svm.get_account(&launch.bonding_curve).unwrap().lamports > 0);
+}
+
+#[test]
+fn close_curve_on_token_2022_mint() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let options = LaunchOptions { token_2022: true, ..Default::default() };
+    let launch = try_launch_token(&mut svm, &fee_payer, &program_id, options).expect("Initialize should succeed");
+    let authority = launch.authority.pubkey();
+    let recipient = Keypair::new().pubkey();
+    let buyer = Keypair::new();
+    svm.airdrop(&buyer.pubkey(), 2_000_000_000).unwrap();
+
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000, 100_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+    let ix = sell_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000, 0);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+
+    let ix = close_curve_ix(&program_id, &launch, &authority, &recipient);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert!(svm.get_account(&launch.bonding_curve).map_or(true, |a| a.lamports == 0));
+    let mint = svm.get_account(&launch.mint).unwrap();
+    assert_eq!(mint.owner, TOKEN_2022_PROGRAM_ID);
+    assert_eq!(&mint.data[0..4], &[0u8; 4]); // mint_authority = None
+    assert_eq!(&mint.data[46..50], &[0u8; 4]); // freeze_authority = None
+}
+
+fn update_metadata_ix(
+    program_id: &Pubkey,
+    launch: &Launch,
+    authority: &Pubkey,
+    update_authority: &Pubkey,
+    (update_data, delegate_to_curve, lock): (u8, u8, u8),
+    token_name: &str,
+) -> Instruction {
+    let mut data = vec![26u8, update_data, delegate_to_curve, lock]; // UpdateMetadata discriminator, flags
+    let mut name = [0u8; 32];
+    name[0] = token_name.len() as u8;
+    name[1..=token_name.len()].copy_from_slice(token_name.as_bytes());
+    data.extend_from_slice(&name); // token_name
+    data.extend_from_slice(&[0u8; 10]); // token_symbol (empty)
+    data.extend_from_slice(&[0u8; 200]); // token_uri (empty)
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),                  // authority
+            AccountMeta::new_readonly(launch.bonding_curve, false),       // bonding_curve
+            AccountMeta::new_readonly(launch.mint, false),                // mint
+            AccountMeta::new(derive_metadata_pda(&launch.mint), false),   // metadata_account
+            AccountMeta::new_readonly(*update_authority, *update_authority == *authority), // update_authority
+            AccountMeta::new_readonly(METAPLEX_PROGRAM_ID, false),        // metaplex_program
+            AccountMeta::new_readonly(launch.config, false),              // config
+        ],
+        data,
+    }
+}
+
+#[test]
+fn update_metadata_rejects_bad_requests() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let authority = launch.authority.pubkey();
+
+    // nothing to do
+    let ix = update_metadata_ix(&program_id, &launch, &authority, &authority, (0, 0, 0), "");
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    // only the curve owner may update
+    let outsider = Keypair::new();
+    let ix = update_metadata_ix(&program_id, &launch, &outsider.pubkey(), &outsider.pubkey(), (1, 0, 0), "Renamed");
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&outsider]));
+
+    // the update authority must be the owner wallet or the curve PDA
+    let ix = update_metadata_ix(&program_id, &launch, &authority, &outsider.pubkey(), (1, 0, 0), "Renamed");
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+}
+
+#[test]
+fn initialize_validates_royalty_and_creator_share() {
+    let (mut svm, fee_payer, program_id) = setup();
+
+    let royalty = |seller_fee_basis_points, creator_share| LaunchOptions {
+        seller_fee_basis_points,
+        creator_share,
+        ..Default::default()
+    };
+    assert!(try_launch_token(&mut svm, &fee_payer, &program_id, royalty(10_001, 0)).is_none());
+    assert!(try_launch_token(&mut svm, &fee_payer, &program_id, royalty(500, 101)).is_none());
+    assert!(try_launch_token(&mut svm, &fee_payer, &program_id, royalty(500, 80)).is_some());
+    assert!(try_launch_token(&mut svm, &fee_payer, &program_id, royalty(10_000, 100)).is_some());
+}
+
+const METAPLEX_FIXTURE: &str = "tests/fixtures/mpl_token_metadata.so";
+
+// Load the Metaplex Token Metadata program dumped from mainnet
+// (solana program dump metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s tests/fixtures/mpl_token_metadata.so)
+// and create the protocol config with metadata enabled
+fn setup_metaplex(svm: &mut LiteSVM, fee_payer: &Keypair, program_id: &Pubkey) {
+    assert!(
+        std::path::Path::new(METAPLEX_FIXTURE).exists(),
+        "{METAPLEX_FIXTURE} not found; dump it with `solana program dump metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s {METAPLEX_FIXTURE}`"
+    );
+    svm.add_program_from_file(METAPLEX_PROGRAM_ID, METAPLEX_FIXTURE).unwrap();
+
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(fee_payer.pubkey(), true), // admin
+            AccountMeta::new(derive_config_pda(program_id), false), // config
+            AccountMeta::new(fee_payer.pubkey(), true),          // payer
+            AccountMeta::new_readonly(system_program::ID, false), // system_program
+        ],
+        data: initialize_config_data(&fee_payer.pubkey(), 84_000_000_000, 0, 1_000, 1, 0),
+    };
+    assert!(send_signed(svm, fee_payer, ix, &[]));
+}
+
+fn derive_master_edition_pda(mint: &Pubkey) -> Pubkey {
+    derive_pda(
+        &[b"metadata", METAPLEX_PROGRAM_ID.as_ref(), mint.as_ref(), b"edition"],
+        &METAPLEX_PROGRAM_ID,
+    )
+    .0
+}
+
+// Create a sized collection NFT owned by fee_payer, then hand its update
+// authority to the program's collection authority PDA
+fn create_platform_collection(svm: &mut LiteSVM, fee_payer: &Keypair, program_id: &Pubkey) -> Pubkey {
+    let payer = fee_payer.pubkey();
+    let collection = Keypair::new();
+    let mint = collection.pubkey();
+    let create_mint = solana_sdk::system_instruction::create_account(
+        &payer,
+        &mint,
+        Rent::default().minimum_balance(82),
+        82,
+        &TOKEN_PROGRAM_ID,
+    );
+    let init_mint = spl_token::instruction::initialize_mint2(&TOKEN_PROGRAM_ID, &mint, &payer, Some(&payer), 0).unwrap();
+    assert!(send_signed(svm, fee_payer, create_mint, &[&collection]));
+    assert!(send_signed(svm, fee_payer, init_mint, &[]));
+    let ata = create_ata(svm, fee_payer, &payer, &mint);
+    let mint_to = spl_token::instruction::mint_to(&TOKEN_PROGRAM_ID, &mint, &ata, &payer, &[], 1).unwrap();
+    assert!(send_signed(svm, fee_payer, mint_to, &[]));
+
+    let metadata = derive_metadata_pda(&mint);
+    let edition = derive_master_edition_pda(&mint);
+
+    let mut data = vec![33u8]; // CreateMetadataAccountV3
+    data.extend_from_slice(&8u32.to_le_bytes());
+    data.extend_from_slice(b"Platform"); // name
+    data.extend_from_slice(&0u32.to_le_bytes()); // symbol
+    data.extend_from_slice(&0u32.to_le_bytes()); // uri
+    data.extend_from_slice(&0u16.to_le_bytes()); // seller_fee_basis_points
+    data.extend_from_slice(&[0, 0, 0]); // creators, collection, uses: None
+    data.push(1); // is_mutable
+    data.extend_from_slice(&[1, 0]); // collection_details: Some(V1 { size })
+    data.extend_from_slice(&0u64.to_le_bytes());
+    let ix = Instruction {
+        program_id: METAPLEX_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(metadata, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(payer, true), // mint_authority
+            AccountMeta::new(payer, true),          // payer
+            AccountMeta::new_readonly(payer, true), // update_authority
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(solana_sdk::sysvar::rent::ID, false),
+        ],
+        data,
+    };
+    assert!(send_signed(svm, fee_payer, ix, &[]));
+
+    let mut data = vec![17u8, 1]; // CreateMasterEditionV3, max_supply: Some(0)
+    data.extend_from_slice(&0u64.to_le_bytes());
+    let ix = Instruction {
+        program_id: METAPLEX_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(edition, false),
+            AccountMeta::new(mint, false),
+            AccountMeta::new_readonly(payer, true), // update_authority
+            AccountMeta::new_readonly(payer, true), // mint_authority
+            AccountMeta::new(payer, true),          // payer
+            AccountMeta::new(metadata, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(solana_sdk::sysvar::rent::ID, false),
+        ],
+        data,
+    };
+    assert!(send_signed(svm, fee_payer, ix, &[]));
+
+    let collection_authority = derive_pda(&[b"collection_authority"], program_id).0;
+    let mut data = vec![15u8, 0, 1]; // UpdateMetadataAccountV2: data None, new_update_authority Some
+    data.extend_from_slice(collection_authority.as_ref());
+    data.extend_from_slice(&[0, 0]); // primary_sale_happened, is_mutable: None
+    let ix = Instruction {
+        program_id: METAPLEX_PROGRAM_ID,
+        accounts: vec![AccountMeta::new(metadata, false), AccountMeta::new_readonly(payer, true)],
+        data,
+    };
+    assert!(send_signed(svm, fee_payer, ix, &[]));
+
+    mint
+}
+
+fn verify_collection_ix(program_id: &Pubkey, launch: &Launch, payer: &Pubkey, collection_mint: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*payer, true),                                          // payer
+            AccountMeta::new_readonly(launch.bonding_curve, false),                  // bonding_curve
+            AccountMeta::new_readonly(launch.mint, false),                           // mint
+            AccountMeta::new(derive_metadata_pda(&launch.mint), false),              // metadata_account
+            AccountMeta::new_readonly(derive_pda(&[b"collection_authority"], program_id).0, false), // collection_authority
+            AccountMeta::new_readonly(*collection_mint, false),                      // collection_mint
+            AccountMeta::new(derive_metadata_pda(collection_mint), false),           // collection_metadata
+            AccountMeta::new_readonly(derive_master_edition_pda(collection_mint), false), // collection_master_edition
+            AccountMeta::new_readonly(METAPLEX_PROGRAM_ID, false),                   // metaplex_program
+            AccountMeta::new_readonly(launch.config, false),                         // config
+        ],
+        data: vec![27u8], // VerifyCollection discriminator
+    }
+}
+
+#[test]
+fn launched_token_joins_platform_collection() {
+    let (mut svm, fee_payer, program_id) = setup();
+    setup_metaplex(&mut svm, &fee_payer, &program_id);
+    let collection = create_platform_collection(&mut svm, &fee_payer, &program_id);
+
+    let options = LaunchOptions { seller_fee_basis_points: 500, creator_share: 80, collection, ..Default::default() };
+    let launch = try_launch_token(&mut svm, &fee_payer, &program_id, options).expect("Initialize should succeed");
+    let metadata = derive_metadata_pda(&launch.mint);
+    let collection_field = |verified: u8| [&[1u8, verified][..], collection.as_ref()].concat();
+    let has_collection = |svm: &LiteSVM, verified| {
+        svm.get_account(&metadata).unwrap().data.windows(34).any(|w| w == collection_field(verified))
+    };
+    assert!(has_collection(&svm, 0));
+
+    // a collection the program does not control cannot be verified
+    let foreign = Keypair::new();
+    svm.airdrop(&foreign.pubkey(), 1_000_000_000).unwrap();
+    let foreign_collection = create_platform_collection(&mut svm, &foreign, &Pubkey::new_unique());
+    let ix = verify_collection_ix(&program_id, &launch, &fee_payer.pubkey(), &foreign_collection);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[]));
+
+    let ix = verify_collection_ix(&program_id, &launch, &fee_payer.pubkey(), &collection);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+    assert!(has_collection(&svm, 1));
+}
+
+// Fields of a launch's Metaplex metadata account the tests look at
+struct MetadataView {
+    update_authority: Pubkey,
+    name: String,
+    seller_fee_basis_points: u16,
+    // key, verified, share
+    creators: Vec<(Pubkey, u8, u8)>,
+    is_mutable: bool,
+}
+
+fn read_metadata(svm: &LiteSVM, mint: &Pubkey) -> MetadataView {
+    let data = svm.get_account(&derive_metadata_pda(mint)).unwrap().data;
+    let len = |o: usize| u32::from_le_bytes(data[o..o + 4].try_into().unwrap()) as usize;
+
+    // key, update authority, mint, then name, symbol and uri
+    let name_len = len(65);
+    let name = String::from_utf8_lossy(&data[69..69 + name_len]).trim_end_matches('\0').to_string();
+    let mut o = 65;
+    for _ in 0..3 {
+        o += 4 + len(o);
+    }
+    let seller_fee_basis_points = u16::from_le_bytes(data[o..o + 2].try_into().unwrap());
+    o += 2;
+    let mut creators = Vec::new();
+    if data[o] == 1 {
+        let count = len(o + 1);
+        for creator in data[o + 5..].chunks(34).take(count) {
+            creators.push((Pubkey::try_from(&creator[..32]).unwrap(), creator[32], creator[33]));
+        }
+        o += 5 + count * 34;
+    } else {
+        o += 1;
+    }
+
+    MetadataView {
+        update_authority: Pubkey::try_from(&data[1..33]).unwrap(),
+        name,
+        seller_fee_basis_points,
+        creators,
+        is_mutable: data[o + 1] == 1, // after primary_sale_happened
+    }
+}
+
+// Royalty and creators of a launch's Metaplex metadata
+fn metadata_attribution(svm: &LiteSVM, mint: &Pubkey) -> (u16, Vec<(Pubkey, u8, u8)>) {
+    let metadata = read_metadata(svm, mint);
+    (metadata.seller_fee_basis_points, metadata.creators)
+}
+
+#[test]
+fn metadata_lists_creators_and_royalty() {
+    let (mut svm, fee_payer, program_id) = setup();
+    setup_metaplex(&mut svm, &fee_payer, &program_id);
+    let platform = fee_payer.pubkey(); // protocol fee recipient
+
+    // a zero creator share leaves the platform as the only creator
+    let options = LaunchOptions { seller_fee_basis_points: 500, creator_share: 0, ..Default::default() };
+    let launch = try_launch_token(&mut svm, &fee_payer, &program_id, options).expect("Initialize should succeed");
+    assert_eq!(metadata_attribution(&svm, &launch.mint), (500, vec![(platform, 0, 100)]));
+
+    let options = LaunchOptions { seller_fee_basis_points: 250, creator_share: 80, ..Default::default() };
+    let launch = try_launch_token(&mut svm, &fee_payer, &program_id, options).expect("Initialize should succeed");
+    let authority = launch.authority.pubkey();
+    assert_eq!(metadata_attribution(&svm, &launch.mint), (250, vec![(authority, 1, 80), (platform, 0, 20)]));
+
+    let options = LaunchOptions { seller_fee_basis_points: 1_000, creator_share: 100, ..Default::default() };
+    let launch = try_launch_token(&mut svm, &fee_payer, &program_id, options).expect("Initialize should succeed");
+    let authority = launch.authority.pubkey();
+    assert_eq!(metadata_attribution(&svm, &launch.mint), (1_000, vec![(authority, 1, 100)]));
+}
+
+#[test]
+fn update_metadata_renames_delegates_and_locks() {
+    let (mut svm, fee_payer, program_id) = setup();
+    setup_metaplex(&mut svm, &fee_payer, &program_id);
+    let collection = create_platform_collection(&mut svm, &fee_payer, &program_id);
+
+    let options = LaunchOptions { seller_fee_basis_points: 500, creator_share: 80, collection, ..Default::default() };
+    let launch = try_launch_token(&mut svm, &fee_payer, &program_id, options).expect("Initialize should succeed");
+    let authority = launch.authority.pubkey();
+    let ix = verify_collection_ix(&program_id, &launch, &fee_payer.pubkey(), &collection);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+    let verified_collection = [&[1u8, 1][..], collection.as_ref()].concat();
+    let metadata_account = derive_metadata_pda(&launch.mint);
+    let has_verified_collection = |svm: &LiteSVM| {
+        svm.get_account(&metadata_account).unwrap().data.windows(34).any(|w| w == verified_collection)
+    };
+    let attribution = metadata_attribution(&svm, &launch.mint);
+    assert_eq!(attribution, (500, vec![(authority, 1, 80), (fee_payer.pubkey(), 0, 20)]));
+
+    // renaming keeps royalty, creators and the verified collection
+    let ix = update_metadata_ix(&program_id, &launch, &authority, &authority, (1, 0, 0), "Renamed");
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    let metadata = read_metadata(&svm, &launch.mint);
+    assert_eq!(metadata.name, "Renamed");
+    assert_eq!((metadata.seller_fee_basis_points, metadata.creators), attribution);
+    assert!(has_verified_collection(&svm));
+
+    // once the curve PDA holds the update authority, the wallet no longer can
+    let ix = update_metadata_ix(&program_id, &launch, &authority, &authority, (0, 1, 0), "");
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(read_metadata(&svm, &launch.mint).update_authority, launch.bonding_curve);
+    let ix = update_metadata_ix(&program_id, &launch, &authority, &authority, (1, 0, 0), "Wallet");
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let ix = update_metadata_ix(&program_id, &launch, &authority, &launch.bonding_curve, (1, 0, 0), "ViaCurve");
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    let metadata = read_metadata(&svm, &launch.mint);
+    assert_eq!(metadata.name, "ViaCurve");
+    assert_eq!((metadata.seller_fee_basis_points, metadata.creators), attribution);
+    assert!(has_verified_collection(&svm));
+
+    // a locked metadata account cannot change again
+    let ix = update_metadata_ix(&program_id, &launch, &authority, &launch.bonding_curve, (0, 0, 1), "");
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert!(!read_metadata(&svm, &launch.mint).is_mutable);
+    let ix = update_metadata_ix(&program_id, &launch, &authority, &launch.bonding_curve, (1, 0, 0), "Locked");
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(read_metadata(&svm, &launch.mint).name, "ViaCurve");
+}
+
+#[test]
+fn token_2022_launch_trades_and_mints() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let options = LaunchOptions { token_2022: true, ..Default::default() };
+    let launch = try_launch_token(&mut svm, &fee_payer, &program_id, options).expect("Initialize should succeed");
+
+    // the TokenMetadata extension lives in the mint, with the authority as update authority
+    let mint = svm.get_account(&launch.mint).unwrap();
+    assert_eq!(mint.owner, TOKEN_2022_PROGRAM_ID);
+    let metadata = &mint.data[MINT_2022_WITH_METADATA_POINTER_LEN..];
+    assert_eq!(metadata.len(), 4 + 32 + 32 + 4 * 3 + 4); // empty name, symbol, uri
+    assert_eq!(&metadata[4..36], launch.authority.pubkey().as_ref());
+    assert_eq!(&metadata[36..68], launch.mint.as_ref());
+
+    let buyer = Keypair::new();
+    svm.airdrop(&buyer.pubkey(), 1_000_000_000).unwrap();
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000, 100_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+    assert_eq!(svm.get_account(&launch.ata(&buyer.pubkey())).unwrap().owner, TOKEN_2022_PROGRAM_ID);
+    assert_eq!(token_balance(&svm, &launch.ata(&buyer.pubkey())), 1_000);
+
+    let ix = sell_tokens_ix(&program_id, &launch, &buyer.pubkey(), 400, 0);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+    assert_eq!(token_balance(&svm, &launch.ata(&buyer.pubkey())), 600);
+
+    let recipient_ata = create_ata(&mut svm, &fee_payer, &fee_payer.pubkey(), &launch.mint);
+    let ix = admin_mint_ix(&program_id, &launch, &launch.authority.pubkey(), &recipient_ata, 1_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(token_balance(&svm, &recipient_ata), 1_000);
+
+    // the token program must be the one owning the mint
+    let mut ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000, 100_000_000);
+    ix.accounts[7] = AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+}
+
+#[test]
+fn token_2022_metadata_rejects_metaplex_only_fields() {
+    let (mut svm, fee_payer, program_id) = setup();
+
+    // in-mint metadata has no royalty, creators or collection
+    let token_2022 = |seller_fee_basis_points, creator_share, collection| LaunchOptions {
+        token_2022: true,
+        seller_fee_basis_points,
+        creator_share,
+        collection,
+        ..Default::default()
+    };
+    assert!(try_launch_token(&mut svm, &fee_payer, &program_id, token_2022(500, 0, Pubkey::default())).is_none());
+    assert!(try_launch_token(&mut svm, &fee_payer, &program_id, token_2022(0, 80, Pubkey::default())).is_none());
+    assert!(try_launch_token(&mut svm, &fee_payer, &program_id, token_2022(0, 0, Pubkey::new_unique())).is_none());
+    let launch = try_launch_token(&mut svm, &fee_payer, &program_id, token_2022(0, 0, Pubkey::default()))
+        .expect("Initialize should succeed");
+
+    // and UpdateMetadata only handles Metaplex metadata
+    let authority = launch.authority.pubkey();
+    let ix = update_metadata_ix(&program_id, &launch, &authority, &authority, (1, 0, 0), "Renamed");
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+}
+
+fn harvest_withheld_fees_ix(program_id: &Pubkey, launch: &Launch, destination: &Pubkey, sources: &[Pubkey]) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(launch.bonding_curve, false), // bonding_curve
+        AccountMeta::new(launch.mint, false),                   // mint
+        AccountMeta::new(*destination, false),                  // creator_token_account
+        AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false), // token_program
+        AccountMeta::new_readonly(launch.config, false),        // config
+    ];
+    accounts.extend(sources.iter().map(|source| AccountMeta::new(*source, false)));
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: vec![28u8], // HarvestWithheldFees discriminator
+    }
+}
+
+#[test]
+fn transfer_fee_is_withheld_and_harvested_to_creator() {
+    let (mut svm, fee_payer, program_id) = setup();
+
+    // only Token-2022 mints can carry a transfer fee
+    let options = LaunchOptions { transfer_fee_basis_points: 500, ..Default::default() };
+    assert!(try_launch_token(&mut svm, &fee_payer, &program_id, options).is_none());
+
+    let options = LaunchOptions {
+        token_2022: true,
+        transfer_fee_basis_points: 500,
+        maximum_transfer_fee: u64::MAX,
+        ..Default::default()
+    };
+    let launch = try_launch_token(&mut svm, &fee_payer, &program_id, options).expect("Initialize should succeed");
+
+    // buys mint straight to the buyer, free of the transfer fee
+    let buyer = Keypair::new();
+    svm.airdrop(&buyer.pubkey(), 1_000_000_000).unwrap();
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 10_000, 100_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+    assert_eq!(token_balance(&svm, &launch.ata(&buyer.pubkey())), 10_000);
+
+    // a wallet-to-wallet transfer has 5% withheld at the destination
+    let recipient = Keypair::new();
+    let recipient_ata = create_ata(&mut svm, &fee_payer, &recipient.pubkey(), &launch.mint);
+    let mut data = vec![12u8]; // TransferChecked
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.push(9); // decimals
+    let ix = Instruction {
+        program_id: TOKEN_2022_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(launch.ata(&buyer.pubkey()), false),
+            AccountMeta::new_readonly(launch.mint, false),
+            AccountMeta::new(recipient_ata, false),
+            AccountMeta::new_readonly(buyer.pubkey(), true),
+        ],
+        data,
+    };
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+    assert_eq!(token_balance(&svm, &recipient_ata), 950);
+
+    // vesting claims out of the program-owned escrow pay the fee as well
+    let beneficiary = Keypair::new();
+    let destination = create_ata(&mut svm, &fee_payer, &beneficiary.pubkey(), &launch.mint);
+    let now = svm.get_sysvar::<Clock>().unix_timestamp;
+    let (ix, vesting) = create_vesting_ix(
+        &program_id,
+        &launch,
+        &beneficiary.pubkey(),
+        1_000,
+        (now, now + 100, now + 1_000),
+        &fee_payer.pubkey(),
+    );
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    warp_clock(&mut svm, 1_000);
+    let ix = claim_vested_ix(&program_id, &beneficiary.pubkey(), &vesting, &destination);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&beneficiary]));
+    assert_eq!(token_balance(&svm, &destination), 950);
+
+    // the fees can only be swept to the creator
+    let ix = harvest_withheld_fees_ix(&program_id, &launch, &recipient_ata, &[recipient_ata, destination]);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[]));
+
+    let creator_ata = create_ata(&mut svm, &fee_payer, &launch.authority.pubkey(), &launch.mint);
+    let ix = harvest_withheld_fees_ix(&program_id, &launch, &creator_ata, &[recipient_ata, destination]);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+    assert_eq!(token_balance(&svm, &creator_ata), 100);
+}
+
+#[test]
+fn initialize_without_metadata_skips_metaplex() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let config = initialize_config(&mut svm, &fee_payer, &program_id);
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let create_mint_ix = solana_sdk::system_instruction::create_account(
+        &fee_payer.pubkey(),
+        &mint,
+        Rent::default().minimum_balance(82),
+        82,
+        &TOKEN_PROGRAM_ID,
+    );
+    assert!(send_signed(&mut svm, &fee_payer, create_mint_ix, &[&mint_keypair]));
+
+    let (bonding_curve, _) = derive_pda(&[b"x_token", mint.as_ref()], &program_id);
+    let (treasury, _) = derive_pda(&[b"treasury", mint.as_ref()], &program_id);
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 1_000_000_000).unwrap();
+    let authority_ata = spl_associated_token_account::get_associated_token_address(&authority.pubkey(), &mint);
+
+    // only the curve fields: no strings, royalty, collection or transfer fee
+    let mut data = vec![29u8]; // InitializeWithoutMetadata discriminator
+    data.push(9); // decimals
+    data.push(0); // curve_type (linear)
+    data.extend_from_slice(&100u16.to_le_bytes()); // fee_basis_points
+    data.extend_from_slice(&[0u8; 32]); // owner (empty)
+    data.extend_from_slice(&1_000_000u64.to_le_bytes()); // base_price
+    data.extend_from_slice(&1_000u64.to_le_bytes()); // slope
+    data.extend_from_slice(&1_000_000_000_000u64.to_le_bytes()); // max_supply
+    data.extend_from_slice(&authority.pubkey().to_bytes()); // fee_recipient
+    data.extend_from_slice(&1_000u64.to_le_bytes()); // initial_buy_amount
+    data.extend_from_slice(&100_000_000u64.to_le_bytes()); // initial_max_sol
+    data.extend_from_slice(&0u64.to_le_bytes()); // creator_allocation
+
+    let accounts = vec![
+        AccountMeta::new_readonly(authority.pubkey(), true), // authority
+        AccountMeta::new(bonding_curve, false),               // bonding_curve
+        AccountMeta::new(mint, false),                        // mint
+        AccountMeta::new(treasury, false),                    // treasury
+        AccountMeta::new(authority_ata, false),               // authority_token_account
+        AccountMeta::new(fee_payer.pubkey(), true),           // payer
+        AccountMeta::new_readonly(system_program::ID, false), // system_program
+        AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),   // token_program
+        AccountMeta::new_readonly(ATA_PROGRAM_ID, false),     // associated_token_program
+        AccountMeta::new(authority.pubkey(), false),          // fee_recipient_account
+        AccountMeta::new_readonly(config, false),             // config
+    ];
+
+    // the full Initialize encoding is rejected
+    let mut full_data = data.clone();
+    full_data.extend_from_slice(&[0u8; 32 + 10 + 200 + 2 + 1 + 32 + 2 + 8]);
+    let ix = Instruction { program_id, accounts: accounts.clone(), data: full_data };
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&authority]));
+
+    let ix = Instruction { program_id, accounts, data };
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&authority]));
+    assert_eq!(token_balance(&svm, &authority_ata), 1_000);
+
+    let data = svm.get_account(&bonding_curve).unwrap().data;
+    assert_eq!(data[329], 1); // metadata_mode: none
+
+    // curves launched with metadata record where it lives
+    let launch = launch_token(&mut svm, &fee_payer, &program_id); // Metaplex disabled in the config
+    assert_eq!(svm.get_account(&launch.bonding_curve).unwrap().data[329], 1);
+    let options = LaunchOptions { token_2022: true, ..Default::default() };
+    let launch = try_launch_token(&mut svm, &fee_payer, &program_id, options).expect("Initialize should succeed");
+    assert_eq!(svm.get_account(&launch.bonding_curve).unwrap().data[329], 2);
+}
+
+#[test]
+fn initialize_accepts_original_data_layout() {
+    let (mut svm, fee_payer, program_id) = setup();
+
+    // unknown versions of the appended fields are rejected
+    let options = LaunchOptions { fixed_version: Some(3), ..Default::default() };
+    assert!(try_launch_token(&mut svm, &fee_payer, &program_id, options).is_none());
+
+    // data from before the version byte launches with the later fields zeroed
+    let options = LaunchOptions { fixed_version: Some(1), ..Default::default() };
+    let launch = try_launch_token(&mut svm, &fee_payer, &program_id, options).expect("v1 Initialize should succeed");
+    let curve = svm.get_account(&launch.bonding_curve).unwrap().data;
+    assert_eq!(&curve[64..96], launch.authority.pubkey().as_ref()); // fee_recipient
+    assert_eq!(&curve[312..320], &0u64.to_le_bytes()); // creator_allocation
+
+    let buyer = Keypair::new();
+    svm.airdrop(&buyer.pubkey(), 1_000_000_000).unwrap();
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000, 100_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+}
+
+#[test]
+fn initialize_compact_encoding() {
+    let (mut svm, fee_payer, program_id) = setup();
+
+    // unknown encoding versions are rejected
+    let options = LaunchOptions { compact_version: Some(2), ..Default::default() };
+    assert!(try_launch_token(&mut svm, &fee_payer, &program_id, options).is_none());
+
+    let options = LaunchOptions {
+        token_2022: true,
+        transfer_fee_basis_points: 250,
+        maximum_transfer_fee: 5_000,
+        compact_version: Some(1),
+        ..Default::default()
+    };
+    let authority = Pubkey::default();
+    let compact_len = compact_initialize_data(1, &authority, &options).len();
+    assert!(compact_len < fixed_initialize_data(&authority, &options).len() / 2);
+    let launch = try_launch_token(&mut svm, &fee_payer, &program_id, options).expect("InitializeCompact should succeed");
+
+    let curve = svm.get_account(&launch.bonding_curve).unwrap().data;
+    assert_eq!(&curve[64..96], launch.authority.pubkey().as_ref()); // fee_recipient
+    assert_eq!(curve[329], 2); // metadata_mode: Token-2022
+
+    // the strings and transfer fee reach the mint
+    let mint = svm.get_account(&launch.mint).unwrap().data;
+    assert!(mint.windows(22).any(|w| w == b"https://x.test/xt.json"));
+    let newer_fee = &mint[166 + 4 + 90..166 + 4 + 108]; // TransferFeeConfig is the first extension
+    assert_eq!(&newer_fee[8..16], &5_000u64.to_le_bytes());
+    assert_eq!(&newer_fee[16..18], &250u16.to_le_bytes());
+}
+
+// Helper function to build a CreateMetadata instruction for `launch`
+fn create_metadata_ix(program_id: &Pubkey, launch: &Launch, token_name: &str) -> Instruction {
+    let mut data = vec![32u8]; // CreateMetadata discriminator
+    let mut name = [0u8; 32];
+    name[0] = token_name.len() as u8;
+    name[1..1 + token_name.len()].copy_from_slice(token_name.as_bytes());
+    data.extend_from_slice(&name); // token_name
+    data.extend_from_slice(&[0u8; 10]); // token_symbol (empty)
+    data.extend_from_slice(&[0u8; 200]); // token_uri (empty)
+    data.extend_from_slice(&0u16.to_le_bytes()); // seller_fee_basis_points
+    data.push(0); // creator_share
+    data.extend_from_slice(&[0u8; 32]); // collection (none)
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(launch.authority.pubkey(), true),       // authority
+            AccountMeta::new(launch.bonding_curve, false),                    // bonding_curve
+            AccountMeta::new(launch.mint, false),                             // mint
+            AccountMeta::new(launch.authority.pubkey(), true),                // payer
+            AccountMeta::new_readonly(system_program::ID, false),             // system_program
+            AccountMeta::new_readonly(launch.token_program, false),           // token_program
+            AccountMeta::new_readonly(solana_sdk::sysvar::rent::ID, false),   // rent
+            AccountMeta::new(derive_metadata_pda(&launch.mint), false),       // metadata_account
+            AccountMeta::new_readonly(METAPLEX_PROGRAM_ID, false),            // metaplex_program
+            AccountMeta::new_readonly(launch.config, false),                  // config
+        ],
+        data,
+    }
+}
+
+// Helper function to build an InitialBuy instruction crediting `authority`, paid by `payer`
+fn initial_buy_ix(program_id: &Pubkey, launch: &Launch, authority: &Pubkey, payer: &Pubkey, token_amount: u64) -> Instruction {
+    let mut data = vec![33u8]; // InitialBuy discriminator
+    data.extend_from_slice(&token_amount.to_le_bytes());
+    data.extend_from_slice(&100_000_000u64.to_le_bytes()); // max_sol_amount
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*authority, true),                 // authority
+            AccountMeta::new(launch.bonding_curve, false),               // bonding_curve
+            AccountMeta::new(launch.mint, false),                        // mint
+            AccountMeta::new(launch.treasury, false),                    // treasury
+            AccountMeta::new(launch.ata(authority), false),              // authority_token_account
+            AccountMeta::new(*payer, true),                              // payer
+            AccountMeta::new(launch.authority.pubkey(), false),          // fee_recipient_account
+            AccountMeta::new_readonly(system_program::ID, false),        // system_program
+            AccountMeta::new_readonly(launch.token_program, false),      // token_program
+            AccountMeta::new_readonly(ATA_PROGRAM_ID, false),            // associated_token_program
+            AccountMeta::new_readonly(launch.config, false),             // config
+        ],
+        data,
+    }
+}
+
+// Helper function to build a CreateCurve instruction for `launch`; with a
+// mint nonce the mint is the PDA derived from the authority and owner
+fn create_curve_ix(program_id: &Pubkey, launch: &Launch, payer: &Pubkey, owner: &str, mint_nonce: Option<u64>) -> Instruction {
+    let mut owner_field = [0u8; 32];
+    owner_field[0] = owner.len() as u8;
+    owner_field[1..1 + owner.len()].copy_from_slice(owner.as_bytes());
+
+    let mut data = vec![31u8]; // CreateCurve discriminator
+    data.push(9); // decimals
+    data.push(0); // curve_type (linear)
+    data.extend_from_slice(&100u16.to_le_bytes()); // fee_basis_points
+    data.extend_from_slice(&owner_field); // owner
+    data.extend_from_slice(&1_000_000u64.to_le_bytes()); // base_price
+    data.extend_from_slice(&1_000u64.to_le_bytes()); // slope
+    data.extend_from_slice(&1_000_000_000_000u64.to_le_bytes()); // max_supply
+    data.extend_from_slice(&0u64.to_le_bytes()); // creator_allocation
+    data.extend_from_slice(&launch.authority.pubkey().to_bytes()); // fee_recipient
+    data.extend_from_slice(&0u16.to_le_bytes()); // transfer_fee_basis_points
+    data.extend_from_slice(&0u64.to_le_bytes()); // maximum_transfer_fee
+    data.push(mint_nonce.is_some() as u8); // derive_mint
+    data.extend_from_slice(&mint_nonce.unwrap_or(0).to_le_bytes()); // mint_nonce
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(launch.authority.pubkey(), true), // authority
+            AccountMeta::new(launch.bonding_curve, false),               // bonding_curve
+            AccountMeta::new(launch.mint, false),                        // mint
+            AccountMeta::new(launch.treasury, false),                    // treasury
+            AccountMeta::new(*payer, true),                              // payer
+            AccountMeta::new_readonly(system_program::ID, false),        // system_program
+            AccountMeta::new_readonly(launch.token_program, false),      // token_program
+            AccountMeta::new_readonly(launch.config, false),             // config
+        ],
+        data,
+    }
+}
+
+#[test]
+fn launch_in_separate_steps() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let config = initialize_config(&mut svm, &fee_payer, &program_id);
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let create_mint_ix = solana_sdk::system_instruction::create_account(
+        &fee_payer.pubkey(),
+        &mint,
+        Rent::default().minimum_balance(MINT_2022_WITH_METADATA_POINTER_LEN),
+        MINT_2022_WITH_METADATA_POINTER_LEN as u64,
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    assert!(send_signed(&mut svm, &fee_payer, create_mint_ix, &[&mint_keypair]));
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 1_000_000_000).unwrap();
+    let launch = Launch {
+        mint,
+        bonding_curve: derive_pda(&[b"x_token", mint.as_ref()], &program_id).0,
+        treasury: derive_pda(&[b"treasury", mint.as_ref()], &program_id).0,
+        authority,
+        config,
+        token_program: TOKEN_2022_PROGRAM_ID,
+    };
+
+    let ix = create_curve_ix(&program_id, &launch, &fee_payer.pubkey(), "", None);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(svm.get_account(&launch.bonding_curve).unwrap().data[329], 1); // metadata_mode: none yet
+
+    // metadata can be added once, into the Token-2022 mint
+    let ix = create_metadata_ix(&program_id, &launch, "Stepwise");
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(svm.get_account(&launch.bonding_curve).unwrap().data[329], 2);
+    assert!(svm.get_account(&mint).unwrap().data.windows(8).any(|w| w == b"Stepwise"));
+    let ix = create_metadata_ix(&program_id, &launch, "Again");
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    // only the owner gets the initial buy
+    let outsider = Keypair::new();
+    svm.airdrop(&outsider.pubkey(), 1_000_000_000).unwrap();
+    let ix = initial_buy_ix(&program_id, &launch, &outsider.pubkey(), &fee_payer.pubkey(), 1_000);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&outsider]));
+
+    let ix = initial_buy_ix(&program_id, &launch, &launch.authority.pubkey(), &fee_payer.pubkey(), 1_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(token_balance(&svm, &launch.ata(&launch.authority.pubkey())), 1_000);
+
+    // once the curve has sold tokens the initial buy is closed
+    let ix = initial_buy_ix(&program_id, &launch, &launch.authority.pubkey(), &fee_payer.pubkey(), 1_000);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let buyer = Keypair::new();
+    svm.airdrop(&buyer.pubkey(), 1_000_000_000).unwrap();
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 10_000, 100_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+}
+
+#[test]
+fn multisig_authority_launches_in_separate_steps() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let config = initialize_config(&mut svm, &fee_payer, &program_id);
+    let members = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let keys: Vec<Pubkey> = members.iter().map(|m| m.pubkey()).collect();
+    let multisig = create_multisig(&mut svm, &fee_payer, &program_id, 2, &keys).unwrap();
+
+    let mint_keypair = Keypair::new();
+    let mint = mint_keypair.pubkey();
+    let create_mint_ix = solana_sdk::system_instruction::create_account(
+        &fee_payer.pubkey(),
+        &mint,
+        Rent::default().minimum_balance(MINT_2022_WITH_METADATA_POINTER_LEN),
+        MINT_2022_WITH_METADATA_POINTER_LEN as u64,
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    assert!(send_signed(&mut svm, &fee_payer, create_mint_ix, &[&mint_keypair]));
+
+    // the launch keypair only pays; the multisig is the authority
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+    let launch = Launch {
+        mint,
+        bonding_curve: derive_pda(&[b"x_token", mint.as_ref()], &program_id).0,
+        treasury: derive_pda(&[b"treasury", mint.as_ref()], &program_id).0,
+        authority: payer,
+        config,
+        token_program: TOKEN_2022_PROGRAM_ID,
+    };
+    let as_multisig = |mut ix: Instruction, approvals: &[&Keypair]| {
+        ix.accounts[0].pubkey = multisig;
+        with_multisig_signers(ix, approvals)
+    };
+
+    let ix = create_curve_ix(&program_id, &launch, &fee_payer.pubkey(), "", None);
+    assert!(!send_signed(&mut svm, &fee_payer, as_multisig(ix.clone(), &[&members[0]]), &[&members[0]]));
+    let ix = as_multisig(ix, &[&members[0], &members[2]]);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&members[0], &members[2]]));
+    assert_eq!(&svm.get_account(&launch.bonding_curve).unwrap().data[0..32], multisig.as_ref()); // authority
+
+    let ix = create_metadata_ix(&program_id, &launch, "Multisig");
+    assert!(!send_signed(&mut svm, &fee_payer, as_multisig(ix.clone(), &[&members[1]]), &[&launch.authority, &members[1]]));
+    let ix = as_multisig(ix, &[&members[0], &members[1]]);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority, &members[0], &members[1]]));
+    assert_eq!(svm.get_account(&launch.bonding_curve).unwrap().data[329], 2); // metadata_mode: Token-2022
+    assert!(svm.get_account(&mint).unwrap().data.windows(8).any(|w| w == b"Multisig"));
+}
+
+#[test]
+fn initial_buy_closed_once_tokens_circulate() {
+    let (mut svm, fee_payer, program_id) = setup();
+    // a regular buy closes it
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let buyer = Keypair::new();
+    svm.airdrop(&buyer.pubkey(), 1_000_000_000).unwrap();
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 1_000, 100_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+    let ix = initial_buy_ix(&program_id, &launch, &launch.authority.pubkey(), &fee_payer.pubkey(), 1_000);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    // and so does minting from the creator allocation
+    let launch = launch_token(&mut svm, &fee_payer, &program_id);
+    let recipient_ata = create_ata(&mut svm, &fee_payer, &fee_payer.pubkey(), &launch.mint);
+    let ix = admin_mint_ix(&program_id, &launch, &launch.authority.pubkey(), &recipient_ata, 1_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    let ix = initial_buy_ix(&program_id, &launch, &launch.authority.pubkey(), &fee_payer.pubkey(), 1_000);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+}
+
+#[test]
+fn derived_mint_launch() {
+    let (mut svm, fee_payer, program_id) = setup();
+    let config = initialize_config(&mut svm, &fee_payer, &program_id);
+
+    let authority = Keypair::new();
+    svm.airdrop(&authority.pubkey(), 1_000_000_000).unwrap();
+    let derived_launch = |authority: &Keypair, nonce: u64| {
+        let mint = derive_pda(
+            &[b"mint", authority.pubkey().as_ref(), b"elonmusk", &nonce.to_le_bytes()],
+            &program_id,
+        )
+        .0;
+        Launch {
+            mint,
+            bonding_curve: derive_pda(&[b"x_token", mint.as_ref()], &program_id).0,
+            treasury: derive_pda(&[b"treasury", mint.as_ref()], &program_id).0,
+            authority: authority.insecure_clone(),
+            config,
+            token_program: TOKEN_PROGRAM_ID,
+        }
+    };
+
+    // the mint is created by the program, no mint keypair signs
+    let launch = derived_launch(&authority, 0);
+    let ix = create_curve_ix(&program_id, &launch, &fee_payer.pubkey(), "ElonMusk", Some(0));
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(svm.get_account(&launch.mint).unwrap().owner, TOKEN_PROGRAM_ID);
+
+    let buyer = Keypair::new();
+    svm.airdrop(&buyer.pubkey(), 1_000_000_000).unwrap();
+    let ix = buy_tokens_ix(&program_id, &launch, &buyer.pubkey(), 10_000, 100_000_000);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&buyer]));
+    assert_eq!(token_balance(&svm, &launch.ata(&buyer.pubkey())), 10_000);
+
+    // the username is case-insensitive, so the same handle and nonce is taken
+    let ix = create_curve_ix(&program_id, &launch, &fee_payer.pubkey(), "elonmusk", Some(0));
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    // a mint that does not match the derivation is rejected
+    let other = derived_launch(&authority, 2);
+    let ix = create_curve_ix(&program_id, &other, &fee_payer.pubkey(), "ElonMusk", Some(1));
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&other.authority]));
+
+    let launch = derived_launch(&authority, 1);
+    let ix = create_curve_ix(&program_id, &launch, &fee_payer.pubkey(), "ElonMusk", Some(1));
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+}
+
+// Helper function to set the protocol fee recipient and creation fee through UpdateConfig
+fn set_creation_fee(
+    svm: &mut LiteSVM,
+    fee_payer: &Keypair,
+    program_id: &Pubkey,
+    fee_recipient: &Pubkey,
+    creation_fee_lamports: u64,
+    creation_fee_rebate_basis_points: u16,
+) -> bool {
+    let mut data = vec![6u8]; // UpdateConfig discriminator
+    data.extend_from_slice(&fee_payer.pubkey().to_bytes()); // new_admin
+    data.extend_from_slice(&initialize_config_data(fee_recipient, 84_000_000_000, 0, 1_000, 0, 0)[1..]);
+    data.push(2); // version
+    data.extend_from_slice(&0i64.to_le_bytes()); // timelock_delay_seconds
+    data.extend_from_slice(&creation_fee_lamports.to_le_bytes());
+    data.extend_from_slice(&creation_fee_rebate_basis_points.to_le_bytes());
+    let ix = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(fee_payer.pubkey(), true),
+            AccountMeta::new(derive_config_pda(program_id), false),
+        ],
+        data,
+    };
+    send_signed(svm, fee_payer, ix, &[])
+}
+
+fn claim_creation_rebate_ix(program_id: &Pubkey, launch: &Launch, creator: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(launch.bonding_curve, false),   // bonding_curve
+            AccountMeta::new_readonly(launch.mint, false),   // mint
+            AccountMeta::new(*creator, false),               // creator
+            AccountMeta::new_readonly(launch.config, false), // config
+        ],
+        data: vec![34u8], // ClaimCreationRebate discriminator
+    }
+}
+
+fn creation_rebate(svm: &LiteSVM, launch: &Launch) -> u64 {
+    let data = svm.get_account(&launch.bonding_curve).unwrap().data;
+    u64::from_le_bytes(data[336..344].try_into().unwrap())
+}
+
+#[test]
+fn creation_fee_with_rebate_on_graduation() {
+    let (mut svm, fee_payer, program_id) = setup();
+    initialize_config(&mut svm, &fee_payer, &program_id);
+    let protocol = Keypair::new().pubkey();
+
+    // the rebate share is a fraction of the fee
+    assert!(!set_creation_fee(&mut svm, &fee_payer, &program_id, &protocol, 100_000_000, 10_001));
+    assert!(set_creation_fee(&mut svm, &fee_payer, &program_id, &protocol, 100_000_000, 2_500));
+
+    // the protocol fee recipient must be passed along
+    assert!(try_launch_token(&mut svm, &fee_payer, &program_id, LaunchOptions::default()).is_none());
+    let wrong = LaunchOptions { protocol_fee_recipient: Some(fee_payer.pubkey()), ..Default::default() };
+    assert!(try_launch_token(&mut svm, &fee_payer, &program_id, wrong).is_none());
+
+    let options = LaunchOptions { protocol_fee_recipient: Some(protocol), ..Default::default() };
+    let launch = try_launch_token(&mut svm, &fee_payer, &program_id, options).expect("Initialize should succeed");
+    let curve_rent = Rent::default().minimum_balance(svm.get_account(&launch.bonding_curve).unwrap().data.len());
+    assert_eq!(svm.get_balance(&protocol).unwrap(), 75_000_000);
+    assert_eq!(svm.get_balance(&launch.bonding_curve).unwrap(), curve_rent + 25_000_000);
+    assert_eq!(creation_rebate(&svm, &launch), 25_000_000);
+
+    // the rebate waits for graduation
+    let authority = launch.authority.pubkey();
+    let ix = claim_creation_rebate_ix(&program_id, &launch, &authority);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[]));
+
+    let mut curve = svm.get_account(&launch.bonding_curve).unwrap();
+    curve.data[215] = 1; // graduated
+    svm.set_account(launch.bonding_curve, curve).unwrap();
+
+    // only the curve authority receives it, once
+    let ix = claim_creation_rebate_ix(&program_id, &launch, &fee_payer.pubkey());
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[]));
+
+    let before = svm.get_balance(&authority).unwrap();
+    let ix = claim_creation_rebate_ix(&program_id, &launch, &authority);
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[]));
+    assert_eq!(svm.get_balance(&authority).unwrap(), before + 25_000_000);
+    assert_eq!(svm.get_balance(&launch.bonding_curve).unwrap(), curve_rent);
+    assert_eq!(creation_rebate(&svm, &launch), 0);
+
+    let ix = claim_creation_rebate_ix(&program_id, &launch, &authority);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[]));
+}
+
+#[test]
+fn close_curve_forfeits_creation_rebate() {
+    let (mut svm, fee_payer, program_id) = setup();
+    initialize_config(&mut svm, &fee_payer, &program_id);
+    let protocol = Keypair::new().pubkey();
+    assert!(set_creation_fee(&mut svm, &fee_payer, &program_id, &protocol, 100_000_000, 5_000));
+
+    let options = LaunchOptions { protocol_fee_recipient: Some(protocol), ..Default::default() };
+    let launch = try_launch_token(&mut svm, &fee_payer, &program_id, options).expect("Initialize should succeed");
+    let authority = launch.authority.pubkey();
+    let recipient = Keypair::new().pubkey();
+
+    // the unearned rebate needs the protocol fee recipient to go to
+    let ix = close_curve_ix(&program_id, &launch, &authority, &recipient);
+    assert!(!send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+
+    let locked = svm.get_account(&launch.bonding_curve).unwrap().lamports
+        + svm.get_account(&launch.treasury).unwrap().lamports;
+    let mut ix = close_curve_ix(&program_id, &launch, &authority, &recipient);
+    ix.accounts.push(AccountMeta::new(protocol, false));
+    assert!(send_signed(&mut svm, &fee_payer, ix, &[&launch.authority]));
+    assert_eq!(svm.get_balance(&protocol).unwrap(), 100_000_000);
+    assert_eq!(svm.get_account(&recipient).unwrap().lamports, locked - 50_000_000);
+    assert!(svm.get_account(&launch.bonding_curve).map_or(true, |a| a.lamports == 0));
+}